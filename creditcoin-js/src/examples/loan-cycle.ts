@@ -9,6 +9,7 @@ import { ethConnection, testCurrency } from './ethereum';
 import { LoanTerms, TransferKind, CHAINS } from '../model';
 import { setupAuthority } from './setup-authority';
 import { registerCurrencyAsync } from '../extrinsics/register-currency';
+import { exactTermRanges } from '../transforms';
 
 export type PostAddressRegistrationInfo = {
     wallet: Wallet;
@@ -126,8 +127,8 @@ export const fullLoanCycleExample = async (
         const askGuid = Guid.newGuid();
         const bidGuid = Guid.newGuid();
         const [{ itemId: askOrderId, item: askOrder }, { itemId: bidOrderId, item: bidOrder }] = await Promise.all([
-            addAskOrder(lenderAddress.itemId, loanTerms, expBlock, askGuid, lender),
-            addBidOrder(borrowerAddress.itemId, loanTerms, expBlock, bidGuid, borrower),
+            addAskOrder(lenderAddress.itemId, exactTermRanges(loanTerms), expBlock, askGuid, lender),
+            addBidOrder(borrowerAddress.itemId, exactTermRanges(loanTerms), expBlock, bidGuid, borrower),
         ]);
         console.log(askOrder);
         console.log(bidOrder);
@@ -1,9 +1,9 @@
 import { ApiPromise, SubmittableResult } from '@polkadot/api';
-import { AddressId, AskOrder, AskOrderId, LoanTerms, EventReturnJoinType } from '../model';
+import { AddressId, AskOrder, AskOrderId, LoanTermRanges, EventReturnJoinType } from '../model';
 import { KeyringPair } from '@polkadot/keyring/types';
 import { handleTransaction, processEvents } from './common';
 import { TxCallback, TxFailureCallback } from '../types';
-import { createAskOrder, createCreditcoinLoanTerms } from '../transforms';
+import { createAskOrder, createCreditcoinLoanTermRanges } from '../transforms';
 import { Guid } from 'js-guid';
 import { blake2AsHex } from '@polkadot/util-crypto';
 
@@ -15,7 +15,7 @@ export const createAskOrderId = (expirationBlock: number, guid: Guid): AskOrderI
 export const addAskOrder = async (
     api: ApiPromise,
     lenderAddressId: AddressId,
-    loanTerms: LoanTerms,
+    termRanges: LoanTermRanges,
     expirationBlock: number,
     guid: Guid,
     signer: KeyringPair,
@@ -23,7 +23,7 @@ export const addAskOrder = async (
     onFail: TxFailureCallback,
 ) => {
     const unsubscribe: () => void = await api.tx.creditcoin
-        .addAskOrder(lenderAddressId, createCreditcoinLoanTerms(api, loanTerms), expirationBlock, guid.toString())
+        .addAskOrder(lenderAddressId, createCreditcoinLoanTermRanges(api, termRanges), expirationBlock, guid.toString())
         .signAndSend(signer, { nonce: -1 }, (result) => handleTransaction(api, unsubscribe, result, onSuccess, onFail));
 };
 
@@ -34,15 +34,15 @@ export const processAskOrderAdded = (api: ApiPromise, result: SubmittableResult)
 export const addAskOrderAsync = async (
     api: ApiPromise,
     lenderAddressId: AddressId,
-    loanTerms: LoanTerms,
+    termRanges: LoanTermRanges,
     expirationBlock: number,
     guid: Guid,
     signer: KeyringPair,
 ) => {
     return new Promise<AskOrderAdded>((resolve, reject) => {
         const onSuccess = (result: SubmittableResult) => resolve(processAskOrderAdded(api, result));
-        addAskOrder(api, lenderAddressId, loanTerms, expirationBlock, guid, signer, onSuccess, reject).catch((reason) =>
-            reject(reason),
+        addAskOrder(api, lenderAddressId, termRanges, expirationBlock, guid, signer, onSuccess, reject).catch(
+            (reason) => reject(reason),
         );
     });
 };
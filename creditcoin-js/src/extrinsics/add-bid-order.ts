@@ -1,9 +1,9 @@
 import { ApiPromise, SubmittableResult } from '@polkadot/api';
-import { AddressId, BidOrder, BidOrderId, LoanTerms, EventReturnJoinType } from '../model';
+import { AddressId, BidOrder, BidOrderId, LoanTermRanges, EventReturnJoinType } from '../model';
 import { KeyringPair } from '@polkadot/keyring/types';
 import { handleTransaction, processEvents } from './common';
 import { TxCallback, TxFailureCallback } from '../types';
-import { createBidOrder, createCreditcoinLoanTerms } from '../transforms';
+import { createBidOrder, createCreditcoinLoanTermRanges } from '../transforms';
 import { Guid } from 'js-guid';
 import { blake2AsHex } from '@polkadot/util-crypto';
 
@@ -15,7 +15,7 @@ export const createBidOrderId = (expirationBlock: number, guid: Guid): BidOrderI
 export const addBidOrder = async (
     api: ApiPromise,
     borrowerAddressId: AddressId,
-    loanTerms: LoanTerms,
+    termRanges: LoanTermRanges,
     expirationBlock: number,
     guid: Guid,
     signer: KeyringPair,
@@ -23,7 +23,12 @@ export const addBidOrder = async (
     onFail: TxFailureCallback,
 ) => {
     const unsubscribe: () => void = await api.tx.creditcoin
-        .addBidOrder(borrowerAddressId, createCreditcoinLoanTerms(api, loanTerms), expirationBlock, guid.toString())
+        .addBidOrder(
+            borrowerAddressId,
+            createCreditcoinLoanTermRanges(api, termRanges),
+            expirationBlock,
+            guid.toString(),
+        )
         .signAndSend(signer, { nonce: -1 }, (result) => handleTransaction(api, unsubscribe, result, onSuccess, onFail));
 };
 
@@ -34,14 +39,14 @@ export const processBidOrderAdded = (api: ApiPromise, result: SubmittableResult)
 export const addBidOrderAsync = async (
     api: ApiPromise,
     borrowerAddressId: AddressId,
-    loanTerms: LoanTerms,
+    termRanges: LoanTermRanges,
     expirationBlock: number,
     guid: Guid,
     signer: KeyringPair,
 ) => {
     return new Promise<BidOrderAdded>((resolve, reject) => {
         const onSuccess = (result: SubmittableResult) => resolve(processBidOrderAdded(api, result));
-        addBidOrder(api, borrowerAddressId, loanTerms, expirationBlock, guid, signer, onSuccess, reject).catch(
+        addBidOrder(api, borrowerAddressId, termRanges, expirationBlock, guid, signer, onSuccess, reject).catch(
             (reason) => reject(reason),
         );
     });
@@ -13,6 +13,7 @@ import {
     Blockchain,
     AddressId,
     LoanTerms,
+    LoanTermRanges,
     AskOrderId,
     BidOrderId,
     OfferId,
@@ -37,19 +38,19 @@ export const extrinsics = (api: ApiPromise) => {
 
     const addAskOrder = (
         lenderAddressId: AddressId,
-        loanTerms: LoanTerms,
+        termRanges: LoanTermRanges,
         expirationBlock: number,
         guid: Guid,
         signer: KeyringPair,
-    ) => addAskOrderAsync(api, lenderAddressId, loanTerms, expirationBlock, guid, signer);
+    ) => addAskOrderAsync(api, lenderAddressId, termRanges, expirationBlock, guid, signer);
 
     const addBidOrder = (
         borrowerAddressId: AddressId,
-        loanTerms: LoanTerms,
+        termRanges: LoanTermRanges,
         expirationBlock: number,
         guid: Guid,
         signer: KeyringPair,
-    ) => addBidOrderAsync(api, borrowerAddressId, loanTerms, expirationBlock, guid, signer);
+    ) => addBidOrderAsync(api, borrowerAddressId, termRanges, expirationBlock, guid, signer);
 
     const addOffer = (askOrderId: AskOrderId, bidOrderId: BidOrderId, expirationBlock: number, signer: KeyringPair) =>
         addOfferAsync(api, askOrderId, bidOrderId, expirationBlock, signer);
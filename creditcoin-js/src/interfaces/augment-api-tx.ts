@@ -20,6 +20,7 @@ import type {
     PalletCreditcoinDealOrderId,
     PalletCreditcoinLegacyTransferKind,
     PalletCreditcoinLoanTerms,
+    PalletCreditcoinLoanTermsLoanTermRanges,
     PalletCreditcoinOcwErrorsVerificationFailureCause,
     PalletCreditcoinOcwTasksCollectCoinsGCreContract,
     PalletCreditcoinOfferId,
@@ -229,14 +230,21 @@ declare module '@polkadot/api-base/types/submittable' {
                 (
                     addressId: H256 | string | Uint8Array,
                     terms:
-                        | PalletCreditcoinLoanTerms
-                        | { amount?: any; interestRate?: any; termLength?: any; currency?: any }
+                        | PalletCreditcoinLoanTermsLoanTermRanges
+                        | {
+                              minAmount?: any;
+                              maxAmount?: any;
+                              interestRate?: any;
+                              minTermLength?: any;
+                              maxTermLength?: any;
+                              currency?: any;
+                          }
                         | string
                         | Uint8Array,
                     expirationBlock: u32 | AnyNumber | Uint8Array,
                     guid: Bytes | string | Uint8Array,
                 ) => SubmittableExtrinsic<ApiType>,
-                [H256, PalletCreditcoinLoanTerms, u32, Bytes]
+                [H256, PalletCreditcoinLoanTermsLoanTermRanges, u32, Bytes]
             >;
             addAuthority: AugmentedSubmittable<
                 (who: AccountId32 | string | Uint8Array) => SubmittableExtrinsic<ApiType>,
@@ -246,14 +254,21 @@ declare module '@polkadot/api-base/types/submittable' {
                 (
                     addressId: H256 | string | Uint8Array,
                     terms:
-                        | PalletCreditcoinLoanTerms
-                        | { amount?: any; interestRate?: any; termLength?: any; currency?: any }
+                        | PalletCreditcoinLoanTermsLoanTermRanges
+                        | {
+                              minAmount?: any;
+                              maxAmount?: any;
+                              interestRate?: any;
+                              minTermLength?: any;
+                              maxTermLength?: any;
+                              currency?: any;
+                          }
                         | string
                         | Uint8Array,
                     expirationBlock: u32 | AnyNumber | Uint8Array,
                     guid: Bytes | string | Uint8Array,
                 ) => SubmittableExtrinsic<ApiType>,
-                [H256, PalletCreditcoinLoanTerms, u32, Bytes]
+                [H256, PalletCreditcoinLoanTermsLoanTermRanges, u32, Bytes]
             >;
             addDealOrder: AugmentedSubmittable<
                 (
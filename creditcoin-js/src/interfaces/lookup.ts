@@ -371,9 +371,20 @@ export default {
     /**
      * Lookup60: pallet_creditcoin::types::loan_terms::AskTerms<primitive_types::H256>
      **/
-    PalletCreditcoinLoanTermsAskTerms: 'PalletCreditcoinLoanTerms',
+    PalletCreditcoinLoanTermsAskTerms: 'PalletCreditcoinLoanTermsLoanTermRanges',
     /**
-     * Lookup61: pallet_creditcoin::types::loan_terms::LoanTerms<primitive_types::H256>
+     * Lookup61: pallet_creditcoin::types::loan_terms::LoanTermRanges<primitive_types::H256>
+     **/
+    PalletCreditcoinLoanTermsLoanTermRanges: {
+        minAmount: 'U256',
+        maxAmount: 'U256',
+        interestRate: 'PalletCreditcoinLoanTermsInterestRate',
+        minTermLength: 'PalletCreditcoinLoanTermsDuration',
+        maxTermLength: 'PalletCreditcoinLoanTermsDuration',
+        currency: 'H256',
+    },
+    /**
+     * Lookup62: pallet_creditcoin::types::loan_terms::LoanTerms<primitive_types::H256>
      **/
     PalletCreditcoinLoanTerms: {
         amount: 'U256',
@@ -382,7 +393,7 @@ export default {
         currency: 'H256',
     },
     /**
-     * Lookup62: pallet_creditcoin::types::loan_terms::InterestRate
+     * Lookup63: pallet_creditcoin::types::loan_terms::InterestRate
      **/
     PalletCreditcoinLoanTermsInterestRate: {
         ratePerPeriod: 'u64',
@@ -391,24 +402,24 @@ export default {
         interestType: 'PalletCreditcoinLoanTermsInterestType',
     },
     /**
-     * Lookup63: pallet_creditcoin::types::loan_terms::Duration
+     * Lookup64: pallet_creditcoin::types::loan_terms::Duration
      **/
     PalletCreditcoinLoanTermsDuration: {
         secs: 'u64',
         nanos: 'u32',
     },
     /**
-     * Lookup64: pallet_creditcoin::types::loan_terms::InterestType
+     * Lookup65: pallet_creditcoin::types::loan_terms::InterestType
      **/
     PalletCreditcoinLoanTermsInterestType: {
         _enum: ['Simple', 'Compound'],
     },
     /**
-     * Lookup66: pallet_creditcoin::types::BidOrderId<BlockNum, primitive_types::H256>
+     * Lookup67: pallet_creditcoin::types::BidOrderId<BlockNum, primitive_types::H256>
      **/
     PalletCreditcoinBidOrderId: '(u32,H256)',
     /**
-     * Lookup67: pallet_creditcoin::types::BidOrder<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256>
+     * Lookup68: pallet_creditcoin::types::BidOrder<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256>
      **/
     PalletCreditcoinBidOrder: {
         borrowerAddressId: 'H256',
@@ -418,15 +429,15 @@ export default {
         borrower: 'AccountId32',
     },
     /**
-     * Lookup68: pallet_creditcoin::types::loan_terms::BidTerms<primitive_types::H256>
+     * Lookup69: pallet_creditcoin::types::loan_terms::BidTerms<primitive_types::H256>
      **/
-    PalletCreditcoinLoanTermsBidTerms: 'PalletCreditcoinLoanTerms',
+    PalletCreditcoinLoanTermsBidTerms: 'PalletCreditcoinLoanTermsLoanTermRanges',
     /**
-     * Lookup69: pallet_creditcoin::types::OfferId<BlockNum, primitive_types::H256>
+     * Lookup70: pallet_creditcoin::types::OfferId<BlockNum, primitive_types::H256>
      **/
     PalletCreditcoinOfferId: '(u32,H256)',
     /**
-     * Lookup70: pallet_creditcoin::types::Offer<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256>
+     * Lookup71: pallet_creditcoin::types::Offer<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256>
      **/
     PalletCreditcoinOffer: {
         askId: 'PalletCreditcoinAskOrderId',
@@ -436,7 +447,7 @@ export default {
         lender: 'AccountId32',
     },
     /**
-     * Lookup71: pallet_creditcoin::types::DealOrder<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256, Moment>
+     * Lookup72: pallet_creditcoin::types::DealOrder<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256, Moment>
      **/
     PalletCreditcoinDealOrder: {
         offerId: 'PalletCreditcoinOfferId',
@@ -452,11 +463,11 @@ export default {
         borrower: 'AccountId32',
     },
     /**
-     * Lookup74: pallet_creditcoin::types::LegacySighash
+     * Lookup75: pallet_creditcoin::types::LegacySighash
      **/
     PalletCreditcoinLegacySighash: '[u8;60]',
     /**
-     * Lookup76: pallet_creditcoin::ocw::errors::VerificationFailureCause
+     * Lookup77: pallet_creditcoin::ocw::errors::VerificationFailureCause
      **/
     PalletCreditcoinOcwErrorsVerificationFailureCause: {
         _enum: [
@@ -482,7 +493,7 @@ export default {
         ],
     },
     /**
-     * Lookup77: pallet_creditcoin::types::platform::Currency
+     * Lookup78: pallet_creditcoin::types::platform::Currency
      **/
     PalletCreditcoinPlatformCurrency: {
         _enum: {
@@ -490,7 +501,7 @@ export default {
         },
     },
     /**
-     * Lookup78: pallet_creditcoin::types::platform::EvmCurrencyType
+     * Lookup79: pallet_creditcoin::types::platform::EvmCurrencyType
      **/
     PalletCreditcoinPlatformEvmCurrencyType: {
         _enum: {
@@ -498,7 +509,7 @@ export default {
         },
     },
     /**
-     * Lookup81: pallet_rewards::pallet::Event<T>
+     * Lookup82: pallet_rewards::pallet::Event<T>
      **/
     PalletRewardsEvent: {
         _enum: {
@@ -506,7 +517,7 @@ export default {
         },
     },
     /**
-     * Lookup82: pallet_scheduler::pallet::Event<T>
+     * Lookup83: pallet_scheduler::pallet::Event<T>
      **/
     PalletSchedulerEvent: {
         _enum: {
@@ -538,11 +549,11 @@ export default {
         },
     },
     /**
-     * Lookup85: pallet_offchain_task_scheduler::pallet::Event<T>
+     * Lookup86: pallet_offchain_task_scheduler::pallet::Event<T>
      **/
     PalletOffchainTaskSchedulerEvent: 'Null',
     /**
-     * Lookup86: frame_system::Phase
+     * Lookup87: frame_system::Phase
      **/
     FrameSystemPhase: {
         _enum: {
@@ -552,14 +563,14 @@ export default {
         },
     },
     /**
-     * Lookup89: frame_system::LastRuntimeUpgradeInfo
+     * Lookup90: frame_system::LastRuntimeUpgradeInfo
      **/
     FrameSystemLastRuntimeUpgradeInfo: {
         specVersion: 'Compact<u32>',
         specName: 'Text',
     },
     /**
-     * Lookup92: frame_system::pallet::Call<T>
+     * Lookup93: frame_system::pallet::Call<T>
      **/
     FrameSystemCall: {
         _enum: {
@@ -597,7 +608,7 @@ export default {
         },
     },
     /**
-     * Lookup97: frame_system::limits::BlockWeights
+     * Lookup98: frame_system::limits::BlockWeights
      **/
     FrameSystemLimitsBlockWeights: {
         baseBlock: 'SpWeightsWeightV2Weight',
@@ -605,7 +616,7 @@ export default {
         perClass: 'FrameSupportDispatchPerDispatchClassWeightsPerClass',
     },
     /**
-     * Lookup98: frame_support::dispatch::PerDispatchClass<frame_system::limits::WeightsPerClass>
+     * Lookup99: frame_support::dispatch::PerDispatchClass<frame_system::limits::WeightsPerClass>
      **/
     FrameSupportDispatchPerDispatchClassWeightsPerClass: {
         normal: 'FrameSystemLimitsWeightsPerClass',
@@ -613,7 +624,7 @@ export default {
         mandatory: 'FrameSystemLimitsWeightsPerClass',
     },
     /**
-     * Lookup99: frame_system::limits::WeightsPerClass
+     * Lookup100: frame_system::limits::WeightsPerClass
      **/
     FrameSystemLimitsWeightsPerClass: {
         baseExtrinsic: 'SpWeightsWeightV2Weight',
@@ -622,13 +633,13 @@ export default {
         reserved: 'Option<SpWeightsWeightV2Weight>',
     },
     /**
-     * Lookup101: frame_system::limits::BlockLength
+     * Lookup102: frame_system::limits::BlockLength
      **/
     FrameSystemLimitsBlockLength: {
         max: 'FrameSupportDispatchPerDispatchClassU32',
     },
     /**
-     * Lookup102: frame_support::dispatch::PerDispatchClass<T>
+     * Lookup103: frame_support::dispatch::PerDispatchClass<T>
      **/
     FrameSupportDispatchPerDispatchClassU32: {
         normal: 'u32',
@@ -636,14 +647,14 @@ export default {
         mandatory: 'u32',
     },
     /**
-     * Lookup103: sp_weights::RuntimeDbWeight
+     * Lookup104: sp_weights::RuntimeDbWeight
      **/
     SpWeightsRuntimeDbWeight: {
         read: 'u64',
         write: 'u64',
     },
     /**
-     * Lookup104: sp_version::RuntimeVersion
+     * Lookup105: sp_version::RuntimeVersion
      **/
     SpVersionRuntimeVersion: {
         specName: 'Text',
@@ -656,7 +667,7 @@ export default {
         stateVersion: 'u8',
     },
     /**
-     * Lookup110: frame_system::pallet::Error<T>
+     * Lookup111: frame_system::pallet::Error<T>
      **/
     FrameSystemError: {
         _enum: [
@@ -669,7 +680,7 @@ export default {
         ],
     },
     /**
-     * Lookup111: pallet_timestamp::pallet::Call<T>
+     * Lookup112: pallet_timestamp::pallet::Call<T>
      **/
     PalletTimestampCall: {
         _enum: {
@@ -679,7 +690,7 @@ export default {
         },
     },
     /**
-     * Lookup113: pallet_balances::BalanceLock<Balance>
+     * Lookup114: pallet_balances::BalanceLock<Balance>
      **/
     PalletBalancesBalanceLock: {
         id: '[u8;8]',
@@ -687,26 +698,26 @@ export default {
         reasons: 'PalletBalancesReasons',
     },
     /**
-     * Lookup114: pallet_balances::Reasons
+     * Lookup115: pallet_balances::Reasons
      **/
     PalletBalancesReasons: {
         _enum: ['Fee', 'Misc', 'All'],
     },
     /**
-     * Lookup117: pallet_balances::ReserveData<ReserveIdentifier, Balance>
+     * Lookup118: pallet_balances::ReserveData<ReserveIdentifier, Balance>
      **/
     PalletBalancesReserveData: {
         id: '[u8;8]',
         amount: 'u128',
     },
     /**
-     * Lookup119: pallet_balances::Releases
+     * Lookup120: pallet_balances::Releases
      **/
     PalletBalancesReleases: {
         _enum: ['V1_0_0', 'V2_0_0'],
     },
     /**
-     * Lookup120: pallet_balances::pallet::Call<T, I>
+     * Lookup121: pallet_balances::pallet::Call<T, I>
      **/
     PalletBalancesCall: {
         _enum: {
@@ -739,7 +750,7 @@ export default {
         },
     },
     /**
-     * Lookup124: pallet_balances::pallet::Error<T, I>
+     * Lookup125: pallet_balances::pallet::Error<T, I>
      **/
     PalletBalancesError: {
         _enum: [
@@ -754,13 +765,13 @@ export default {
         ],
     },
     /**
-     * Lookup126: pallet_transaction_payment::Releases
+     * Lookup127: pallet_transaction_payment::Releases
      **/
     PalletTransactionPaymentReleases: {
         _enum: ['V1Ancient', 'V2'],
     },
     /**
-     * Lookup127: pallet_sudo::pallet::Call<T>
+     * Lookup128: pallet_sudo::pallet::Call<T>
      **/
     PalletSudoCall: {
         _enum: {
@@ -784,7 +795,7 @@ export default {
         },
     },
     /**
-     * Lookup129: pallet_creditcoin::pallet::Call<T>
+     * Lookup130: pallet_creditcoin::pallet::Call<T>
      **/
     PalletCreditcoinCall: {
         _enum: {
@@ -891,15 +902,15 @@ export default {
         },
     },
     /**
-     * Lookup130: sp_core::ecdsa::Public
+     * Lookup131: sp_core::ecdsa::Public
      **/
     SpCoreEcdsaPublic: '[u8;33]',
     /**
-     * Lookup132: sp_core::ecdsa::Signature
+     * Lookup133: sp_core::ecdsa::Signature
      **/
     SpCoreEcdsaSignature: '[u8;65]',
     /**
-     * Lookup134: sp_runtime::MultiSigner
+     * Lookup135: sp_runtime::MultiSigner
      **/
     SpRuntimeMultiSigner: {
         _enum: {
@@ -909,15 +920,15 @@ export default {
         },
     },
     /**
-     * Lookup135: sp_core::ed25519::Public
+     * Lookup136: sp_core::ed25519::Public
      **/
     SpCoreEd25519Public: '[u8;32]',
     /**
-     * Lookup136: sp_core::sr25519::Public
+     * Lookup137: sp_core::sr25519::Public
      **/
     SpCoreSr25519Public: '[u8;32]',
     /**
-     * Lookup137: sp_runtime::MultiSignature
+     * Lookup138: sp_runtime::MultiSignature
      **/
     SpRuntimeMultiSignature: {
         _enum: {
@@ -927,15 +938,15 @@ export default {
         },
     },
     /**
-     * Lookup138: sp_core::ed25519::Signature
+     * Lookup139: sp_core::ed25519::Signature
      **/
     SpCoreEd25519Signature: '[u8;64]',
     /**
-     * Lookup140: sp_core::sr25519::Signature
+     * Lookup141: sp_core::sr25519::Signature
      **/
     SpCoreSr25519Signature: '[u8;64]',
     /**
-     * Lookup141: pallet_creditcoin::types::LegacyTransferKind
+     * Lookup142: pallet_creditcoin::types::LegacyTransferKind
      **/
     PalletCreditcoinLegacyTransferKind: {
         _enum: {
@@ -946,7 +957,7 @@ export default {
         },
     },
     /**
-     * Lookup142: pallet_creditcoin::types::TaskOutput<sp_core::crypto::AccountId32, Balance, BlockNum, primitive_types::H256, Moment>
+     * Lookup143: pallet_creditcoin::types::TaskOutput<sp_core::crypto::AccountId32, Balance, BlockNum, primitive_types::H256, Moment>
      **/
     PalletCreditcoinTaskOutput: {
         _enum: {
@@ -955,7 +966,7 @@ export default {
         },
     },
     /**
-     * Lookup143: pallet_creditcoin::types::TaskId<primitive_types::H256>
+     * Lookup144: pallet_creditcoin::types::TaskId<primitive_types::H256>
      **/
     PalletCreditcoinTaskId: {
         _enum: {
@@ -964,7 +975,7 @@ export default {
         },
     },
     /**
-     * Lookup144: pallet_difficulty::pallet::Call<T>
+     * Lookup145: pallet_difficulty::pallet::Call<T>
      **/
     PalletDifficultyCall: {
         _enum: {
@@ -977,7 +988,7 @@ export default {
         },
     },
     /**
-     * Lookup146: pallet_scheduler::pallet::Call<T>
+     * Lookup147: pallet_scheduler::pallet::Call<T>
      **/
     PalletSchedulerCall: {
         _enum: {
@@ -1017,13 +1028,13 @@ export default {
         },
     },
     /**
-     * Lookup148: pallet_sudo::pallet::Error<T>
+     * Lookup149: pallet_sudo::pallet::Error<T>
      **/
     PalletSudoError: {
         _enum: ['RequireSudo'],
     },
     /**
-     * Lookup150: pallet_creditcoin::types::Task<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256, Moment>
+     * Lookup151: pallet_creditcoin::types::Task<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256, Moment>
      **/
     PalletCreditcoinTask: {
         _enum: {
@@ -1032,7 +1043,7 @@ export default {
         },
     },
     /**
-     * Lookup151: pallet_creditcoin::types::transfer::UnverifiedTransfer<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256, Moment>
+     * Lookup152: pallet_creditcoin::types::transfer::UnverifiedTransfer<sp_core::crypto::AccountId32, BlockNum, primitive_types::H256, Moment>
      **/
     PalletCreditcoinTransferUnverifiedTransfer: {
         transfer: 'PalletCreditcoinTransfer',
@@ -1042,7 +1053,7 @@ export default {
         currencyToCheck: 'PalletCreditcoinCurrencyOrLegacyTransferKind',
     },
     /**
-     * Lookup152: pallet_creditcoin::types::CurrencyOrLegacyTransferKind
+     * Lookup153: pallet_creditcoin::types::CurrencyOrLegacyTransferKind
      **/
     PalletCreditcoinCurrencyOrLegacyTransferKind: {
         _enum: {
@@ -1051,7 +1062,7 @@ export default {
         },
     },
     /**
-     * Lookup154: pallet_creditcoin::pallet::Error<T>
+     * Lookup155: pallet_creditcoin::pallet::Error<T>
      **/
     PalletCreditcoinError: {
         _enum: [
@@ -1112,20 +1123,20 @@ export default {
         ],
     },
     /**
-     * Lookup156: pallet_difficulty::DifficultyAndTimestamp<Moment>
+     * Lookup157: pallet_difficulty::DifficultyAndTimestamp<Moment>
      **/
     PalletDifficultyDifficultyAndTimestamp: {
         difficulty: 'U256',
         timestamp: 'u64',
     },
     /**
-     * Lookup158: pallet_difficulty::pallet::Error<T>
+     * Lookup159: pallet_difficulty::pallet::Error<T>
      **/
     PalletDifficultyError: {
         _enum: ['ZeroTargetTime', 'ZeroAdjustmentPeriod', 'NegativeAdjustmentPeriod'],
     },
     /**
-     * Lookup161: pallet_scheduler::Scheduled<Name, frame_support::traits::preimages::Bounded<creditcoin_node_runtime::RuntimeCall>, BlockNumber, creditcoin_node_runtime::OriginCaller, sp_core::crypto::AccountId32>
+     * Lookup162: pallet_scheduler::Scheduled<Name, frame_support::traits::preimages::Bounded<creditcoin_node_runtime::RuntimeCall>, BlockNumber, creditcoin_node_runtime::OriginCaller, sp_core::crypto::AccountId32>
      **/
     PalletSchedulerScheduled: {
         maybeId: 'Option<[u8;32]>',
@@ -1135,7 +1146,7 @@ export default {
         origin: 'CreditcoinNodeRuntimeOriginCaller',
     },
     /**
-     * Lookup162: frame_support::traits::preimages::Bounded<creditcoin_node_runtime::RuntimeCall>
+     * Lookup163: frame_support::traits::preimages::Bounded<creditcoin_node_runtime::RuntimeCall>
      **/
     FrameSupportPreimagesBounded: {
         _enum: {
@@ -1156,7 +1167,7 @@ export default {
         },
     },
     /**
-     * Lookup164: creditcoin_node_runtime::OriginCaller
+     * Lookup165: creditcoin_node_runtime::OriginCaller
      **/
     CreditcoinNodeRuntimeOriginCaller: {
         _enum: {
@@ -1165,7 +1176,7 @@ export default {
         },
     },
     /**
-     * Lookup165: frame_support::dispatch::RawOrigin<sp_core::crypto::AccountId32>
+     * Lookup166: frame_support::dispatch::RawOrigin<sp_core::crypto::AccountId32>
      **/
     FrameSupportDispatchRawOrigin: {
         _enum: {
@@ -1175,51 +1186,51 @@ export default {
         },
     },
     /**
-     * Lookup166: sp_core::Void
+     * Lookup167: sp_core::Void
      **/
     SpCoreVoid: 'Null',
     /**
-     * Lookup168: pallet_scheduler::pallet::Error<T>
+     * Lookup169: pallet_scheduler::pallet::Error<T>
      **/
     PalletSchedulerError: {
         _enum: ['FailedToSchedule', 'NotFound', 'TargetBlockNumberInPast', 'RescheduleNoChange', 'Named'],
     },
     /**
-     * Lookup169: pallet_offchain_task_scheduler::pallet::Error<T>
+     * Lookup170: pallet_offchain_task_scheduler::pallet::Error<T>
      **/
     PalletOffchainTaskSchedulerError: {
         _enum: ['OffchainSignedTxFailed', 'NoLocalAcctForSignedTx'],
     },
     /**
-     * Lookup172: frame_system::extensions::check_non_zero_sender::CheckNonZeroSender<T>
+     * Lookup173: frame_system::extensions::check_non_zero_sender::CheckNonZeroSender<T>
      **/
     FrameSystemExtensionsCheckNonZeroSender: 'Null',
     /**
-     * Lookup173: frame_system::extensions::check_spec_version::CheckSpecVersion<T>
+     * Lookup174: frame_system::extensions::check_spec_version::CheckSpecVersion<T>
      **/
     FrameSystemExtensionsCheckSpecVersion: 'Null',
     /**
-     * Lookup174: frame_system::extensions::check_tx_version::CheckTxVersion<T>
+     * Lookup175: frame_system::extensions::check_tx_version::CheckTxVersion<T>
      **/
     FrameSystemExtensionsCheckTxVersion: 'Null',
     /**
-     * Lookup175: frame_system::extensions::check_genesis::CheckGenesis<T>
+     * Lookup176: frame_system::extensions::check_genesis::CheckGenesis<T>
      **/
     FrameSystemExtensionsCheckGenesis: 'Null',
     /**
-     * Lookup178: frame_system::extensions::check_nonce::CheckNonce<T>
+     * Lookup179: frame_system::extensions::check_nonce::CheckNonce<T>
      **/
     FrameSystemExtensionsCheckNonce: 'Compact<u32>',
     /**
-     * Lookup179: frame_system::extensions::check_weight::CheckWeight<T>
+     * Lookup180: frame_system::extensions::check_weight::CheckWeight<T>
      **/
     FrameSystemExtensionsCheckWeight: 'Null',
     /**
-     * Lookup180: pallet_transaction_payment::ChargeTransactionPayment<T>
+     * Lookup181: pallet_transaction_payment::ChargeTransactionPayment<T>
      **/
     PalletTransactionPaymentChargeTransactionPayment: 'Compact<u128>',
     /**
-     * Lookup181: creditcoin_node_runtime::Runtime
+     * Lookup182: creditcoin_node_runtime::Runtime
      **/
     CreditcoinNodeRuntimeRuntime: 'Null',
 };
@@ -62,6 +62,7 @@ import type {
     PalletCreditcoinLoanTermsDuration,
     PalletCreditcoinLoanTermsInterestRate,
     PalletCreditcoinLoanTermsInterestType,
+    PalletCreditcoinLoanTermsLoanTermRanges,
     PalletCreditcoinOcwErrorsVerificationFailureCause,
     PalletCreditcoinOcwTasksCollectCoinsGCreContract,
     PalletCreditcoinOffer,
@@ -174,6 +175,7 @@ declare module '@polkadot/types/types/registry' {
         PalletCreditcoinLoanTermsDuration: PalletCreditcoinLoanTermsDuration;
         PalletCreditcoinLoanTermsInterestRate: PalletCreditcoinLoanTermsInterestRate;
         PalletCreditcoinLoanTermsInterestType: PalletCreditcoinLoanTermsInterestType;
+        PalletCreditcoinLoanTermsLoanTermRanges: PalletCreditcoinLoanTermsLoanTermRanges;
         PalletCreditcoinOcwErrorsVerificationFailureCause: PalletCreditcoinOcwErrorsVerificationFailureCause;
         PalletCreditcoinOcwTasksCollectCoinsGCreContract: PalletCreditcoinOcwTasksCollectCoinsGCreContract;
         PalletCreditcoinOffer: PalletCreditcoinOffer;
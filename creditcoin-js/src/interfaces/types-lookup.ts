@@ -466,9 +466,19 @@ declare module '@polkadot/types/lookup' {
     }
 
     /** @name PalletCreditcoinLoanTermsAskTerms (60) */
-    interface PalletCreditcoinLoanTermsAskTerms extends PalletCreditcoinLoanTerms {}
+    interface PalletCreditcoinLoanTermsAskTerms extends PalletCreditcoinLoanTermsLoanTermRanges {}
 
-    /** @name PalletCreditcoinLoanTerms (61) */
+    /** @name PalletCreditcoinLoanTermsLoanTermRanges (61) */
+    interface PalletCreditcoinLoanTermsLoanTermRanges extends Struct {
+        readonly minAmount: U256;
+        readonly maxAmount: U256;
+        readonly interestRate: PalletCreditcoinLoanTermsInterestRate;
+        readonly minTermLength: PalletCreditcoinLoanTermsDuration;
+        readonly maxTermLength: PalletCreditcoinLoanTermsDuration;
+        readonly currency: H256;
+    }
+
+    /** @name PalletCreditcoinLoanTerms (62) */
     interface PalletCreditcoinLoanTerms extends Struct {
         readonly amount: U256;
         readonly interestRate: PalletCreditcoinLoanTermsInterestRate;
@@ -476,7 +486,7 @@ declare module '@polkadot/types/lookup' {
         readonly currency: H256;
     }
 
-    /** @name PalletCreditcoinLoanTermsInterestRate (62) */
+    /** @name PalletCreditcoinLoanTermsInterestRate (63) */
     interface PalletCreditcoinLoanTermsInterestRate extends Struct {
         readonly ratePerPeriod: u64;
         readonly decimals: u64;
@@ -484,23 +494,23 @@ declare module '@polkadot/types/lookup' {
         readonly interestType: PalletCreditcoinLoanTermsInterestType;
     }
 
-    /** @name PalletCreditcoinLoanTermsDuration (63) */
+    /** @name PalletCreditcoinLoanTermsDuration (64) */
     interface PalletCreditcoinLoanTermsDuration extends Struct {
         readonly secs: u64;
         readonly nanos: u32;
     }
 
-    /** @name PalletCreditcoinLoanTermsInterestType (64) */
+    /** @name PalletCreditcoinLoanTermsInterestType (65) */
     interface PalletCreditcoinLoanTermsInterestType extends Enum {
         readonly isSimple: boolean;
         readonly isCompound: boolean;
         readonly type: 'Simple' | 'Compound';
     }
 
-    /** @name PalletCreditcoinBidOrderId (66) */
+    /** @name PalletCreditcoinBidOrderId (67) */
     interface PalletCreditcoinBidOrderId extends ITuple<[u32, H256]> {}
 
-    /** @name PalletCreditcoinBidOrder (67) */
+    /** @name PalletCreditcoinBidOrder (68) */
     interface PalletCreditcoinBidOrder extends Struct {
         readonly borrowerAddressId: H256;
         readonly terms: PalletCreditcoinLoanTermsBidTerms;
@@ -509,13 +519,13 @@ declare module '@polkadot/types/lookup' {
         readonly borrower: AccountId32;
     }
 
-    /** @name PalletCreditcoinLoanTermsBidTerms (68) */
-    interface PalletCreditcoinLoanTermsBidTerms extends PalletCreditcoinLoanTerms {}
+    /** @name PalletCreditcoinLoanTermsBidTerms (69) */
+    interface PalletCreditcoinLoanTermsBidTerms extends PalletCreditcoinLoanTermsLoanTermRanges {}
 
-    /** @name PalletCreditcoinOfferId (69) */
+    /** @name PalletCreditcoinOfferId (70) */
     interface PalletCreditcoinOfferId extends ITuple<[u32, H256]> {}
 
-    /** @name PalletCreditcoinOffer (70) */
+    /** @name PalletCreditcoinOffer (71) */
     interface PalletCreditcoinOffer extends Struct {
         readonly askId: PalletCreditcoinAskOrderId;
         readonly bidId: PalletCreditcoinBidOrderId;
@@ -524,7 +534,7 @@ declare module '@polkadot/types/lookup' {
         readonly lender: AccountId32;
     }
 
-    /** @name PalletCreditcoinDealOrder (71) */
+    /** @name PalletCreditcoinDealOrder (72) */
     interface PalletCreditcoinDealOrder extends Struct {
         readonly offerId: PalletCreditcoinOfferId;
         readonly lenderAddressId: H256;
@@ -539,10 +549,10 @@ declare module '@polkadot/types/lookup' {
         readonly borrower: AccountId32;
     }
 
-    /** @name PalletCreditcoinLegacySighash (74) */
+    /** @name PalletCreditcoinLegacySighash (75) */
     interface PalletCreditcoinLegacySighash extends U8aFixed {}
 
-    /** @name PalletCreditcoinOcwErrorsVerificationFailureCause (76) */
+    /** @name PalletCreditcoinOcwErrorsVerificationFailureCause (77) */
     interface PalletCreditcoinOcwErrorsVerificationFailureCause extends Enum {
         readonly isTaskNonexistent: boolean;
         readonly isTaskFailed: boolean;
@@ -585,28 +595,28 @@ declare module '@polkadot/types/lookup' {
             | 'TransactionNotFound';
     }
 
-    /** @name PalletCreditcoinPlatformCurrency (77) */
+    /** @name PalletCreditcoinPlatformCurrency (78) */
     interface PalletCreditcoinPlatformCurrency extends Enum {
         readonly isEvm: boolean;
         readonly asEvm: ITuple<[PalletCreditcoinPlatformEvmCurrencyType, PalletCreditcoinPlatformEvmInfo]>;
         readonly type: 'Evm';
     }
 
-    /** @name PalletCreditcoinPlatformEvmCurrencyType (78) */
+    /** @name PalletCreditcoinPlatformEvmCurrencyType (79) */
     interface PalletCreditcoinPlatformEvmCurrencyType extends Enum {
         readonly isSmartContract: boolean;
         readonly asSmartContract: ITuple<[Bytes, Vec<PalletCreditcoinPlatformEvmTransferKind>]>;
         readonly type: 'SmartContract';
     }
 
-    /** @name PalletRewardsEvent (81) */
+    /** @name PalletRewardsEvent (82) */
     interface PalletRewardsEvent extends Enum {
         readonly isRewardIssued: boolean;
         readonly asRewardIssued: ITuple<[AccountId32, u128]>;
         readonly type: 'RewardIssued';
     }
 
-    /** @name PalletSchedulerEvent (82) */
+    /** @name PalletSchedulerEvent (83) */
     interface PalletSchedulerEvent extends Enum {
         readonly isScheduled: boolean;
         readonly asScheduled: {
@@ -648,10 +658,10 @@ declare module '@polkadot/types/lookup' {
             | 'PermanentlyOverweight';
     }
 
-    /** @name PalletOffchainTaskSchedulerEvent (85) */
+    /** @name PalletOffchainTaskSchedulerEvent (86) */
     type PalletOffchainTaskSchedulerEvent = Null;
 
-    /** @name FrameSystemPhase (86) */
+    /** @name FrameSystemPhase (87) */
     interface FrameSystemPhase extends Enum {
         readonly isApplyExtrinsic: boolean;
         readonly asApplyExtrinsic: u32;
@@ -660,13 +670,13 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'ApplyExtrinsic' | 'Finalization' | 'Initialization';
     }
 
-    /** @name FrameSystemLastRuntimeUpgradeInfo (89) */
+    /** @name FrameSystemLastRuntimeUpgradeInfo (90) */
     interface FrameSystemLastRuntimeUpgradeInfo extends Struct {
         readonly specVersion: Compact<u32>;
         readonly specName: Text;
     }
 
-    /** @name FrameSystemCall (92) */
+    /** @name FrameSystemCall (93) */
     interface FrameSystemCall extends Enum {
         readonly isFillBlock: boolean;
         readonly asFillBlock: {
@@ -717,21 +727,21 @@ declare module '@polkadot/types/lookup' {
             | 'RemarkWithEvent';
     }
 
-    /** @name FrameSystemLimitsBlockWeights (97) */
+    /** @name FrameSystemLimitsBlockWeights (98) */
     interface FrameSystemLimitsBlockWeights extends Struct {
         readonly baseBlock: SpWeightsWeightV2Weight;
         readonly maxBlock: SpWeightsWeightV2Weight;
         readonly perClass: FrameSupportDispatchPerDispatchClassWeightsPerClass;
     }
 
-    /** @name FrameSupportDispatchPerDispatchClassWeightsPerClass (98) */
+    /** @name FrameSupportDispatchPerDispatchClassWeightsPerClass (99) */
     interface FrameSupportDispatchPerDispatchClassWeightsPerClass extends Struct {
         readonly normal: FrameSystemLimitsWeightsPerClass;
         readonly operational: FrameSystemLimitsWeightsPerClass;
         readonly mandatory: FrameSystemLimitsWeightsPerClass;
     }
 
-    /** @name FrameSystemLimitsWeightsPerClass (99) */
+    /** @name FrameSystemLimitsWeightsPerClass (100) */
     interface FrameSystemLimitsWeightsPerClass extends Struct {
         readonly baseExtrinsic: SpWeightsWeightV2Weight;
         readonly maxExtrinsic: Option<SpWeightsWeightV2Weight>;
@@ -739,25 +749,25 @@ declare module '@polkadot/types/lookup' {
         readonly reserved: Option<SpWeightsWeightV2Weight>;
     }
 
-    /** @name FrameSystemLimitsBlockLength (101) */
+    /** @name FrameSystemLimitsBlockLength (102) */
     interface FrameSystemLimitsBlockLength extends Struct {
         readonly max: FrameSupportDispatchPerDispatchClassU32;
     }
 
-    /** @name FrameSupportDispatchPerDispatchClassU32 (102) */
+    /** @name FrameSupportDispatchPerDispatchClassU32 (103) */
     interface FrameSupportDispatchPerDispatchClassU32 extends Struct {
         readonly normal: u32;
         readonly operational: u32;
         readonly mandatory: u32;
     }
 
-    /** @name SpWeightsRuntimeDbWeight (103) */
+    /** @name SpWeightsRuntimeDbWeight (104) */
     interface SpWeightsRuntimeDbWeight extends Struct {
         readonly read: u64;
         readonly write: u64;
     }
 
-    /** @name SpVersionRuntimeVersion (104) */
+    /** @name SpVersionRuntimeVersion (105) */
     interface SpVersionRuntimeVersion extends Struct {
         readonly specName: Text;
         readonly implName: Text;
@@ -769,7 +779,7 @@ declare module '@polkadot/types/lookup' {
         readonly stateVersion: u8;
     }
 
-    /** @name FrameSystemError (110) */
+    /** @name FrameSystemError (111) */
     interface FrameSystemError extends Enum {
         readonly isInvalidSpecName: boolean;
         readonly isSpecVersionNeedsToIncrease: boolean;
@@ -786,7 +796,7 @@ declare module '@polkadot/types/lookup' {
             | 'CallFiltered';
     }
 
-    /** @name PalletTimestampCall (111) */
+    /** @name PalletTimestampCall (112) */
     interface PalletTimestampCall extends Enum {
         readonly isSet: boolean;
         readonly asSet: {
@@ -795,14 +805,14 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Set';
     }
 
-    /** @name PalletBalancesBalanceLock (113) */
+    /** @name PalletBalancesBalanceLock (114) */
     interface PalletBalancesBalanceLock extends Struct {
         readonly id: U8aFixed;
         readonly amount: u128;
         readonly reasons: PalletBalancesReasons;
     }
 
-    /** @name PalletBalancesReasons (114) */
+    /** @name PalletBalancesReasons (115) */
     interface PalletBalancesReasons extends Enum {
         readonly isFee: boolean;
         readonly isMisc: boolean;
@@ -810,20 +820,20 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Fee' | 'Misc' | 'All';
     }
 
-    /** @name PalletBalancesReserveData (117) */
+    /** @name PalletBalancesReserveData (118) */
     interface PalletBalancesReserveData extends Struct {
         readonly id: U8aFixed;
         readonly amount: u128;
     }
 
-    /** @name PalletBalancesReleases (119) */
+    /** @name PalletBalancesReleases (120) */
     interface PalletBalancesReleases extends Enum {
         readonly isV100: boolean;
         readonly isV200: boolean;
         readonly type: 'V100' | 'V200';
     }
 
-    /** @name PalletBalancesCall (120) */
+    /** @name PalletBalancesCall (121) */
     interface PalletBalancesCall extends Enum {
         readonly isTransfer: boolean;
         readonly asTransfer: {
@@ -866,7 +876,7 @@ declare module '@polkadot/types/lookup' {
             | 'ForceUnreserve';
     }
 
-    /** @name PalletBalancesError (124) */
+    /** @name PalletBalancesError (125) */
     interface PalletBalancesError extends Enum {
         readonly isVestingBalance: boolean;
         readonly isLiquidityRestrictions: boolean;
@@ -887,14 +897,14 @@ declare module '@polkadot/types/lookup' {
             | 'TooManyReserves';
     }
 
-    /** @name PalletTransactionPaymentReleases (126) */
+    /** @name PalletTransactionPaymentReleases (127) */
     interface PalletTransactionPaymentReleases extends Enum {
         readonly isV1Ancient: boolean;
         readonly isV2: boolean;
         readonly type: 'V1Ancient' | 'V2';
     }
 
-    /** @name PalletSudoCall (127) */
+    /** @name PalletSudoCall (128) */
     interface PalletSudoCall extends Enum {
         readonly isSudo: boolean;
         readonly asSudo: {
@@ -917,7 +927,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Sudo' | 'SudoUncheckedWeight' | 'SetKey' | 'SudoAs';
     }
 
-    /** @name PalletCreditcoinCall (129) */
+    /** @name PalletCreditcoinCall (130) */
     interface PalletCreditcoinCall extends Enum {
         readonly isClaimLegacyWallet: boolean;
         readonly asClaimLegacyWallet: {
@@ -1066,13 +1076,13 @@ declare module '@polkadot/types/lookup' {
             | 'RemoveAuthority';
     }
 
-    /** @name SpCoreEcdsaPublic (130) */
+    /** @name SpCoreEcdsaPublic (131) */
     interface SpCoreEcdsaPublic extends U8aFixed {}
 
-    /** @name SpCoreEcdsaSignature (132) */
+    /** @name SpCoreEcdsaSignature (133) */
     interface SpCoreEcdsaSignature extends U8aFixed {}
 
-    /** @name SpRuntimeMultiSigner (134) */
+    /** @name SpRuntimeMultiSigner (135) */
     interface SpRuntimeMultiSigner extends Enum {
         readonly isEd25519: boolean;
         readonly asEd25519: SpCoreEd25519Public;
@@ -1083,13 +1093,13 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Ed25519' | 'Sr25519' | 'Ecdsa';
     }
 
-    /** @name SpCoreEd25519Public (135) */
+    /** @name SpCoreEd25519Public (136) */
     interface SpCoreEd25519Public extends U8aFixed {}
 
-    /** @name SpCoreSr25519Public (136) */
+    /** @name SpCoreSr25519Public (137) */
     interface SpCoreSr25519Public extends U8aFixed {}
 
-    /** @name SpRuntimeMultiSignature (137) */
+    /** @name SpRuntimeMultiSignature (138) */
     interface SpRuntimeMultiSignature extends Enum {
         readonly isEd25519: boolean;
         readonly asEd25519: SpCoreEd25519Signature;
@@ -1100,13 +1110,13 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Ed25519' | 'Sr25519' | 'Ecdsa';
     }
 
-    /** @name SpCoreEd25519Signature (138) */
+    /** @name SpCoreEd25519Signature (139) */
     interface SpCoreEd25519Signature extends U8aFixed {}
 
-    /** @name SpCoreSr25519Signature (140) */
+    /** @name SpCoreSr25519Signature (141) */
     interface SpCoreSr25519Signature extends U8aFixed {}
 
-    /** @name PalletCreditcoinLegacyTransferKind (141) */
+    /** @name PalletCreditcoinLegacyTransferKind (142) */
     interface PalletCreditcoinLegacyTransferKind extends Enum {
         readonly isErc20: boolean;
         readonly asErc20: Bytes;
@@ -1118,7 +1128,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Erc20' | 'Ethless' | 'Native' | 'Other';
     }
 
-    /** @name PalletCreditcoinTaskOutput (142) */
+    /** @name PalletCreditcoinTaskOutput (143) */
     interface PalletCreditcoinTaskOutput extends Enum {
         readonly isVerifyTransfer: boolean;
         readonly asVerifyTransfer: ITuple<[H256, PalletCreditcoinTransfer]>;
@@ -1127,7 +1137,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'VerifyTransfer' | 'CollectCoins';
     }
 
-    /** @name PalletCreditcoinTaskId (143) */
+    /** @name PalletCreditcoinTaskId (144) */
     interface PalletCreditcoinTaskId extends Enum {
         readonly isVerifyTransfer: boolean;
         readonly asVerifyTransfer: H256;
@@ -1136,7 +1146,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'VerifyTransfer' | 'CollectCoins';
     }
 
-    /** @name PalletDifficultyCall (144) */
+    /** @name PalletDifficultyCall (145) */
     interface PalletDifficultyCall extends Enum {
         readonly isSetTargetBlockTime: boolean;
         readonly asSetTargetBlockTime: {
@@ -1149,7 +1159,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'SetTargetBlockTime' | 'SetAdjustmentPeriod';
     }
 
-    /** @name PalletSchedulerCall (146) */
+    /** @name PalletSchedulerCall (147) */
     interface PalletSchedulerCall extends Enum {
         readonly isSchedule: boolean;
         readonly asSchedule: {
@@ -1193,13 +1203,13 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Schedule' | 'Cancel' | 'ScheduleNamed' | 'CancelNamed' | 'ScheduleAfter' | 'ScheduleNamedAfter';
     }
 
-    /** @name PalletSudoError (148) */
+    /** @name PalletSudoError (149) */
     interface PalletSudoError extends Enum {
         readonly isRequireSudo: boolean;
         readonly type: 'RequireSudo';
     }
 
-    /** @name PalletCreditcoinTask (150) */
+    /** @name PalletCreditcoinTask (151) */
     interface PalletCreditcoinTask extends Enum {
         readonly isVerifyTransfer: boolean;
         readonly asVerifyTransfer: PalletCreditcoinTransferUnverifiedTransfer;
@@ -1208,7 +1218,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'VerifyTransfer' | 'CollectCoins';
     }
 
-    /** @name PalletCreditcoinTransferUnverifiedTransfer (151) */
+    /** @name PalletCreditcoinTransferUnverifiedTransfer (152) */
     interface PalletCreditcoinTransferUnverifiedTransfer extends Struct {
         readonly transfer: PalletCreditcoinTransfer;
         readonly fromExternal: Bytes;
@@ -1217,7 +1227,7 @@ declare module '@polkadot/types/lookup' {
         readonly currencyToCheck: PalletCreditcoinCurrencyOrLegacyTransferKind;
     }
 
-    /** @name PalletCreditcoinCurrencyOrLegacyTransferKind (152) */
+    /** @name PalletCreditcoinCurrencyOrLegacyTransferKind (153) */
     interface PalletCreditcoinCurrencyOrLegacyTransferKind extends Enum {
         readonly isCurrency: boolean;
         readonly asCurrency: PalletCreditcoinPlatformCurrency;
@@ -1226,7 +1236,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Currency' | 'TransferKind';
     }
 
-    /** @name PalletCreditcoinError (154) */
+    /** @name PalletCreditcoinError (155) */
     interface PalletCreditcoinError extends Enum {
         readonly isAddressAlreadyRegistered: boolean;
         readonly isNonExistentAddress: boolean;
@@ -1339,13 +1349,13 @@ declare module '@polkadot/types/lookup' {
             | 'CurrencyNotRegistered';
     }
 
-    /** @name PalletDifficultyDifficultyAndTimestamp (156) */
+    /** @name PalletDifficultyDifficultyAndTimestamp (157) */
     interface PalletDifficultyDifficultyAndTimestamp extends Struct {
         readonly difficulty: U256;
         readonly timestamp: u64;
     }
 
-    /** @name PalletDifficultyError (158) */
+    /** @name PalletDifficultyError (159) */
     interface PalletDifficultyError extends Enum {
         readonly isZeroTargetTime: boolean;
         readonly isZeroAdjustmentPeriod: boolean;
@@ -1353,7 +1363,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'ZeroTargetTime' | 'ZeroAdjustmentPeriod' | 'NegativeAdjustmentPeriod';
     }
 
-    /** @name PalletSchedulerScheduled (161) */
+    /** @name PalletSchedulerScheduled (162) */
     interface PalletSchedulerScheduled extends Struct {
         readonly maybeId: Option<U8aFixed>;
         readonly priority: u8;
@@ -1362,7 +1372,7 @@ declare module '@polkadot/types/lookup' {
         readonly origin: CreditcoinNodeRuntimeOriginCaller;
     }
 
-    /** @name FrameSupportPreimagesBounded (162) */
+    /** @name FrameSupportPreimagesBounded (163) */
     interface FrameSupportPreimagesBounded extends Enum {
         readonly isLegacy: boolean;
         readonly asLegacy: {
@@ -1378,7 +1388,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Legacy' | 'Inline' | 'Lookup';
     }
 
-    /** @name CreditcoinNodeRuntimeOriginCaller (164) */
+    /** @name CreditcoinNodeRuntimeOriginCaller (165) */
     interface CreditcoinNodeRuntimeOriginCaller extends Enum {
         readonly isSystem: boolean;
         readonly asSystem: FrameSupportDispatchRawOrigin;
@@ -1386,7 +1396,7 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'System' | 'Void';
     }
 
-    /** @name FrameSupportDispatchRawOrigin (165) */
+    /** @name FrameSupportDispatchRawOrigin (166) */
     interface FrameSupportDispatchRawOrigin extends Enum {
         readonly isRoot: boolean;
         readonly isSigned: boolean;
@@ -1395,10 +1405,10 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'Root' | 'Signed' | 'None';
     }
 
-    /** @name SpCoreVoid (166) */
+    /** @name SpCoreVoid (167) */
     type SpCoreVoid = Null;
 
-    /** @name PalletSchedulerError (168) */
+    /** @name PalletSchedulerError (169) */
     interface PalletSchedulerError extends Enum {
         readonly isFailedToSchedule: boolean;
         readonly isNotFound: boolean;
@@ -1408,34 +1418,34 @@ declare module '@polkadot/types/lookup' {
         readonly type: 'FailedToSchedule' | 'NotFound' | 'TargetBlockNumberInPast' | 'RescheduleNoChange' | 'Named';
     }
 
-    /** @name PalletOffchainTaskSchedulerError (169) */
+    /** @name PalletOffchainTaskSchedulerError (170) */
     interface PalletOffchainTaskSchedulerError extends Enum {
         readonly isOffchainSignedTxFailed: boolean;
         readonly isNoLocalAcctForSignedTx: boolean;
         readonly type: 'OffchainSignedTxFailed' | 'NoLocalAcctForSignedTx';
     }
 
-    /** @name FrameSystemExtensionsCheckNonZeroSender (172) */
+    /** @name FrameSystemExtensionsCheckNonZeroSender (173) */
     type FrameSystemExtensionsCheckNonZeroSender = Null;
 
-    /** @name FrameSystemExtensionsCheckSpecVersion (173) */
+    /** @name FrameSystemExtensionsCheckSpecVersion (174) */
     type FrameSystemExtensionsCheckSpecVersion = Null;
 
-    /** @name FrameSystemExtensionsCheckTxVersion (174) */
+    /** @name FrameSystemExtensionsCheckTxVersion (175) */
     type FrameSystemExtensionsCheckTxVersion = Null;
 
-    /** @name FrameSystemExtensionsCheckGenesis (175) */
+    /** @name FrameSystemExtensionsCheckGenesis (176) */
     type FrameSystemExtensionsCheckGenesis = Null;
 
-    /** @name FrameSystemExtensionsCheckNonce (178) */
+    /** @name FrameSystemExtensionsCheckNonce (179) */
     interface FrameSystemExtensionsCheckNonce extends Compact<u32> {}
 
-    /** @name FrameSystemExtensionsCheckWeight (179) */
+    /** @name FrameSystemExtensionsCheckWeight (180) */
     type FrameSystemExtensionsCheckWeight = Null;
 
-    /** @name PalletTransactionPaymentChargeTransactionPayment (180) */
+    /** @name PalletTransactionPaymentChargeTransactionPayment (181) */
     interface PalletTransactionPaymentChargeTransactionPayment extends Compact<u128> {}
 
-    /** @name CreditcoinNodeRuntimeRuntime (181) */
+    /** @name CreditcoinNodeRuntimeRuntime (182) */
     type CreditcoinNodeRuntimeRuntime = Null;
 } // declare module
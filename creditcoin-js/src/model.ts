@@ -72,12 +72,21 @@ export type LoanTerms = {
     currency: CurrencyId;
 };
 
+export type LoanTermRanges = {
+    minAmount: BN;
+    maxAmount: BN;
+    interestRate: InterestRate;
+    minTermLength: Duration;
+    maxTermLength: Duration;
+    currency: CurrencyId;
+};
+
 export type TupleId = [number, string];
 export type AskOrderId = TupleId;
 export type BidOrderId = TupleId;
 
 type AskOrBidOrderBase = {
-    loanTerms: LoanTerms;
+    termRanges: LoanTermRanges;
     expirationBlock: number;
     blockNumber: number;
 };
@@ -9,7 +9,7 @@ import { PalletCreditcoinAddress } from '@polkadot/types/lookup';
 
 import { Blockchain, LoanTerms, DealOrderId, Currency } from './model';
 import { CreditcoinApi } from './types';
-import { createAddress } from './transforms';
+import { createAddress, exactTermRanges } from './transforms';
 import { EthConnection } from './examples/ethereum';
 import { AddressRegistered, createAddressId } from './extrinsics/register-address';
 import { createCurrencyId, registerCurrencyAsync } from './extrinsics/register-currency';
@@ -94,8 +94,8 @@ export const addAskAndBidOrder = async (
     const bidGuid = Guid.newGuid();
 
     const [askOrderAdded, bidOrderAdded] = await Promise.all([
-        addAskOrder(lenderRegAddr.itemId, loanTerms, expirationBlock, askGuid, lender),
-        addBidOrder(borrowerRegAddr.itemId, loanTerms, expirationBlock, bidGuid, borrower),
+        addAskOrder(lenderRegAddr.itemId, exactTermRanges(loanTerms), expirationBlock, askGuid, lender),
+        addBidOrder(borrowerRegAddr.itemId, exactTermRanges(loanTerms), expirationBlock, bidGuid, borrower),
     ]);
 
     return [askOrderAdded.itemId, bidOrderAdded.itemId];
@@ -8,6 +8,7 @@ import {
     PalletCreditcoinLoanTerms,
     PalletCreditcoinLoanTermsInterestRate,
     PalletCreditcoinLoanTermsDuration,
+    PalletCreditcoinLoanTermsLoanTermRanges,
     PalletCreditcoinOffer,
     PalletCreditcoinTransfer,
     PalletCreditcoinPlatformBlockchain,
@@ -22,6 +23,7 @@ import {
     Address,
     AskOrder,
     LoanTerms,
+    LoanTermRanges,
     BidOrder,
     Offer,
     EvmInfo,
@@ -167,6 +169,45 @@ export const createCreditcoinLoanTerms = (
         currency,
     });
 
+export const createLoanTermRanges = ({
+    minAmount,
+    maxAmount,
+    interestRate,
+    minTermLength,
+    maxTermLength,
+    currency,
+}: PalletCreditcoinLoanTermsLoanTermRanges): LoanTermRanges => ({
+    minAmount,
+    maxAmount,
+    interestRate: createInterestRate(interestRate),
+    minTermLength: createDuration(minTermLength),
+    maxTermLength: createDuration(maxTermLength),
+    currency: currency.toString(),
+});
+
+export const createCreditcoinLoanTermRanges = (
+    api: ApiPromise,
+    { minAmount, maxAmount, interestRate, minTermLength, maxTermLength, currency }: LoanTermRanges,
+): PalletCreditcoinLoanTermsLoanTermRanges =>
+    api.createType('PalletCreditcoinLoanTermsLoanTermRanges', {
+        minAmount,
+        maxAmount,
+        interestRate,
+        minTermLength,
+        maxTermLength,
+        currency,
+    });
+
+// Ranges which only accept the given terms
+export const exactTermRanges = ({ amount, interestRate, termLength, currency }: LoanTerms): LoanTermRanges => ({
+    minAmount: amount,
+    maxAmount: amount,
+    interestRate,
+    minTermLength: termLength,
+    maxTermLength: termLength,
+    currency,
+});
+
 export const createAskOrder = ({
     terms,
     lenderAddressId,
@@ -176,7 +217,7 @@ export const createAskOrder = ({
 }: PalletCreditcoinAskOrder): AskOrder => ({
     blockNumber: block.toNumber(),
     expirationBlock: expirationBlock.toNumber(),
-    loanTerms: createLoanTerms(terms),
+    termRanges: createLoanTermRanges(terms),
     lenderAddressId: lenderAddressId.toString(),
     lenderAccountId: lender.toString(),
 });
@@ -190,7 +231,7 @@ export const createBidOrder = ({
 }: PalletCreditcoinBidOrder): BidOrder => ({
     blockNumber: block.toNumber(),
     expirationBlock: expirationBlock.toNumber(),
-    loanTerms: createLoanTerms(terms),
+    termRanges: createLoanTermRanges(terms),
     borrowerAddressId: borrowerAddressId.toString(),
     borrowerAccountId: borrower.toString(),
 });
@@ -15,6 +15,7 @@ import {
     DealOrderLocked,
     ExternalAddress,
     LoanTerms,
+    LoanTermRanges,
     OfferId,
     TransferId,
     TransferKind,
@@ -56,14 +57,14 @@ export interface Extrinsics {
     ) => Promise<AddressRegistered>;
     addAskOrder: (
         lenderAddressId: AddressId,
-        loanTerms: LoanTerms,
+        termRanges: LoanTermRanges,
         expirationBlock: number,
         guid: Guid,
         signer: KeyringPair,
     ) => Promise<AskOrderAdded>;
     addBidOrder: (
         borrowerAddressId: AddressId,
-        loanTerms: LoanTerms,
+        termRanges: LoanTermRanges,
         expirationBlock: number,
         guid: Guid,
         signer: KeyringPair,
@@ -1,5 +1,5 @@
 import { Guid, LoanTerms, KeyringPair, POINT_01_CTC } from 'creditcoin-js';
-import { createCreditcoinLoanTerms } from 'creditcoin-js/lib/transforms';
+import { createCreditcoinLoanTermRanges, exactTermRanges } from 'creditcoin-js/lib/transforms';
 import { AddressRegistered } from 'creditcoin-js/lib/extrinsics/register-address';
 import { ethConnection, testCurrency } from 'creditcoin-js/lib/examples/ethereum';
 import { Blockchain } from 'creditcoin-js/lib/model';
@@ -63,7 +63,7 @@ describe('AddAskOrder', () => {
             const unsubscribe = api.tx.creditcoin
                 .addAskOrder(
                     lenderRegAddr.itemId,
-                    createCreditcoinLoanTerms(api, loanTerms),
+                    createCreditcoinLoanTermRanges(api, exactTermRanges(loanTerms)),
                     expirationBlock,
                     askGuid.toString(),
                 )
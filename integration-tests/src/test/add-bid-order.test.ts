@@ -5,7 +5,7 @@ import { Blockchain } from 'creditcoin-js/lib/model';
 import { signAccountId } from 'creditcoin-js/lib/utils';
 import { CreditcoinApi } from 'creditcoin-js/lib/types';
 import { loanTermsWithCurrency, testData, tryRegisterAddress } from 'creditcoin-js/lib/testUtils';
-import { createCreditcoinLoanTerms } from 'creditcoin-js/lib/transforms';
+import { createCreditcoinLoanTermRanges, exactTermRanges } from 'creditcoin-js/lib/transforms';
 
 import { extractFee } from '../utils';
 
@@ -62,7 +62,7 @@ describe('AddBidOrder', () => {
             const unsubscribe = api.tx.creditcoin
                 .addBidOrder(
                     borrowerRegAddr.itemId,
-                    createCreditcoinLoanTerms(api, loanTerms),
+                    createCreditcoinLoanTermRanges(api, exactTermRanges(loanTerms)),
                     expirationBlock,
                     bidGuid.toString(),
                 )
@@ -0,0 +1,139 @@
+use super::Error;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use creditcoin_node_runtime as runtime;
+use creditcoin_runtime_api::LoanApi;
+use pallet_creditcoin::{AmountDue, CreditHistory, DealOrderId};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits};
+use std::sync::Arc;
+
+use jsonrpsee::{
+	core::{async_trait, Error as JsonRpseeError, RpcResult},
+	proc_macros::rpc,
+	types::{
+		error::{CallError, ErrorCode},
+		ErrorObject,
+	},
+};
+
+type AccountId = <runtime::Runtime as frame_system::Config>::AccountId;
+type BlockNumber = runtime::BlockNumber;
+type Hash = runtime::Hash;
+type Moment = runtime::Moment;
+
+#[rpc(client, server)]
+pub trait LoanApi<BlockNumber, Hash, Moment> {
+	#[method(name = "creditcoin_getAmountDue")]
+	async fn amount_due(
+		&self,
+		deal_order_id: DealOrderId<BlockNumber, Hash>,
+		at: Moment,
+	) -> RpcResult<Option<AmountDue>>;
+
+	#[method(name = "creditcoin_getCreditHistory")]
+	async fn credit_history(&self, account_id: String) -> RpcResult<CreditHistory<Hash>>;
+}
+
+pub struct Loan<C, B> {
+	client: Arc<C>,
+	_p: PhantomData<B>,
+}
+
+impl<C, B> Loan<C, B> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _p: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, B> LoanApiServer<BlockNumber, Hash, Moment> for Loan<C, B>
+where
+	C: sp_api::ProvideRuntimeApi<B>,
+	C: HeaderBackend<B>,
+	C: Send + Sync + 'static,
+	C::Api: LoanApi<B, AccountId, BlockNumber, Hash, Moment>,
+	B: traits::Block,
+{
+	async fn amount_due(
+		&self,
+		deal_order_id: DealOrderId<BlockNumber, Hash>,
+		at: Moment,
+	) -> RpcResult<Option<AmountDue>> {
+		let api = self.client.runtime_api();
+		let best = {
+			let best = self.client.info().best_hash;
+			BlockId::hash(best)
+		};
+
+		api.amount_due(&best, deal_order_id, at).map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				ErrorCode::ServerError(Error::RuntimeError.into()).code(),
+				"Unable to query amount due.",
+				Some(format!("{e:?}")),
+			)))
+		})
+	}
+
+	async fn credit_history(&self, account_id: String) -> RpcResult<CreditHistory<Hash>> {
+		let api = self.client.runtime_api();
+		let best = {
+			let best = self.client.info().best_hash;
+			BlockId::hash(best)
+		};
+		let account_id = AccountId::from_str(&account_id).map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				ErrorCode::InvalidParams.code(),
+				"Not a valid hex-string or SS58 address.",
+				Some(format!("{e:?}")),
+			)))
+		})?;
+
+		api.credit_history(&best, account_id).map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				ErrorCode::ServerError(Error::RuntimeError.into()).code(),
+				"Unable to query credit history.",
+				Some(format!("{e:?}")),
+			)))
+		})
+	}
+}
+
+#[cfg(test)]
+pub mod test {
+	use super::*;
+	use creditcoin_node_runtime::Block;
+
+	#[tokio::test]
+	async fn amount_due_should_be_none_when_deal_order_does_not_exist() {
+		let client = Arc::new(test_client::new());
+		let loan = Loan::<_, Block>::new(client);
+
+		let deal_order_id: DealOrderId<BlockNumber, Hash> =
+			serde_json::from_str(&format!(r#"[1, "0x{}"]"#, "00".repeat(32))).unwrap();
+
+		assert_eq!(loan.amount_due(deal_order_id, 0).await.unwrap(), None);
+	}
+
+	#[tokio::test]
+	async fn credit_history_should_be_empty_for_new_account() {
+		let client = Arc::new(test_client::new());
+		let loan = Loan::<_, Block>::new(client);
+
+		let history = loan
+			.credit_history("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY".into())
+			.await
+			.unwrap();
+
+		assert_eq!(history.record, Default::default());
+		assert!(history.volume.is_empty());
+	}
+
+	#[tokio::test]
+	async fn credit_history_should_error_on_malformed_account() {
+		let client = Arc::new(test_client::new());
+		let loan = Loan::<_, Block>::new(client);
+
+		assert!(loan.credit_history("not an account".into()).await.is_err());
+	}
+}
@@ -2,6 +2,8 @@ use super::Error;
 use core::marker::PhantomData;
 use core::str::FromStr;
 use creditcoin_node_runtime as runtime;
+use creditcoin_runtime_api::TaskStatusApi;
+use pallet_creditcoin::{TaskId, TaskStatus};
 use sc_rpc::DenyUnsafe;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{generic::BlockId, traits};
@@ -18,11 +20,19 @@ use jsonrpsee::{
 };
 
 type AccountId = <runtime::Runtime as frame_system::Config>::AccountId;
+type BlockNumber = runtime::BlockNumber;
+type Hash = runtime::Hash;
 
 #[rpc(client, server)]
-pub trait TaskApi<AccountId> {
+pub trait TaskApi<AccountId, BlockNumber, Hash> {
 	#[method(name = "task_getOffchainNonceKey")]
 	async fn offchain_nonce_key(&self, account_id: String) -> RpcResult<Vec<u8>>;
+
+	#[method(name = "task_getStatus")]
+	async fn task_status(
+		&self,
+		task_id: TaskId<Hash>,
+	) -> RpcResult<Option<TaskStatus<BlockNumber>>>;
 }
 
 pub struct Task<C, B> {
@@ -38,12 +48,12 @@ impl<C, B> Task<C, B> {
 }
 
 #[async_trait]
-impl<C, B> TaskApiServer<AccountId> for Task<C, B>
+impl<C, B> TaskApiServer<AccountId, BlockNumber, Hash> for Task<C, B>
 where
 	C: sp_api::ProvideRuntimeApi<B>,
 	C: HeaderBackend<B>,
 	C: Send + Sync + 'static,
-	C::Api: TaskApi<B, AccountId>,
+	C::Api: TaskApi<B, AccountId> + TaskStatusApi<B, BlockNumber, Hash>,
 	B: traits::Block,
 {
 	async fn offchain_nonce_key(&self, account_id: String) -> RpcResult<Vec<u8>> {
@@ -69,6 +79,25 @@ where
 			)))
 		})
 	}
+
+	async fn task_status(
+		&self,
+		task_id: TaskId<Hash>,
+	) -> RpcResult<Option<TaskStatus<BlockNumber>>> {
+		let api = self.client.runtime_api();
+		let at = {
+			let best = self.client.info().best_hash;
+			BlockId::hash(best)
+		};
+
+		api.task_status(&at, task_id).map_err(|e| {
+			JsonRpseeError::Call(CallError::Custom(ErrorObject::owned(
+				ErrorCode::ServerError(Error::RuntimeError.into()).code(),
+				"Unable to query task status.",
+				Some(format!("{e:?}")),
+			)))
+		})
+	}
 }
 
 #[cfg(test)]
@@ -106,4 +135,16 @@ pub mod test {
 			Ok(_) => panic!("This is not expected"),
 		}
 	}
+
+	#[tokio::test]
+	async fn task_status_should_be_none_when_task_does_not_exist() {
+		let client = Arc::new(test_client::new());
+		let t = Task::<_, Block>::new(client, DenyUnsafe::No);
+
+		let task_id: TaskId<Hash> =
+			serde_json::from_str(&format!(r#"{{"verifyTransfer": "0x{}"}}"#, "00".repeat(32)))
+				.unwrap();
+
+		assert_eq!(t.task_status(task_id).await.unwrap(), None);
+	}
 }
@@ -0,0 +1,34 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use pallet_creditcoin::{AmountDue, CreditHistory, DealOrderId, TaskId, TaskStatus};
+use parity_scale_codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	pub trait LoanApi<AccountId: Codec, BlockNumber: Codec, Hash: Codec, Moment: Codec> {
+		/// The outstanding principal, the interest owed on it and the total amount due on a funded
+		/// deal order as of `at`.
+		fn amount_due(
+			deal_order_id: DealOrderId<BlockNumber, Hash>,
+			at: Moment,
+		) -> Option<AmountDue>;
+
+		/// The outcomes of the loans taken by `account_id` and the principal borrowed per currency.
+		fn credit_history(account_id: AccountId) -> CreditHistory<Hash>;
+	}
+
+	pub trait RewardApi<AccountId: Codec, Balance: Codec, BlockNumber: Codec> {
+		/// The index of the reward era the current block belongs to.
+		fn current_reward_era() -> BlockNumber;
+
+		/// The rewards `authority` earned for completing tasks during `era`.
+		fn authority_rewards(era: BlockNumber, authority: AccountId) -> Balance;
+
+		/// The rewards paid to all authorities during `era`.
+		fn total_rewards(era: BlockNumber) -> Balance;
+	}
+
+	pub trait TaskStatusApi<BlockNumber: Codec, Hash: Codec> {
+		/// The status of the task `id`, if it is pending or finished recently enough to be tracked.
+		fn task_status(id: TaskId<Hash>) -> Option<TaskStatus<BlockNumber>>;
+	}
+}
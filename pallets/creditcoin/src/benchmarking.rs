@@ -105,6 +105,55 @@ benchmarks! {
 	}: { Creditcoin::<T>::on_initialize(expiration_block) }
 	verify {}
 
+	on_idle {
+		//mark c matured dealorders as defaulted
+		let c in 1..255;
+
+		<Timestamp<T>>::set_timestamp(1u32.into());
+
+		let lender = lender_account::<T>(false);
+		let expiration_block = T::BlockNumber::one();
+		for i in 0..c {
+			insert_fake_deal::<T>(&lender, expiration_block, DealKind::Funded, i);
+		}
+		for (expiration, hash, deal) in DealOrders::<T>::iter() {
+			let deal_id = DealOrderId::with_expiration_hash::<T>(expiration, hash);
+			Creditcoin::<T>::schedule_maturity(&deal_id, &deal);
+		}
+
+		let matured = 2 * crate::helpers::MATURITY_BUCKET_MILLIS;
+		<Timestamp<T>>::set_timestamp(T::Moment::unique_saturated_from(matured));
+
+	}: { Creditcoin::<T>::on_idle(expiration_block, Weight::from_ref_time(u64::MAX)) }
+	verify {
+		assert_eq!(crate::DefaultedDealOrders::<T>::iter().count(), c as usize);
+	}
+
+	match_orders {
+		//match m queued ask orders against open bid orders
+		let m in 0..crate::helpers::MAX_MATCHES_PER_BLOCK;
+
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		crate::OrderMatching::<T>::put(true);
+
+		let lender: T::AccountId = lender_account::<T>(true);
+		let borrower: T::AccountId = borrower_account::<T>(true);
+		let loan_terms = loan_terms::<T>();
+		let expiration_block = T::BlockNumber::one();
+
+		for i in 0..m {
+			generate_bid::<T>(&borrower, &loan_terms, &expiration_block, true, i as u8).unwrap();
+		}
+		Creditcoin::<T>::match_queued_orders();
+		for i in 0..m {
+			generate_ask::<T>(&lender, &loan_terms, &expiration_block, true, i as u8).unwrap();
+		}
+
+	}: { Creditcoin::<T>::match_queued_orders() }
+	verify {
+		assert_eq!(crate::Offers::<T>::iter().count(), m as usize);
+	}
+
 	register_address {
 		let who: T::AccountId = lender_account::<T>(false);
 		let ktypeid = KeyTypeId(*b"dumy");
@@ -142,16 +191,18 @@ benchmarks! {
 
 	add_ask_order {
 		<Timestamp<T>>::set_timestamp(1u32.into());
+		crate::OrderMatching::<T>::put(true);
 		let who:T::AccountId = lender_account::<T>(true);
 		let terms = loan_terms::<T>();
 		let expiration_block = T::BlockNumber::one();
 
 		let (address_id,ask_id,guid) = generate_ask::<T>(&who,&terms,&expiration_block,false,0).unwrap();
 
-	}: _(RawOrigin::Signed(who),address_id,terms,expiration_block,guid.into_bounded())
+	}: _(RawOrigin::Signed(who),address_id,terms.into(),expiration_block,guid.into_bounded())
 
 	add_bid_order {
 		<Timestamp<T>>::set_timestamp(1u32.into());
+		crate::OrderMatching::<T>::put(true);
 		let who:T::AccountId = borrower_account::<T>(true);
 
 		let loan_terms = loan_terms::<T>();
@@ -160,7 +211,7 @@ benchmarks! {
 
 		let (address_id,bid_id,guid) = generate_bid::<T>(&who,&loan_terms,&expiration_block,false,0).unwrap();
 
-	}:_(RawOrigin::Signed(who),address_id,loan_terms,expiration_block,guid.into_bounded())
+	}:_(RawOrigin::Signed(who),address_id,loan_terms.into(),expiration_block,guid.into_bounded())
 
 	add_offer {
 		<Timestamp<T>>::set_timestamp(1u32.into());
@@ -172,6 +223,36 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(lender), ask_id, bid_id, expiration_block)
 
+	cancel_ask_order {
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		let who:T::AccountId = lender_account::<T>(true);
+		let terms = loan_terms::<T>();
+		let expiration_block = T::BlockNumber::one();
+
+		let (_,ask_id,_) = generate_ask::<T>(&who,&terms,&expiration_block,true,0).unwrap();
+
+	}: _(RawOrigin::Signed(who),ask_id)
+
+	cancel_bid_order {
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		let who:T::AccountId = borrower_account::<T>(true);
+		let terms = loan_terms::<T>();
+		let expiration_block = T::BlockNumber::one();
+
+		let (_,bid_id,_) = generate_bid::<T>(&who,&terms,&expiration_block,true,0).unwrap();
+
+	}: _(RawOrigin::Signed(who),bid_id)
+
+	cancel_offer {
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		let lender: T::AccountId = lender_account::<T>(true);
+		let loan_terms = loan_terms::<T>();
+		let expiration_block = T::BlockNumber::one();
+
+		let (offer_id, _, _) = generate_offer::<T>(&lender,&loan_terms,&expiration_block,true,0u8).unwrap();
+
+	}: _(RawOrigin::Signed(lender), offer_id)
+
 	add_deal_order {
 		<Timestamp<T>>::set_timestamp(1u32.into());
 		let lender = lender_account::<T>(false);
@@ -186,6 +267,7 @@ benchmarks! {
 	add_authority {
 		let root = RawOrigin::Root;
 		let who = authority_account::<T>(false);
+		bond_authority::<T>(&who);
 	}: _(root, who)
 
 	persist_transfer {
@@ -260,6 +342,14 @@ benchmarks! {
 		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
 	}: register_repayment_transfer_legacy(RawOrigin::Signed(borrower),LegacyTransferKind::Ethless(contract),repayment_amount,deal_id,tx)
 
+	repay_installment {
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		let borrower: T::AccountId = borrower_account::<T>(true);
+		let (deal_id,_) = generate_funded_deal::<T>(true,0u8).unwrap();
+		let (transfer_id, _) = generate_transfer::<T>(deal_id.clone(),true,true,0u8);
+
+	}: _(RawOrigin::Signed(borrower),deal_id,transfer_id)
+
 	close_deal_order {
 		<Timestamp<T>>::set_timestamp(1u32.into());
 		let borrower: T::AccountId = borrower_account::<T>(true);
@@ -268,6 +358,25 @@ benchmarks! {
 
 	}: _(RawOrigin::Signed(borrower),deal_id,transfer_id)
 
+	assign_deal_order {
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		let lender: T::AccountId = lender_account::<T>(true);
+		let (deal_id,_) = generate_funded_deal::<T>(true,0u8).unwrap();
+		let deal_order = try_get_id!(DealOrders<T>, &deal_id, NonExistentDealOrder).unwrap();
+
+		let ktypeid = KeyTypeId(*b"dumy");
+		let pkey = ecdsa_generate(ktypeid, None);
+		let new_lender = T::Signer::from(pkey).into_account();
+		let new_lender_addr_id = register_eth_addr::<T>(&new_lender,"new_lender");
+
+		let message = deal_id.encode().into_iter()
+			.chain(deal_order.lender_address_id.encode())
+			.chain(new_lender_addr_id.encode())
+			.chain(0u32.encode())
+			.collect::<Vec<u8>>();
+		let signature = ecdsa_sign(ktypeid, &pkey, &message[..]).expect("ecdsa signature");
+	}: _(RawOrigin::Signed(lender),deal_id,new_lender_addr_id,pkey.into(),signature.into())
+
 	exempt {
 		<Timestamp<T>>::set_timestamp(1u32.into());
 		let lender = lender_account::<T>(true);
@@ -340,6 +449,7 @@ benchmarks! {
 	remove_authority {
 		let root = RawOrigin::Root;
 		let who = authority_account::<T>(false);
+		bond_authority::<T>(&who);
 		<Creditcoin<T>>::add_authority(root.clone().into(), who.clone()).unwrap();
 	}: _(root, who)
 
@@ -358,6 +468,74 @@ benchmarks! {
 		let root = RawOrigin::Root;
 		let contract = GCreContract::default();
 	}: _(root, contract)
+
+	set_order_matching {
+		let root = RawOrigin::Root;
+	}: _(root, true)
+
+	renegotiate_deal_order {
+		<Timestamp<T>>::set_timestamp(1u32.into());
+		let lender: T::AccountId = lender_account::<T>(true);
+		let (deal_id,_) = generate_funded_deal::<T>(true,0u8).unwrap();
+
+		let ktypeid = KeyTypeId(*b"dumy");
+		let pkey = ecdsa_generate(ktypeid, None);
+		let borrower = T::Signer::from(pkey).into_account();
+		DealOrders::<T>::mutate(deal_id.expiration(), deal_id.hash(), |deal_order| {
+			deal_order.as_mut().unwrap().borrower = borrower;
+		});
+
+		let term_length = Duration::new(2u64, 0u32);
+		let interest_rate = loan_terms::<T>().interest_rate;
+		let message = deal_id.encode().into_iter()
+			.chain(0u32.encode())
+			.chain(term_length.encode())
+			.chain(interest_rate.encode())
+			.collect::<Vec<u8>>();
+		let signature = ecdsa_sign(ktypeid, &pkey, &message[..]).expect("ecdsa signature");
+	}: _(RawOrigin::Signed(lender),deal_id,term_length,interest_rate,pkey.into(),signature.into())
+
+	set_confirmation_policy {
+		let root = RawOrigin::Root;
+	}: _(root, Blockchain::ETHEREUM, Some(crate::ConfirmationPolicy::Finalized))
+
+	set_quorum_threshold {
+		let root = RawOrigin::Root;
+		for i in 0..3 {
+			let authority: T::AccountId = account("authority", i, 1);
+			<Creditcoin<T>>::add_authority(root.clone().into(), authority).unwrap();
+		}
+	}: _(root, 3)
+
+	set_authority_bond {
+		let root = RawOrigin::Root;
+		let bond = T::Balance::unique_saturated_from(Balances::<T>::minimum_balance());
+	}: _(root, bond)
+
+	slash_authority {
+		let root = RawOrigin::Root;
+		let who = authority_account::<T>(false);
+		bond_authority::<T>(&who);
+		<Creditcoin<T>>::add_authority(root.clone().into(), who.clone()).unwrap();
+		let deadline = T::BlockNumber::one();
+		let task_id = crate::TaskId::from(fake_transfer_id::<T>(0));
+		let vote = T::Hash::default();
+		crate::TaskOutputVotes::<T>::insert((deadline, &task_id, &who), vote);
+		let outcome = <T::Hashing as sp_runtime::traits::Hash>::hash(b"outcome");
+		crate::TaskOutcomes::<T>::insert(deadline, &task_id, outcome);
+	}: _(root, who, deadline, task_id, vote)
+
+	set_task_fee {
+		let root = RawOrigin::Root;
+		let fee = Balances::<T>::minimum_balance();
+	}: _(root, fee)
+
+	bond_authority {
+		let root = RawOrigin::Root;
+		let who = authority_account::<T>(true);
+		<Creditcoin<T>>::add_authority(root.into(), who.clone()).unwrap();
+		bond_authority::<T>(&who);
+	}: _(RawOrigin::Signed(who))
 }
 
 //impl_benchmark_test_suite!(Creditcoin, crate::mock::new_test_ext(), crate::mock::Test);
@@ -453,7 +631,7 @@ fn generate_transfer<T: Config>(
 			order.borrower_address_id,
 			order.lender_address_id,
 			TransferKind::Evm(EvmTransferKind::Ethless),
-			gain.into(),
+			order.terms.amount.saturating_add(gain.into()),
 			deal_id,
 			tx,
 			&order.terms.currency,
@@ -494,6 +672,13 @@ fn generate_locked_deal<T: Config>(
 	Ok((deal_id, transfer_id))
 }
 
+/// Makes new authorities reserve a bond, and gives `who` enough to pay it.
+fn bond_authority<T: Config>(who: &T::AccountId) {
+	let bond = Balances::<T>::minimum_balance();
+	crate::AuthorityBond::<T>::put(bond);
+	<Balances<T> as Currency<T::AccountId>>::make_free_balance_be(who, bond + bond);
+}
+
 fn borrower_account<T: Config>(whitelist: bool) -> T::AccountId {
 	let borrower = account("borrower", 1, 1);
 	if whitelist {
@@ -670,7 +855,7 @@ fn generate_ask<T: Config>(
 		Creditcoin::<T>::add_ask_order(
 			origin.into(),
 			address_id.clone(),
-			loan_terms.clone(),
+			loan_terms.clone().into(),
 			*expiration_block,
 			guid.into_bounded(),
 		)
@@ -699,7 +884,7 @@ fn generate_bid<T: Config>(
 		Creditcoin::<T>::add_bid_order(
 			origin.into(),
 			address_id.clone(),
-			loan_terms.clone(),
+			loan_terms.clone().into(),
 			*expiration_block,
 			guid.into_bounded(),
 		)
@@ -849,6 +1034,9 @@ fn insert_fake_deal<T: Config>(
 		},
 		offer_id,
 		repayment_transfer_id: None,
+		repaid_amount: ExternalAmount::zero(),
+		repayments: Default::default(),
+		accrual: None,
 		terms: loan_terms::<T>(),
 		timestamp: pallet_timestamp::Pallet::<T>::now(),
 	};
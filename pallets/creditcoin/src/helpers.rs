@@ -1,16 +1,25 @@
+mod credit_history;
 mod external_address;
+mod matching;
+mod maturity;
+mod quorum;
 mod register_transfer;
+mod rewards;
+mod task_status;
 
 use crate::{
 	pallet::*,
-	types::{Address, AddressId},
-	DealOrderId, Error, Guid, Id, TransferId,
+	types::{Address, AddressId, DoubleMapExt},
+	Accrual, AmountDue, DealOrderId, Duration, Error, ExternalAmount, Guid, Id, TransferId,
 };
 pub use external_address::{address_is_well_formed, generate_external_address};
 #[cfg(any(test, feature = "runtime-benchmarks"))]
-pub use external_address::{EVMAddress, PublicToAddress};
+pub use external_address::{BTCAddress, BTCAddressType, EVMAddress, PublicToAddress};
 use frame_support::ensure;
 use frame_system::pallet_prelude::*;
+pub use matching::{MAX_MATCHES_PER_BLOCK, MAX_MATCH_CANDIDATES};
+pub use maturity::MATURITY_BUCKET_MILLIS;
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto};
 use sp_std::prelude::*;
 
 #[allow(unused_macros)]
@@ -96,6 +105,91 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// The interest accrued on a deal order as of `at`, on the principal left outstanding by
+	/// the installments repaid so far.
+	pub fn deal_order_accrual(
+		deal_order: &DealOrderFor<T>,
+		at: T::Moment,
+	) -> Result<Accrual<T::Moment>, Error<T>> {
+		let accrual = deal_order.accrual.clone().unwrap_or_else(|| Accrual {
+			principal: deal_order.terms.amount,
+			interest: ExternalAmount::zero(),
+			since: deal_order.timestamp,
+			periods: 0,
+		});
+
+		let interest_rate = &deal_order.terms.interest_rate;
+		let elapsed = at.saturating_sub(accrual.since).unique_saturated_into();
+		let periods =
+			interest_rate.periods_in(&Duration::from_millis(elapsed)).max(accrual.periods);
+		let interest = interest_rate
+			.interest_for_periods(accrual.principal, periods - accrual.periods)
+			.map_err(Error::<T>::from)?;
+
+		Ok(Accrual {
+			interest: accrual.interest.checked_add(interest).ok_or(Error::<T>::InterestOverflow)?,
+			periods,
+			..accrual
+		})
+	}
+
+	/// The principal still outstanding on a deal order and the interest owed on it as of `at`.
+	pub fn deal_order_amount_due(
+		deal_order: &DealOrderFor<T>,
+		at: T::Moment,
+	) -> Result<AmountDue, Error<T>> {
+		let Accrual { principal, interest, .. } = Self::deal_order_accrual(deal_order, at)?;
+		let total = principal.checked_add(interest).ok_or(Error::<T>::InterestOverflow)?;
+		Ok(AmountDue { principal, interest, total })
+	}
+
+	/// The balance still owed on a deal order as of `at`, after any installments already repaid.
+	pub fn deal_order_outstanding(
+		deal_order: &DealOrderFor<T>,
+		at: T::Moment,
+	) -> Result<ExternalAmount, Error<T>> {
+		Ok(Self::deal_order_amount_due(deal_order, at)?.total)
+	}
+
+	/// Records `amount`, repaid by `transfer_id` at `at`, as an installment of `deal_order`.
+	/// The installment pays the interest owed first and then the principal, and only the
+	/// principal left outstanding accrues interest afterwards.
+	pub fn record_installment(
+		deal_order: &mut DealOrderFor<T>,
+		transfer_id: &TransferId<T::Hash>,
+		amount: ExternalAmount,
+		at: T::Moment,
+	) -> Result<(), Error<T>> {
+		let accrual = Self::deal_order_accrual(deal_order, at)?;
+
+		deal_order
+			.repayments
+			.try_push(transfer_id.clone())
+			.map_err(|_| Error::<T>::TooManyRepayments)?;
+		deal_order.repaid_amount = deal_order.repaid_amount.saturating_add(amount);
+
+		let to_interest = amount.min(accrual.interest);
+		let to_principal = amount.saturating_sub(to_interest).min(accrual.principal);
+		deal_order.accrual = Some(Accrual {
+			principal: accrual.principal.saturating_sub(to_principal),
+			interest: accrual.interest.saturating_sub(to_interest),
+			..accrual
+		});
+
+		Ok(())
+	}
+
+	/// The amount owed on a funded deal order as of `at`.
+	/// Returns `None` if the deal order does not exist, is not funded or the amount overflows.
+	pub fn amount_due(
+		deal_order_id: &DealOrderId<T::BlockNumber, T::Hash>,
+		at: T::Moment,
+	) -> Option<AmountDue> {
+		let deal_order = DealOrders::<T>::try_get_id(deal_order_id).ok()?;
+		deal_order.funding_transfer_id.as_ref()?;
+		Self::deal_order_amount_due(&deal_order, at).ok()
+	}
+
 	pub fn use_guid(guid: &Guid) -> Result<(), Error<T>> {
 		ensure!(!<UsedGuids<T>>::contains_key(guid.clone()), Error::<T>::GuidAlreadyUsed);
 		UsedGuids::<T>::insert(guid, ());
@@ -0,0 +1,44 @@
+use super::DealOrderFor;
+use crate::{pallet::*, CreditHistory};
+use sp_runtime::traits::{Saturating, UniqueSaturatedInto};
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	pub fn record_loan_taken(deal_order: &DealOrderFor<T>) {
+		CreditRecords::<T>::mutate(&deal_order.borrower, |record| {
+			record.loans_taken.saturating_inc();
+		});
+		BorrowedVolume::<T>::mutate(&deal_order.borrower, &deal_order.terms.currency, |volume| {
+			*volume = volume.saturating_add(deal_order.terms.amount);
+		});
+	}
+
+	/// Records a deal order being repaid at `repaid_at`, which is on time if the term of the
+	/// loan had not ended yet.
+	pub fn record_repayment(deal_order: &DealOrderFor<T>, repaid_at: T::Moment) {
+		let repaid_at: u64 = repaid_at.unique_saturated_into();
+		let on_time = repaid_at <= Self::deal_order_maturity(deal_order);
+		CreditRecords::<T>::mutate(&deal_order.borrower, |record| {
+			if on_time {
+				record.repaid_on_time.saturating_inc();
+			} else {
+				record.repaid_late.saturating_inc();
+			}
+		});
+	}
+
+	pub fn record_exemption(borrower: &T::AccountId) {
+		CreditRecords::<T>::mutate(borrower, |record| record.exempted.saturating_inc());
+	}
+
+	pub fn record_default(borrower: &T::AccountId) {
+		CreditRecords::<T>::mutate(borrower, |record| record.defaulted.saturating_inc());
+	}
+
+	pub fn credit_history(account_id: &T::AccountId) -> CreditHistory<T::Hash> {
+		CreditHistory {
+			record: CreditRecords::<T>::get(account_id),
+			volume: BorrowedVolume::<T>::iter_prefix(account_id).collect(),
+		}
+	}
+}
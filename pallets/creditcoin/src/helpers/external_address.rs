@@ -1,10 +1,13 @@
-use crate::{Blockchain, ExternalAddress};
-use base58::FromBase58;
+use crate::{BitcoinNetwork, Blockchain, ExternalAddress};
+use base58::{FromBase58, ToBase58};
+use bitcoin_bech32::{constants::Network, u5, WitnessProgram};
 use core::convert::TryFrom;
 use frame_support::BoundedVec;
+use ripemd::{Digest, Ripemd160};
 use sp_core::ecdsa::Public;
 use sp_io::hashing::keccak_256;
 use sp_io::hashing::sha2_256;
+use sp_std::prelude::*;
 
 pub fn generate_external_address(
 	blockchain: &Blockchain,
@@ -15,6 +18,12 @@ pub fn generate_external_address(
 		Blockchain::Evm(_) if EVMAddress::try_extract_address_type(reference).is_some() => {
 			Some(EVMAddress::from_public(&public_key))
 		},
+		Blockchain::Bitcoin(network) => match BTCAddress::try_extract_address_type(reference) {
+			Some(address_type) if address_type.network() == *network => {
+				Some(BTCAddress::from_public(&public_key, address_type))
+			},
+			_ => None,
+		},
 		_ => None,
 	}
 }
@@ -47,17 +56,104 @@ impl PublicToAddress for EVMAddress {
 	}
 }
 
+/// The kinds of bitcoin address whose owner can be proven with a secp256k1 signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BTCAddressType {
+	P2pkh(BitcoinNetwork),
+	P2wpkh(BitcoinNetwork),
+}
+
+impl BTCAddressType {
+	pub fn network(&self) -> BitcoinNetwork {
+		match *self {
+			BTCAddressType::P2pkh(network) | BTCAddressType::P2wpkh(network) => network,
+		}
+	}
+}
+
+pub struct BTCAddress;
+
+impl BTCAddress {
+	pub fn try_extract_address_type(addr: &ExternalAddress) -> Option<BTCAddressType> {
+		let address_str = core::str::from_utf8(addr).ok()?;
+
+		if let Ok(witness_program) = WitnessProgram::from_address(address_str) {
+			let network = match witness_program.network() {
+				Network::Bitcoin => BitcoinNetwork::Mainnet,
+				Network::Testnet => BitcoinNetwork::Testnet,
+				_ => return None,
+			};
+			let is_p2wpkh = witness_program.version().to_u8() == 0
+				&& witness_program.program().len() == BTC_PUBKEY_HASH_LENGTH;
+			return if is_p2wpkh { Some(BTCAddressType::P2wpkh(network)) } else { None };
+		}
+
+		let address_decoded = address_str.from_base58().ok()?;
+		if address_decoded.len() != BTC_MIN_LENGTH || !btc_checksum_matches(&address_decoded) {
+			return None;
+		}
+		match address_decoded[0] {
+			BTC_P2PKH_VERSION_MAINNET => Some(BTCAddressType::P2pkh(BitcoinNetwork::Mainnet)),
+			BTC_P2PKH_VERSION_TESTNET => Some(BTCAddressType::P2pkh(BitcoinNetwork::Testnet)),
+			_ => None,
+		}
+	}
+
+	pub fn from_public(pkey: &Public, address_type: BTCAddressType) -> ExternalAddress {
+		//pkey compressed, 33 bytes
+		let pubkey_hash = Ripemd160::digest(sha2_256(pkey.as_ref())).to_vec();
+
+		let address = match address_type {
+			BTCAddressType::P2pkh(network) => {
+				let version = match network {
+					BitcoinNetwork::Mainnet => BTC_P2PKH_VERSION_MAINNET,
+					BitcoinNetwork::Testnet => BTC_P2PKH_VERSION_TESTNET,
+				};
+				let mut payload = vec![version];
+				payload.extend(pubkey_hash);
+				let checksum = btc_checksum(&payload);
+				payload.extend(checksum);
+				payload.to_base58()
+			},
+			BTCAddressType::P2wpkh(network) => {
+				let network = match network {
+					BitcoinNetwork::Mainnet => Network::Bitcoin,
+					BitcoinNetwork::Testnet => Network::Testnet,
+				};
+				let version = u5::try_from_u8(0).expect("0 is a valid witness version; qed");
+				WitnessProgram::new(version, pubkey_hash, network)
+					.expect("a 20 byte version 0 program is a valid witness program; qed")
+					.to_address()
+			},
+		};
+		BoundedVec::try_from(address.into_bytes())
+			.expect("bitcoin addresses are shorter than the bound; qed")
+	}
+}
+
 pub fn address_is_well_formed(blockchain: &Blockchain, address: &ExternalAddress) -> bool {
 	match blockchain {
 		Blockchain::Evm(_) => eth_address_is_well_formed(address),
+		Blockchain::Bitcoin(_) => btc_address_is_well_formed(address),
 	}
 }
 
 // bitcoin
-#[cfg_attr(not(test), allow(dead_code))]
 const BTC_MIN_LENGTH: usize = 25;
+const BTC_PUBKEY_HASH_LENGTH: usize = 20;
+const BTC_P2PKH_VERSION_MAINNET: u8 = 0x00;
+const BTC_P2PKH_VERSION_TESTNET: u8 = 0x6f;
+
+fn btc_checksum(payload: &[u8]) -> [u8; 4] {
+	let hash = sha2_256(&sha2_256(payload));
+	[hash[0], hash[1], hash[2], hash[3]]
+}
+
+fn btc_checksum_matches(address_decoded: &[u8]) -> bool {
+	let (payload, checksum) = address_decoded.split_at(address_decoded.len() - 4);
+	checksum == btc_checksum(payload)
+}
 
-#[cfg_attr(not(test), allow(dead_code))]
 fn btc_address_is_well_formed(address: &[u8]) -> bool {
 	let address_str = if let Ok(s) = core::str::from_utf8(address) {
 		s
@@ -81,14 +177,7 @@ fn btc_address_is_well_formed(address: &[u8]) -> bool {
 		return false;
 	}
 
-	let last4 = &address_decoded[address_decoded.len() - 4..];
-	let hash = sha2_256(&sha2_256(&address_decoded[0..address_decoded.len() - 4]));
-	let checksum = &hash[0..4];
-	if last4 != checksum {
-		return false;
-	}
-
-	true
+	btc_checksum_matches(&address_decoded)
 }
 
 // ether-like
@@ -171,6 +260,73 @@ mod tests {
 			.unwrap();
 
 		assert!(address_is_well_formed(&ethereum, &eth_addr));
+
+		let btc_addr = b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".to_vec().try_into().unwrap();
+		assert!(address_is_well_formed(&Blockchain::BITCOIN, &btc_addr));
+		assert!(!address_is_well_formed(&Blockchain::BITCOIN, &eth_addr));
+	}
+
+	fn secret_key_one() -> sp_core::ecdsa::Pair {
+		let mut seed = [0u8; 32];
+		seed[31] = 1;
+		sp_core::ecdsa::Pair::from_seed_slice(&seed).unwrap()
+	}
+
+	#[test]
+	#[allow(non_snake_case)]
+	fn BTCAddress_from_public_works() {
+		let public = secret_key_one().public();
+
+		let p2pkh =
+			BTCAddress::from_public(&public, BTCAddressType::P2pkh(BitcoinNetwork::Mainnet));
+		assert_eq!(p2pkh.as_slice(), b"1BgGZ9tcN4rm9KBzDn7KprQz87SZ26SAMH".as_slice());
+
+		let p2wpkh =
+			BTCAddress::from_public(&public, BTCAddressType::P2wpkh(BitcoinNetwork::Mainnet));
+		assert_eq!(p2wpkh.as_slice(), b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4".as_slice());
+
+		let p2wpkh =
+			BTCAddress::from_public(&public, BTCAddressType::P2wpkh(BitcoinNetwork::Testnet));
+		assert_eq!(p2wpkh.as_slice(), b"tb1qw508d6qejxtdg4y5r3zarvary0c5xw7kxpjzsx".as_slice());
+	}
+
+	#[test]
+	#[allow(non_snake_case)]
+	fn BTCAddress_address_type_roundtrip() {
+		let public = secret_key_one().public();
+
+		for address_type in [
+			BTCAddressType::P2pkh(BitcoinNetwork::Mainnet),
+			BTCAddressType::P2pkh(BitcoinNetwork::Testnet),
+			BTCAddressType::P2wpkh(BitcoinNetwork::Mainnet),
+			BTCAddressType::P2wpkh(BitcoinNetwork::Testnet),
+		] {
+			let address = BTCAddress::from_public(&public, address_type);
+			assert!(btc_address_is_well_formed(&address));
+			assert_eq!(BTCAddress::try_extract_address_type(&address), Some(address_type));
+		}
+
+		// p2sh and taproot outputs are not owned by a single key
+		let p2sh = b"3J98t1WpEZ73CNmQviecrnyiWrnqRhWNLy".to_vec().try_into().unwrap();
+		assert_eq!(BTCAddress::try_extract_address_type(&p2sh), None);
+		let p2tr = b"bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y"
+			.to_vec()
+			.try_into()
+			.unwrap();
+		assert_eq!(BTCAddress::try_extract_address_type(&p2tr), None);
+	}
+
+	#[test]
+	fn generate_external_address_checks_bitcoin_network() {
+		let public = secret_key_one().public();
+		let mainnet =
+			BTCAddress::from_public(&public, BTCAddressType::P2wpkh(BitcoinNetwork::Mainnet));
+
+		assert_eq!(
+			generate_external_address(&Blockchain::BITCOIN, &mainnet, public),
+			Some(mainnet.clone())
+		);
+		assert_eq!(generate_external_address(&Blockchain::BITCOIN_TESTNET, &mainnet, public), None);
 	}
 
 	#[test]
@@ -0,0 +1,245 @@
+use crate::{
+	pallet::*, types::DoubleMapExt, Address, AskOrder, AskOrderId, BidOrder, BidOrderId, Offer,
+	OfferId, OrderId, QueuedOrders, RateKey,
+};
+use frame_support::weights::Weight;
+use frame_system::pallet_prelude::*;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::prelude::*;
+
+/// The most queued orders that are matched in a single block.
+pub const MAX_MATCHES_PER_BLOCK: u32 = 8;
+/// The most open counter-orders that are considered when matching a single order. Counter-orders
+/// are visited best rate first, and those whose rate is not acceptable are never visited.
+pub const MAX_MATCH_CANDIDATES: usize = 32;
+
+type AskOrderFor<T> = AskOrder<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+>;
+type BidOrderFor<T> = BidOrder<
+	<T as frame_system::Config>::AccountId,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+>;
+
+impl<T: Config> Pallet<T> {
+	/// Queues a new ask order for matching. When the queue is full the order is opened without
+	/// being matched, so that the bids added after it are still matched against it.
+	pub fn queue_ask_order(
+		ask_id: &AskOrderId<T::BlockNumber, T::Hash>,
+		ask_order: &AskOrderFor<T>,
+	) {
+		if !Self::try_queue_for_matching(OrderId::Ask(ask_id.clone())) {
+			Self::open_ask_order(ask_id, ask_order);
+		}
+	}
+
+	/// Queues a new bid order for matching. When the queue is full the order is opened without
+	/// being matched, so that the asks added after it are still matched against it.
+	pub fn queue_bid_order(
+		bid_id: &BidOrderId<T::BlockNumber, T::Hash>,
+		bid_order: &BidOrderFor<T>,
+	) {
+		if !Self::try_queue_for_matching(OrderId::Bid(bid_id.clone())) {
+			Self::open_bid_order(bid_id, bid_order);
+		}
+	}
+
+	fn try_queue_for_matching(order_id: OrderId<T::BlockNumber, T::Hash>) -> bool {
+		MatchQueue::<T>::mutate(|queue| queue.try_push(order_id).is_ok())
+	}
+
+	/// Matches up to [`MAX_MATCHES_PER_BLOCK`] queued orders against the open orders on the
+	/// other side of the book. Orders without a compatible counter-order are left open so that
+	/// later orders can be matched against them.
+	pub fn match_queued_orders() -> Weight {
+		let mut batch = MatchQueue::<T>::take().into_inner();
+		let rest = batch.split_off(batch.len().min(MAX_MATCHES_PER_BLOCK as usize));
+		if !rest.is_empty() {
+			MatchQueue::<T>::put(
+				QueuedOrders::try_from(rest)
+					.expect("the rest of the queue is shorter than the queue; qed"),
+			);
+		}
+
+		let head = Self::block_number();
+		let matched = batch.len().unique_saturated_into();
+		for order_id in batch {
+			match order_id {
+				OrderId::Ask(ask_id) => Self::match_ask_order(&ask_id, head),
+				OrderId::Bid(bid_id) => Self::match_bid_order(&bid_id, head),
+			}
+		}
+
+		<T as Config>::WeightInfo::match_orders(matched)
+	}
+
+	fn match_ask_order(ask_id: &AskOrderId<T::BlockNumber, T::Hash>, head: BlockNumberFor<T>) {
+		let ask_order = match AskOrders::<T>::try_get_id(ask_id) {
+			Ok(ask_order) if ask_order.expiration_block >= head => ask_order,
+			_ => return,
+		};
+		let lender_address = match Self::get_address(&ask_order.lender_address_id) {
+			Ok(address) => address,
+			Err(_) => return,
+		};
+		let currency = ask_order.terms.currency.clone();
+		let class = ask_order.terms.interest_rate.class();
+		let rate = ask_order.terms.interest_rate.sort_key();
+
+		// bids are stored highest rate first, so the ones below the lender's rate come last
+		let candidates: Vec<_> = OpenBidOrders::<T>::iter_key_prefix((currency.clone(), class))
+			.take_while(|(key, _)| descending(key) >= rate)
+			.take(MAX_MATCH_CANDIDATES)
+			.collect();
+		let mut best: Option<(RateKey, BidOrderId<T::BlockNumber, T::Hash>, BidOrderFor<T>)> = None;
+		for (key, bid_id) in candidates {
+			let bid_order = match BidOrders::<T>::try_get_id(&bid_id) {
+				Ok(bid_order) if bid_order.expiration_block >= head => bid_order,
+				_ => {
+					OpenBidOrders::<T>::remove((&currency, class, key, &bid_id));
+					continue;
+				},
+			};
+			if !Self::is_match(&ask_order, &lender_address, &bid_order) {
+				continue;
+			}
+			match &best {
+				Some((best_key, ..)) if *best_key != key => break,
+				Some((.., best)) if best.block <= bid_order.block => {},
+				_ => best = Some((key, bid_id, bid_order)),
+			}
+		}
+
+		match best {
+			Some((_, bid_id, bid_order)) => {
+				Self::close_bid_order(&bid_id, &bid_order);
+				Self::add_matched_offer(ask_id.clone(), &ask_order, bid_id, &bid_order);
+			},
+			None => Self::open_ask_order(ask_id, &ask_order),
+		}
+	}
+
+	fn match_bid_order(bid_id: &BidOrderId<T::BlockNumber, T::Hash>, head: BlockNumberFor<T>) {
+		let bid_order = match BidOrders::<T>::try_get_id(bid_id) {
+			Ok(bid_order) if bid_order.expiration_block >= head => bid_order,
+			_ => return,
+		};
+		let currency = bid_order.terms.currency.clone();
+		let class = bid_order.terms.interest_rate.class();
+		let rate = bid_order.terms.interest_rate.sort_key();
+
+		// asks are stored cheapest rate first, so the ones above the borrower's rate come last
+		let candidates: Vec<_> = OpenAskOrders::<T>::iter_key_prefix((currency.clone(), class))
+			.take_while(|(key, _)| *key <= rate)
+			.take(MAX_MATCH_CANDIDATES)
+			.collect();
+		let mut best: Option<(RateKey, AskOrderId<T::BlockNumber, T::Hash>, AskOrderFor<T>)> = None;
+		for (key, ask_id) in candidates {
+			let ask_order = match AskOrders::<T>::try_get_id(&ask_id) {
+				Ok(ask_order) if ask_order.expiration_block >= head => ask_order,
+				_ => {
+					OpenAskOrders::<T>::remove((&currency, class, key, &ask_id));
+					continue;
+				},
+			};
+			let lender_address = match Self::get_address(&ask_order.lender_address_id) {
+				Ok(address) => address,
+				Err(_) => continue,
+			};
+			if !Self::is_match(&ask_order, &lender_address, &bid_order) {
+				continue;
+			}
+			match &best {
+				Some((best_key, ..)) if *best_key != key => break,
+				Some((.., best)) if best.block <= ask_order.block => {},
+				_ => best = Some((key, ask_id, ask_order)),
+			}
+		}
+
+		match best {
+			Some((_, ask_id, ask_order)) => {
+				Self::close_ask_order(&ask_id, &ask_order);
+				Self::add_matched_offer(ask_id, &ask_order, bid_id.clone(), &bid_order);
+			},
+			None => Self::open_bid_order(bid_id, &bid_order),
+		}
+	}
+
+	fn open_ask_order(ask_id: &AskOrderId<T::BlockNumber, T::Hash>, ask_order: &AskOrderFor<T>) {
+		let rate = &ask_order.terms.interest_rate;
+		let key = (&ask_order.terms.currency, rate.class(), rate.sort_key(), ask_id);
+		OpenAskOrders::<T>::insert(key, ());
+	}
+
+	fn open_bid_order(bid_id: &BidOrderId<T::BlockNumber, T::Hash>, bid_order: &BidOrderFor<T>) {
+		let rate = &bid_order.terms.interest_rate;
+		let key = (&bid_order.terms.currency, rate.class(), descending(&rate.sort_key()), bid_id);
+		OpenBidOrders::<T>::insert(key, ());
+	}
+
+	/// Takes an ask order out of the open orders that bids are matched against.
+	pub fn close_ask_order(
+		ask_id: &AskOrderId<T::BlockNumber, T::Hash>,
+		ask_order: &AskOrderFor<T>,
+	) {
+		let rate = &ask_order.terms.interest_rate;
+		let key = (&ask_order.terms.currency, rate.class(), rate.sort_key(), ask_id);
+		OpenAskOrders::<T>::remove(key);
+	}
+
+	/// Takes a bid order out of the open orders that asks are matched against.
+	pub fn close_bid_order(
+		bid_id: &BidOrderId<T::BlockNumber, T::Hash>,
+		bid_order: &BidOrderFor<T>,
+	) {
+		let rate = &bid_order.terms.interest_rate;
+		let key = (&bid_order.terms.currency, rate.class(), descending(&rate.sort_key()), bid_id);
+		OpenBidOrders::<T>::remove(key);
+	}
+
+	fn is_match(
+		ask_order: &AskOrderFor<T>,
+		lender_address: &Address<T::AccountId>,
+		bid_order: &BidOrderFor<T>,
+	) -> bool {
+		if ask_order.lender == bid_order.borrower || !ask_order.terms.match_with(&bid_order.terms) {
+			return false;
+		}
+		match Self::get_address(&bid_order.borrower_address_id) {
+			Ok(borrower_address) => borrower_address.matches_chain_of(lender_address),
+			Err(_) => false,
+		}
+	}
+
+	fn add_matched_offer(
+		ask_id: AskOrderId<T::BlockNumber, T::Hash>,
+		ask_order: &AskOrderFor<T>,
+		bid_id: BidOrderId<T::BlockNumber, T::Hash>,
+		bid_order: &BidOrderFor<T>,
+	) {
+		let expiration_block = ask_order.expiration_block.min(bid_order.expiration_block);
+		let offer_id = OfferId::new::<T>(expiration_block, &ask_id, &bid_id);
+		if Offers::<T>::contains_id(&offer_id) {
+			return;
+		}
+
+		let offer = Offer {
+			ask_id,
+			bid_id,
+			block: Self::block_number(),
+			expiration_block,
+			lender: ask_order.lender.clone(),
+		};
+
+		Self::deposit_event(Event::<T>::OfferAdded(offer_id.clone(), offer.clone()));
+		Offers::<T>::insert_id(offer_id, offer);
+	}
+}
+
+/// Flips a rate key so that higher rates sort first.
+fn descending(key: &RateKey) -> RateKey {
+	key.map(|byte| !byte)
+}
@@ -0,0 +1,133 @@
+use super::DealOrderFor;
+use crate::{pallet::*, types::DoubleMapExt, DealOrderId};
+use frame_support::weights::Weight;
+use sp_runtime::traits::UniqueSaturatedInto;
+use sp_std::prelude::*;
+
+/// Deal orders are grouped by the window of time in which their term ends, so that only
+/// the windows which have fully elapsed need to be visited when looking for defaults.
+pub const MATURITY_BUCKET_MILLIS: u64 = 10 * 60 * 1000;
+
+impl<T: Config> Pallet<T> {
+	fn maturity_bucket(at: u64) -> u64 {
+		at / MATURITY_BUCKET_MILLIS
+	}
+
+	/// The time (in milliseconds) at which the term of a funded deal order ends.
+	pub fn deal_order_maturity(deal_order: &DealOrderFor<T>) -> u64 {
+		let started: u64 = deal_order.timestamp.unique_saturated_into();
+		let term: u64 = deal_order.terms.term_length.as_millis().unique_saturated_into();
+		started.saturating_add(term)
+	}
+
+	pub fn schedule_maturity(
+		deal_order_id: &DealOrderId<T::BlockNumber, T::Hash>,
+		deal_order: &DealOrderFor<T>,
+	) {
+		let bucket = Self::maturity_bucket(Self::deal_order_maturity(deal_order));
+		DealOrderMaturities::<T>::insert(bucket, deal_order_id, ());
+		NextMaturityBucket::<T>::mutate(|next| {
+			if next.map_or(true, |next| bucket < next) {
+				*next = Some(bucket);
+			}
+		});
+	}
+
+	/// Moves a deal order whose term has changed from the window of its previous maturity into
+	/// the window of its current one.
+	pub fn reschedule_maturity(
+		deal_order_id: &DealOrderId<T::BlockNumber, T::Hash>,
+		previous_maturity: u64,
+		deal_order: &DealOrderFor<T>,
+	) {
+		DealOrderMaturities::<T>::remove(Self::maturity_bucket(previous_maturity), deal_order_id);
+		Self::schedule_maturity(deal_order_id, deal_order);
+	}
+
+	/// Marks deal orders whose term has ended without being repaid as defaulted, visiting as
+	/// many matured deal orders as fit in `remaining_weight`. Looking into a maturity window
+	/// costs a storage read, each deal order found in it costs a full default check.
+	pub fn mark_defaulted_deal_orders(remaining_weight: Weight) -> Weight {
+		let base = <T as Config>::WeightInfo::on_idle(0);
+		if remaining_weight.ref_time() < base.ref_time() {
+			return Weight::zero();
+		}
+		let per_check = <T as Config>::WeightInfo::on_idle(1)
+			.ref_time()
+			.saturating_sub(base.ref_time())
+			.max(1);
+		let per_read = T::DbWeight::get().reads(1).ref_time().max(1);
+		let budget = remaining_weight.ref_time().saturating_sub(base.ref_time());
+
+		let mut bucket = match NextMaturityBucket::<T>::get() {
+			Some(bucket) => bucket,
+			None => return base,
+		};
+		let now: u64 = Self::timestamp().unique_saturated_into();
+		let current_bucket = Self::maturity_bucket(now);
+		let head = Self::block_number();
+
+		let mut spent = 0u64;
+		let mut checked = 0u64;
+		let mut reads = 0u64;
+		while bucket < current_bucket && spent.saturating_add(per_read) <= budget {
+			spent = spent.saturating_add(per_read);
+			reads = reads.saturating_add(1);
+
+			let room = (budget - spent) / per_check;
+			let mut keys = DealOrderMaturities::<T>::iter_key_prefix(bucket);
+			let matured: Vec<_> = keys.by_ref().take(room.unique_saturated_into()).collect();
+			let exhausted = keys.next().is_none();
+
+			for deal_order_id in matured {
+				DealOrderMaturities::<T>::remove(bucket, &deal_order_id);
+				Self::check_for_default(&deal_order_id, head);
+				spent = spent.saturating_add(per_check);
+				checked = checked.saturating_add(1);
+			}
+
+			if !exhausted {
+				break;
+			}
+			bucket = bucket.saturating_add(1);
+		}
+
+		if DealOrderMaturities::<T>::iter_keys().next().is_none() {
+			NextMaturityBucket::<T>::kill();
+		} else {
+			NextMaturityBucket::<T>::put(bucket);
+		}
+
+		<T as Config>::WeightInfo::on_idle(checked.unique_saturated_into())
+			.saturating_add(T::DbWeight::get().reads(reads))
+	}
+
+	/// Forgets that a deal order defaulted once it has been repaid in full or closed.
+	pub fn clear_default(deal_order_id: &DealOrderId<T::BlockNumber, T::Hash>) {
+		DefaultedDealOrders::<T>::remove(deal_order_id.expiration(), deal_order_id.hash());
+	}
+
+	fn check_for_default(
+		deal_order_id: &DealOrderId<T::BlockNumber, T::Hash>,
+		head: T::BlockNumber,
+	) {
+		let deal_order = match DealOrders::<T>::try_get_id(deal_order_id) {
+			Ok(deal_order) => deal_order,
+			Err(()) => return,
+		};
+		if deal_order.repayment_transfer_id.is_some() {
+			return;
+		}
+
+		let maturity = Self::deal_order_maturity(&deal_order).unique_saturated_into();
+		if let Ok(outstanding) = Self::deal_order_outstanding(&deal_order, maturity) {
+			if outstanding.is_zero() {
+				return;
+			}
+		}
+
+		DefaultedDealOrders::<T>::insert_id(deal_order_id.clone(), head);
+		Self::record_default(&deal_order.borrower);
+		Self::deposit_event(Event::<T>::DealOrderDefaulted(deal_order_id.clone()));
+	}
+}
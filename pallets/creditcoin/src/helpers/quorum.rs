@@ -0,0 +1,88 @@
+use super::non_paying_error;
+use crate::{pallet::*, TaskId};
+use frame_support::{dispatch::DispatchErrorWithPostInfo, ensure};
+use pallet_offchain_task_scheduler::authority::AuthorityController;
+use sp_runtime::traits::Zero;
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Records `who`'s vote for the outcome of `task_id`, which is the hash of either the task's
+	/// output or the cause of its failure. Once enough authorities have voted for the same outcome
+	/// for it to be acted on, records that outcome and returns those authorities with `who` first.
+	/// The votes are kept after the quorum is reached, as evidence for `slash_authority`. Without
+	/// a quorum threshold of at least 2, every vote is acted on as soon as it is submitted, and
+	/// only the vote is recorded.
+	pub fn vote_on_task_output(
+		who: &T::AccountId,
+		deadline: T::BlockNumber,
+		task_id: &TaskId<T::Hash>,
+		vote: T::Hash,
+	) -> Result<Option<Vec<T::AccountId>>, DispatchErrorWithPostInfo> {
+		match task_id {
+			TaskId::VerifyTransfer(id) => ensure!(
+				!Transfers::<T>::contains_key(id),
+				non_paying_error(Error::<T>::TransferAlreadyRegistered)
+			),
+			TaskId::CollectCoins(id) => ensure!(
+				!CollectedCoins::<T>::contains_key(id),
+				non_paying_error(Error::<T>::CollectCoinsAlreadyRegistered)
+			),
+		}
+		ensure!(
+			!TaskOutcomes::<T>::contains_key(deadline, task_id),
+			non_paying_error(Error::<T>::TaskAlreadyDecided)
+		);
+		ensure!(
+			!TaskOutputVotes::<T>::contains_key((deadline, task_id, who)),
+			non_paying_error(Error::<T>::AlreadyVoted)
+		);
+
+		let threshold = Self::quorum_threshold();
+		if threshold < 2 {
+			TaskOutputVotes::<T>::insert((deadline, task_id, who), vote);
+			return Ok(Some(vec![who.clone()]));
+		}
+
+		let mut agreeing = vec![who.clone()];
+		let mut disputed = false;
+		for (voter, other) in TaskOutputVotes::<T>::iter_prefix((deadline, task_id.clone())) {
+			if other == vote {
+				agreeing.push(voter);
+			} else {
+				disputed = true;
+			}
+		}
+
+		if disputed {
+			Self::deposit_event(Event::<T>::TaskOutputDisputed(task_id.clone(), who.clone(), vote));
+		}
+
+		TaskOutputVotes::<T>::insert((deadline, task_id, who), vote);
+
+		if (agreeing.len() as u32) < threshold {
+			Self::deposit_event(Event::<T>::TaskOutputVoted(task_id.clone(), who.clone(), vote));
+			return Ok(None);
+		}
+
+		TaskOutcomes::<T>::insert(deadline, task_id, vote);
+		Self::deposit_event(Event::<T>::TaskOutputQuorumReached(task_id.clone(), vote));
+
+		Ok(Some(agreeing))
+	}
+
+	/// Ensures that once an authority is removed, enough remain to reach the quorum threshold.
+	pub fn ensure_quorum_after_removal() -> Result<(), Error<T>> {
+		let remaining = T::TaskScheduler::authority_count().saturating_sub(1);
+		ensure!(Self::quorum_threshold() <= remaining, Error::<T>::QuorumThresholdTooHigh);
+		Ok(())
+	}
+
+	/// Ensures that `who` has reserved a bond, unless no bond is required.
+	pub fn ensure_bonded(who: &T::AccountId) -> Result<(), Error<T>> {
+		ensure!(
+			AuthorityBonds::<T>::contains_key(who) || Self::authority_bond().is_zero(),
+			Error::<T>::AuthorityNotBonded
+		);
+		Ok(())
+	}
+}
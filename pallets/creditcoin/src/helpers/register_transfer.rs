@@ -140,7 +140,7 @@ impl<T: Config> Pallet<T> {
 							EvmCurrencyType::SmartContract(
 								contract,
 								EvmSupportedTransferKinds::try_from(vec![EvmTransferKind::Ethless])
-									.expect("length 1 is less than the bound 2; qed"),
+									.expect("length 1 is less than the bound 3; qed"),
 							),
 							info.clone(),
 						),
@@ -148,12 +148,16 @@ impl<T: Config> Pallet<T> {
 							EvmCurrencyType::SmartContract(
 								contract,
 								EvmSupportedTransferKinds::try_from(vec![EvmTransferKind::Erc20])
-									.expect("length 1 is less than the bound 2; qed"),
+									.expect("length 1 is less than the bound 3; qed"),
 							),
 							info.clone(),
 						),
+						LegacyTransferKind::Native => {
+							Currency::Evm(EvmCurrencyType::Native, info.clone())
+						},
 						_ => return Err(Error::<T>::UnsupportedTransferKind),
 					},
+					Blockchain::Bitcoin(_) => return Err(Error::<T>::UnsupportedTransferKind),
 				};
 				let currency_id = CurrencyId::new::<T>(&currency);
 				deal_order.terms.currency = currency_id;
@@ -212,6 +216,7 @@ impl<T: Config> Pallet<T> {
 		);
 		let pending_transfer = Task::from(pending_transfer);
 		T::TaskScheduler::insert(&deadline, task_id, pending_transfer);
+		Self::track_task(deadline, TransferId::from(*task_id).into());
 
 		Ok(())
 	}
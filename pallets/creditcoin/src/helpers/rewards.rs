@@ -0,0 +1,124 @@
+use crate::{pallet::*, TaskId};
+use frame_support::traits::{BalanceStatus, Get, ReservableCurrency};
+use pallet_offchain_task_scheduler::tasks::TaskScheduler;
+use sp_runtime::{
+	traits::{One, Saturating, Zero},
+	DispatchResult,
+};
+use sp_std::prelude::*;
+use tracing as log;
+
+type Balances<T> = pallet_balances::Pallet<T>;
+
+impl<T: Config> Pallet<T> {
+	/// The index of the reward era `block_number` belongs to.
+	pub fn reward_era(block_number: T::BlockNumber) -> T::BlockNumber {
+		block_number / T::RewardEraLength::get().max(One::one())
+	}
+
+	/// Reserves the task fee from `who`, who registered the task `task_id`. The fee is paid to
+	/// the authorities that complete the task, or returned to `who` if the task fails or expires.
+	pub fn charge_task_fee(who: &T::AccountId, task_id: TaskId<T::Hash>) -> DispatchResult {
+		let fee = Self::task_fee();
+		if fee.is_zero() {
+			return Ok(());
+		}
+
+		<Balances<T> as ReservableCurrency<T::AccountId>>::reserve(who, fee)?;
+		TaskFees::<T>::insert(T::TaskScheduler::deadline(), task_id, (who.clone(), fee));
+
+		Ok(())
+	}
+
+	/// Pays the fee reserved for `task_id` to the `authorities` that completed it, split evenly.
+	/// The first authority also receives whatever is left over by the split.
+	pub fn reward_authorities(
+		deadline: T::BlockNumber,
+		task_id: &TaskId<T::Hash>,
+		authorities: &[T::AccountId],
+	) {
+		let (payer, fee) = match TaskFees::<T>::take(deadline, task_id) {
+			Some(fee) => fee,
+			None => return,
+		};
+		if authorities.is_empty() {
+			<Balances<T> as ReservableCurrency<T::AccountId>>::unreserve(&payer, fee);
+			return;
+		}
+
+		let count = T::Balance::from(authorities.len() as u32);
+		let share = fee / count;
+		let mut remainder = fee.saturating_sub(share.saturating_mul(count));
+		let era = Self::reward_era(Self::block_number());
+		let mut paid = T::Balance::zero();
+
+		for authority in authorities {
+			let amount = share.saturating_add(core::mem::take(&mut remainder));
+			match <Balances<T> as ReservableCurrency<T::AccountId>>::repatriate_reserved(
+				&payer,
+				authority,
+				amount,
+				BalanceStatus::Free,
+			) {
+				Ok(unpaid) => {
+					let reward = amount.saturating_sub(unpaid);
+					paid.saturating_accrue(reward);
+					EraRewards::<T>::mutate(era, authority, |total| {
+						total.saturating_accrue(reward)
+					});
+					Self::deposit_event(Event::<T>::AuthorityRewarded(
+						authority.clone(),
+						task_id.clone(),
+						reward,
+					));
+				},
+				Err(e) => log::warn!("Failed to reward authority {:?}: {:?}", authority, e),
+			}
+		}
+
+		EraRewardTotals::<T>::mutate(era, |total| total.saturating_accrue(paid));
+		<Balances<T> as ReservableCurrency<T::AccountId>>::unreserve(
+			&payer,
+			fee.saturating_sub(paid),
+		);
+	}
+
+	/// Returns the fee reserved for `task_id`, which failed, to the account that paid it.
+	pub fn refund_task_fee(deadline: T::BlockNumber, task_id: &TaskId<T::Hash>) {
+		if let Some((payer, fee)) = TaskFees::<T>::take(deadline, task_id) {
+			<Balances<T> as ReservableCurrency<T::AccountId>>::unreserve(&payer, fee);
+		}
+	}
+
+	/// Returns the fees reserved for tasks that expired at `deadline` to the accounts that paid
+	/// them, and returns how many tasks expired.
+	pub fn refund_expired_task_fees(deadline: T::BlockNumber) -> u32 {
+		let mut count = 0u32;
+		for (_, (payer, fee)) in TaskFees::<T>::drain_prefix(deadline) {
+			<Balances<T> as ReservableCurrency<T::AccountId>>::unreserve(&payer, fee);
+			count.saturating_inc();
+		}
+		count
+	}
+
+	/// Reports the rewards paid during the era that ended right before `block_number`, if any,
+	/// and prunes the rewards of the era that fell out of the retention window. Returns how many
+	/// authority rewards were pruned.
+	pub fn report_ended_reward_era(block_number: T::BlockNumber) -> u32 {
+		let era = Self::reward_era(block_number);
+		if era.is_zero() || Self::reward_era(block_number.saturating_sub(One::one())) == era {
+			return 0;
+		}
+
+		let ended = era.saturating_sub(One::one());
+		Self::deposit_event(Event::<T>::RewardEraEnded(ended, EraRewardTotals::<T>::get(ended)));
+
+		let retention = T::RewardEraRetention::get();
+		if era < retention.saturating_add(One::one()) {
+			return 0;
+		}
+		let pruned = era.saturating_sub(retention).saturating_sub(One::one());
+		EraRewardTotals::<T>::remove(pruned);
+		EraRewards::<T>::clear_prefix(pruned, u32::MAX, None).backend
+	}
+}
@@ -0,0 +1,58 @@
+use crate::{pallet::*, TaskId, TaskStatus};
+use frame_support::traits::Get;
+use sp_runtime::traits::{One, Saturating};
+use sp_std::prelude::*;
+
+impl<T: Config> Pallet<T> {
+	/// Starts tracking `task_id` as pending until its `deadline`.
+	pub fn track_task(deadline: T::BlockNumber, task_id: TaskId<T::Hash>) {
+		TaskStatuses::<T>::insert(&task_id, TaskStatus::Pending(deadline));
+		Self::schedule_task_status_update(deadline, &task_id);
+	}
+
+	/// Records the final `status` of the task `task_id` and keeps it for `TaskStatusRetention`
+	/// blocks.
+	pub fn finish_task(task_id: &TaskId<T::Hash>, status: TaskStatus<T::BlockNumber>) {
+		TaskStatuses::<T>::insert(task_id, status);
+		Self::schedule_task_status_update(Self::task_status_expiration(), task_id);
+	}
+
+	/// Marks the tasks that are still pending at `block_number` as expired and stops tracking
+	/// the ones whose retention period has ended. Returns how many tasks were updated.
+	pub fn update_task_statuses(block_number: T::BlockNumber) -> u32 {
+		let task_ids: Vec<_> =
+			TaskStatusUpdates::<T>::drain_prefix(block_number).map(|(id, ())| id).collect();
+
+		for task_id in &task_ids {
+			match TaskStatuses::<T>::get(task_id) {
+				Some(TaskStatus::Pending(_)) => {
+					TaskStatuses::<T>::insert(task_id, TaskStatus::Expired);
+					let expiration = Self::task_status_expiration();
+					TaskStatusUpdates::<T>::insert(expiration, task_id, ());
+					TaskStatusUpdateBlocks::<T>::insert(task_id, expiration);
+				},
+				_ => {
+					TaskStatuses::<T>::remove(task_id);
+					TaskStatusUpdateBlocks::<T>::remove(task_id);
+				},
+			}
+		}
+
+		task_ids.len() as u32
+	}
+
+	/// Schedules the next update of `task_id`'s status at `block_number`, replacing the update
+	/// scheduled for an earlier registration or state of the task, if any.
+	fn schedule_task_status_update(block_number: T::BlockNumber, task_id: &TaskId<T::Hash>) {
+		if let Some(previous) = TaskStatusUpdateBlocks::<T>::get(task_id) {
+			TaskStatusUpdates::<T>::remove(previous, task_id);
+		}
+		TaskStatusUpdates::<T>::insert(block_number, task_id, ());
+		TaskStatusUpdateBlocks::<T>::insert(task_id, block_number);
+	}
+
+	fn task_status_expiration() -> T::BlockNumber {
+		let retention = T::TaskStatusRetention::get().max(One::one());
+		Self::block_number().saturating_add(retention)
+	}
+}
@@ -29,7 +29,7 @@ pub use types::*;
 
 pub type BalanceFor<T> = <T as pallet_balances::Config>::Balance;
 
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(11);
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -39,14 +39,20 @@ pub mod pallet {
 	use frame_support::{
 		dispatch::{DispatchResult, PostDispatchInfo},
 		pallet_prelude::*,
-		traits::tokens::{currency::Currency as CurrencyT, fungible::Mutate, ExistenceRequirement},
+		traits::tokens::{
+			currency::{Currency as CurrencyT, ReservableCurrency},
+			fungible::Mutate,
+			ExistenceRequirement,
+		},
 		transactional,
 	};
 	use frame_system::{ensure_signed, offchain::CreateSignedTransaction, pallet_prelude::*};
 	use ocw::errors::VerificationFailureCause;
 	use pallet_offchain_task_scheduler::authority::AuthorityController;
 	use pallet_offchain_task_scheduler::tasks::{TaskScheduler, TaskV2};
-	use sp_runtime::traits::{IdentifyAccount, UniqueSaturatedFrom, UniqueSaturatedInto, Verify};
+	use sp_runtime::traits::{
+		Hash, IdentifyAccount, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Verify, Zero,
+	};
 	use tracing as log;
 
 	/// Configure the pallet by specifying the parameters and types on which it depends.
@@ -86,6 +92,15 @@ pub mod pallet {
 
 		type UnverifiedTaskTimeout: Get<<Self as frame_system::Config>::BlockNumber>;
 
+		/// The number of blocks in each era over which authority rewards are reported.
+		type RewardEraLength: Get<<Self as frame_system::Config>::BlockNumber>;
+
+		/// The number of ended reward eras whose rewards are kept after the era is reported.
+		type RewardEraRetention: Get<<Self as frame_system::Config>::BlockNumber>;
+
+		/// The number of blocks a task's status is kept after the task completes, fails or expires.
+		type TaskStatusRetention: Get<<Self as frame_system::Config>::BlockNumber>;
+
 		type WeightInfo: WeightInfo;
 
 		type TaskScheduler: TaskScheduler<
@@ -98,12 +113,17 @@ pub mod pallet {
 	pub trait WeightInfo {
 		fn migration_v7(t: u32) -> Weight;
 		fn on_initialize(a: u32, b: u32, o: u32, d: u32, f: u32) -> Weight;
+		fn on_idle(c: u32) -> Weight;
+		fn match_orders(m: u32) -> Weight;
 		fn register_address() -> Weight;
 		fn claim_legacy_wallet() -> Weight;
 		fn add_ask_order() -> Weight;
 		fn add_bid_order() -> Weight;
 		fn add_offer() -> Weight;
 		fn add_deal_order() -> Weight;
+		fn cancel_ask_order() -> Weight;
+		fn cancel_bid_order() -> Weight;
+		fn cancel_offer() -> Weight;
 		fn add_authority() -> Weight;
 		fn persist_transfer() -> Weight;
 		fn fail_transfer() -> Weight;
@@ -113,7 +133,9 @@ pub mod pallet {
 		fn register_repayment_transfer() -> Weight;
 		fn register_funding_transfer_legacy() -> Weight;
 		fn register_repayment_transfer_legacy() -> Weight;
+		fn repay_installment() -> Weight;
 		fn close_deal_order() -> Weight;
+		fn assign_deal_order() -> Weight;
 		fn exempt() -> Weight;
 		fn register_deal_order() -> Weight;
 		fn request_collect_coins() -> Weight;
@@ -122,6 +144,14 @@ pub mod pallet {
 		fn remove_authority() -> Weight;
 		fn set_collect_coins_contract() -> Weight;
 		fn register_currency() -> Weight;
+		fn set_order_matching() -> Weight;
+		fn renegotiate_deal_order() -> Weight;
+		fn set_confirmation_policy() -> Weight;
+		fn set_quorum_threshold() -> Weight;
+		fn set_authority_bond() -> Weight;
+		fn slash_authority() -> Weight;
+		fn set_task_fee() -> Weight;
+		fn bond_authority() -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -228,6 +258,202 @@ pub mod pallet {
 	#[pallet::getter(fn collect_coins_contract)]
 	pub type CollectCoinsContract<T: Config> = StorageValue<_, GCreContract, ValueQuery>;
 
+	/// Funded deal orders, keyed by the window of time in which their term ends.
+	#[pallet::storage]
+	pub type DealOrderMaturities<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		u64,
+		Blake2_128Concat,
+		DealOrderId<T::BlockNumber, T::Hash>,
+		(),
+	>;
+
+	/// The earliest maturity window that has not been checked for defaults yet.
+	#[pallet::storage]
+	pub type NextMaturityBucket<T: Config> = StorageValue<_, u64>;
+
+	/// Deal orders that were not repaid by the end of their term, and the block at which
+	/// the default was detected.
+	#[pallet::storage]
+	#[pallet::getter(fn defaulted_deal_orders)]
+	pub type DefaultedDealOrders<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Identity, T::Hash, T::BlockNumber>;
+
+	/// Whether new ask and bid orders are matched on-chain.
+	#[pallet::storage]
+	#[pallet::getter(fn order_matching)]
+	pub type OrderMatching<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+	/// Orders waiting to be matched, in the order they were added.
+	#[pallet::storage]
+	pub type MatchQueue<T: Config> =
+		StorageValue<_, QueuedOrders<T::BlockNumber, T::Hash>, ValueQuery>;
+
+	/// Ask orders that have been through matching without finding a bid, by currency and rate
+	/// class, cheapest rate first.
+	#[pallet::storage]
+	pub type OpenAskOrders<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, CurrencyId<T::Hash>>,
+			NMapKey<Identity, RateClass>,
+			NMapKey<Identity, RateKey>,
+			NMapKey<Blake2_128Concat, AskOrderId<T::BlockNumber, T::Hash>>,
+		),
+		(),
+	>;
+
+	/// Bid orders that have been through matching without finding an ask, by currency and rate
+	/// class, highest acceptable rate first.
+	#[pallet::storage]
+	pub type OpenBidOrders<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, CurrencyId<T::Hash>>,
+			NMapKey<Identity, RateClass>,
+			NMapKey<Identity, RateKey>,
+			NMapKey<Blake2_128Concat, BidOrderId<T::BlockNumber, T::Hash>>,
+		),
+		(),
+	>;
+
+	/// How each borrower's loans have turned out.
+	#[pallet::storage]
+	#[pallet::getter(fn credit_records)]
+	pub type CreditRecords<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, CreditRecord, ValueQuery>;
+
+	/// The total principal funded to each borrower, by currency.
+	#[pallet::storage]
+	pub type BorrowedVolume<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Identity,
+		CurrencyId<T::Hash>,
+		ExternalAmount,
+		ValueQuery,
+	>;
+
+	/// The terms each renegotiated deal order had before each of its renegotiations, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn deal_order_amendments)]
+	pub type DealOrderAmendments<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Identity,
+		T::Hash,
+		Amendments<T::BlockNumber, T::Moment>,
+		ValueQuery,
+	>;
+
+	/// How many times each deal order has been assigned to a new lender. The new lender signs
+	/// over this count, so an assignment signature can't be replayed once it has been used.
+	#[pallet::storage]
+	#[pallet::getter(fn deal_order_assignments)]
+	pub type DealOrderAssignments<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::BlockNumber, Identity, T::Hash, u32, ValueQuery>;
+
+	/// How deep transactions on each external chain must be before they are verified. Chains
+	/// without an entry use the offchain worker's defaults.
+	#[pallet::storage]
+	#[pallet::getter(fn confirmation_policy)]
+	pub type ConfirmationPolicies<T: Config> =
+		StorageMap<_, Blake2_128Concat, Blockchain, ConfirmationPolicy>;
+
+	/// How many authorities must submit the same output for a task before it is persisted.
+	/// Below 2, the first output submitted by any authority is persisted.
+	#[pallet::storage]
+	#[pallet::getter(fn quorum_threshold)]
+	pub type QuorumThreshold<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The hash of the output, or of the failure, each authority submitted for a task. Kept for
+	/// `TaskStatusRetention` blocks past the task's deadline, by which it is keyed first.
+	#[pallet::storage]
+	pub type TaskOutputVotes<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Identity, T::BlockNumber>,
+			NMapKey<Identity, TaskId<T::Hash>>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		T::Hash,
+	>;
+
+	/// The vote each task reached a quorum on, kept for as long as the votes themselves. Nothing
+	/// is recorded for tasks decided by a single vote, below a quorum threshold of 2.
+	#[pallet::storage]
+	#[pallet::getter(fn task_outcomes)]
+	pub type TaskOutcomes<T: Config> =
+		StorageDoubleMap<_, Identity, T::BlockNumber, Identity, TaskId<T::Hash>, T::Hash>;
+
+	/// The amount an account must reserve to become an authority.
+	#[pallet::storage]
+	#[pallet::getter(fn authority_bond)]
+	pub type AuthorityBond<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// The amount each authority reserved when it was added.
+	#[pallet::storage]
+	#[pallet::getter(fn authority_bonds)]
+	pub type AuthorityBonds<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::Balance>;
+
+	/// The fee reserved from accounts registering a task, which rewards the authorities that
+	/// complete it.
+	#[pallet::storage]
+	#[pallet::getter(fn task_fee)]
+	pub type TaskFee<T: Config> = StorageValue<_, T::Balance, ValueQuery>;
+
+	/// The account that paid for each pending task and how much it paid, by the task's deadline.
+	#[pallet::storage]
+	pub type TaskFees<T: Config> = StorageDoubleMap<
+		_,
+		Identity,
+		T::BlockNumber,
+		Identity,
+		TaskId<T::Hash>,
+		(T::AccountId, T::Balance),
+	>;
+
+	/// The rewards each authority earned during each reward era, kept for `RewardEraRetention`
+	/// eras after the era ends.
+	#[pallet::storage]
+	#[pallet::getter(fn era_rewards)]
+	pub type EraRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		T::AccountId,
+		T::Balance,
+		ValueQuery,
+	>;
+
+	/// The rewards paid to all authorities during each reward era, kept for `RewardEraRetention`
+	/// eras after the era ends.
+	#[pallet::storage]
+	#[pallet::getter(fn era_reward_totals)]
+	pub type EraRewardTotals<T: Config> =
+		StorageMap<_, Twox64Concat, T::BlockNumber, T::Balance, ValueQuery>;
+
+	/// The status of each task registered recently enough for it to still be tracked.
+	#[pallet::storage]
+	#[pallet::getter(fn task_statuses)]
+	pub type TaskStatuses<T: Config> =
+		StorageMap<_, Identity, TaskId<T::Hash>, TaskStatus<T::BlockNumber>>;
+
+	/// The tasks whose status must be revisited at each block, either because they expire or
+	/// because they are no longer tracked.
+	#[pallet::storage]
+	pub type TaskStatusUpdates<T: Config> =
+		StorageDoubleMap<_, Identity, T::BlockNumber, Identity, TaskId<T::Hash>, ()>;
+
+	/// The block at which each task in `TaskStatusUpdates` is scheduled to be revisited.
+	#[pallet::storage]
+	pub type TaskStatusUpdateBlocks<T: Config> =
+		StorageMap<_, Identity, TaskId<T::Hash>, T::BlockNumber>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -327,6 +553,89 @@ pub mod pallet {
 		/// A currency has been registered and can now be used in loan terms.
 		/// [currency_id, currency]
 		CurrencyRegistered(CurrencyId<T::Hash>, Currency),
+
+		/// A borrower has repaid part of a deal order. The loan stays open until the
+		/// outstanding amount has been repaid and the deal order is closed.
+		/// [deal_order_id, transfer_id, outstanding_amount]
+		InstallmentRepaid(
+			DealOrderId<T::BlockNumber, T::Hash>,
+			TransferId<T::Hash>,
+			ExternalAmount,
+		),
+
+		/// A deal order has been assigned by its lender to a new lender. Repayments must now be
+		/// made to the new lender's address.
+		/// [deal_order_id, new_lender_address_id]
+		DealOrderAssigned(DealOrderId<T::BlockNumber, T::Hash>, AddressId<T::Hash>),
+
+		/// A funded deal order reached the end of its term without being repaid.
+		/// [defaulted_deal_order_id]
+		DealOrderDefaulted(DealOrderId<T::BlockNumber, T::Hash>),
+
+		/// An ask order has been cancelled by its lender before expiring.
+		/// [ask_order_id]
+		AskOrderCancelled(AskOrderId<T::BlockNumber, T::Hash>),
+
+		/// A bid order has been cancelled by its borrower before expiring.
+		/// [bid_order_id]
+		BidOrderCancelled(BidOrderId<T::BlockNumber, T::Hash>),
+
+		/// An offer has been cancelled by its lender before expiring.
+		/// [offer_id]
+		OfferCancelled(OfferId<T::BlockNumber, T::Hash>),
+
+		/// On-chain order matching has been enabled or disabled.
+		/// [enabled]
+		OrderMatchingSet(bool),
+
+		/// The lender and borrower of a deal order have agreed on new terms for it.
+		/// [deal_order_id, new_terms]
+		DealOrderRenegotiated(DealOrderId<T::BlockNumber, T::Hash>, LoanTerms<T::Hash>),
+
+		/// The confirmation policy of an external chain has been set, or reset to the default.
+		/// [blockchain, policy]
+		ConfirmationPolicySet(Blockchain, Option<ConfirmationPolicy>),
+
+		/// The number of authorities that must agree on the output of a task has been set.
+		/// [threshold]
+		QuorumThresholdSet(u32),
+
+		/// An authority voted for the output of a task which has not reached quorum yet.
+		/// [task_id, authority, output_hash]
+		TaskOutputVoted(TaskId<T::Hash>, T::AccountId, T::Hash),
+
+		/// An authority voted for a different output of a task than other authorities did.
+		/// [task_id, authority, output_hash]
+		TaskOutputDisputed(TaskId<T::Hash>, T::AccountId, T::Hash),
+
+		/// Enough authorities agreed on the output of a task for it to be persisted.
+		/// [task_id, output_hash]
+		TaskOutputQuorumReached(TaskId<T::Hash>, T::Hash),
+
+		/// The amount accounts must reserve to become authorities has been set.
+		/// [bond]
+		AuthorityBondSet(T::Balance),
+
+		/// An authority has been removed and its bond slashed for submitting the result of a task
+		/// which contradicts the external chain.
+		/// [authority, task_id, slashed_amount]
+		AuthoritySlashed(T::AccountId, TaskId<T::Hash>, T::Balance),
+
+		/// The fee accounts pay to register a task has been set.
+		/// [fee]
+		TaskFeeSet(T::Balance),
+
+		/// An authority has been paid part of the fee of a task it completed.
+		/// [authority, task_id, reward]
+		AuthorityRewarded(T::AccountId, TaskId<T::Hash>, T::Balance),
+
+		/// A reward era has ended.
+		/// [era, total_rewards]
+		RewardEraEnded(T::BlockNumber, T::Balance),
+
+		/// An authority which was added without a bond has reserved one.
+		/// [authority, bond]
+		AuthorityBonded(T::AccountId, T::Balance),
 	}
 
 	// Errors inform users that something went wrong.
@@ -499,6 +808,58 @@ pub mod pallet {
 
 		/// The currency with the given ID has not been registered.
 		CurrencyNotRegistered,
+
+		/// The interest accrued on the loan is too large to be represented.
+		InterestOverflow,
+
+		/// The deal order has reached the maximum number of repayments.
+		TooManyRepayments,
+
+		/// The deal order is locked and cannot be assigned to another lender.
+		DealOrderLocked,
+
+		/// The transfer was not sent to the deal order's lender address.
+		TransferRecipientMismatch,
+
+		/// The minimum of a loan term range is greater than its maximum.
+		InvalidTermRange,
+
+		/// Only the lender or the borrower of the deal order can perform the action.
+		NotDealParticipant,
+
+		/// A renegotiated term length must not be shorter than the current one.
+		TermLengthShortened,
+
+		/// The deal order has reached the maximum number of renegotiations.
+		TooManyAmendments,
+
+		/// The confirmation policy cannot be used on the given blockchain.
+		UnsupportedConfirmationPolicy,
+
+		/// The authority has already voted on the output of the task.
+		AlreadyVoted,
+
+		/// The deal order has defaulted and can no longer be renegotiated.
+		DealOrderDefaulted,
+
+		/// The quorum threshold would be higher than the number of authorities.
+		QuorumThresholdTooHigh,
+
+		/// The authorities have already reached a quorum on the outcome of the task.
+		TaskAlreadyDecided,
+
+		/// The authority did not submit the given vote on the task.
+		VoteNotRecorded,
+
+		/// The authority's vote agrees with the outcome the task's quorum reached, or the quorum
+		/// the task requires was not reached.
+		VoteNotContradicted,
+
+		/// The authority has not reserved a bond, which is required while the bond is not zero.
+		AuthorityNotBonded,
+
+		/// The authority has already reserved a bond.
+		AuthorityAlreadyBonded,
 	}
 
 	#[pallet::genesis_config]
@@ -539,9 +900,27 @@ pub mod pallet {
 		fn on_initialize(block_number: T::BlockNumber) -> Weight {
 			log::debug!("Cleaning up expired entries");
 
-			let ask_count = AskOrders::<T>::clear_prefix(block_number, u32::MAX, None).backend;
-			let bid_count = BidOrders::<T>::clear_prefix(block_number, u32::MAX, None).backend;
+			let mut ask_count = 0u32;
+			for (hash, ask_order) in AskOrders::<T>::drain_prefix(block_number) {
+				ask_count = ask_count.saturating_add(1);
+				let ask_id = AskOrderId::with_expiration_hash::<T>(block_number, hash);
+				Self::close_ask_order(&ask_id, &ask_order);
+			}
+			let mut bid_count = 0u32;
+			for (hash, bid_order) in BidOrders::<T>::drain_prefix(block_number) {
+				bid_count = bid_count.saturating_add(1);
+				let bid_id = BidOrderId::with_expiration_hash::<T>(block_number, hash);
+				Self::close_bid_order(&bid_id, &bid_order);
+			}
 			let offer_count = Offers::<T>::clear_prefix(block_number, u32::MAX, None).backend;
+			let votes_deadline = block_number.saturating_sub(T::TaskStatusRetention::get());
+			let vote_count =
+				TaskOutputVotes::<T>::clear_prefix((votes_deadline,), u32::MAX, None).backend;
+			let outcome_count =
+				TaskOutcomes::<T>::clear_prefix(votes_deadline, u32::MAX, None).backend;
+			let expired_fee_count = Self::refund_expired_task_fees(block_number);
+			let task_status_count = Self::update_task_statuses(block_number);
+			let pruned_reward_count = Self::report_ended_reward_era(block_number);
 
 			let mut deals_count = 0u32;
 			let deals_to_keep: Vec<_> = DealOrders::<T>::drain_prefix(block_number)
@@ -560,13 +939,30 @@ pub mod pallet {
 				DealOrders::<T>::insert_id(key, deal);
 			}
 
-			<T as Config>::WeightInfo::on_initialize(
+			let weight = <T as Config>::WeightInfo::on_initialize(
 				ask_count,
 				bid_count,
 				offer_count,
 				deals_count,
 				funded_deals_count,
-			)
+			);
+
+			weight
+				.saturating_add(T::DbWeight::get().writes(vote_count.into()))
+				.saturating_add(T::DbWeight::get().writes(outcome_count.into()))
+				.saturating_add(
+					T::DbWeight::get().reads_writes(1, 2).saturating_mul(expired_fee_count.into()),
+				)
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(T::DbWeight::get().writes(pruned_reward_count.into()))
+				.saturating_add(
+					T::DbWeight::get().reads_writes(1, 4).saturating_mul(task_status_count.into()),
+				)
+				.saturating_add(Self::match_queued_orders())
+		}
+
+		fn on_idle(_block_number: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::mark_defaulted_deal_orders(remaining_weight)
 		}
 
 		fn on_runtime_upgrade() -> Weight {
@@ -653,7 +1049,7 @@ pub mod pallet {
 		pub fn add_ask_order(
 			origin: OriginFor<T>,
 			address_id: AddressId<T::Hash>,
-			terms: LoanTerms<T::Hash>,
+			terms: LoanTermRanges<T::Hash>,
 			expiration_block: BlockNumberFor<T>,
 			guid: Guid,
 		) -> DispatchResult {
@@ -685,6 +1081,10 @@ pub mod pallet {
 				lender: who,
 			};
 
+			if Self::order_matching() {
+				Self::queue_ask_order(&ask_order_id, &ask_order);
+			}
+
 			Self::deposit_event(Event::<T>::AskOrderAdded(ask_order_id.clone(), ask_order.clone()));
 			AskOrders::<T>::insert_id(ask_order_id, ask_order);
 			Ok(())
@@ -694,7 +1094,7 @@ pub mod pallet {
 		pub fn add_bid_order(
 			origin: OriginFor<T>,
 			address_id: AddressId<T::Hash>,
-			terms: LoanTerms<T::Hash>,
+			terms: LoanTermRanges<T::Hash>,
 			expiration_block: BlockNumberFor<T>,
 			guid: Guid,
 		) -> DispatchResult {
@@ -726,6 +1126,10 @@ pub mod pallet {
 				borrower: who,
 			};
 
+			if Self::order_matching() {
+				Self::queue_bid_order(&bid_order_id, &bid_order);
+			}
+
 			Self::deposit_event(Event::<T>::BidOrderAdded(bid_order_id.clone(), bid_order.clone()));
 			BidOrders::<T>::insert_id(bid_order_id, bid_order);
 			Ok(())
@@ -822,6 +1226,9 @@ pub mod pallet {
 				funding_transfer_id: None,
 				lock: None,
 				repayment_transfer_id: None,
+				repaid_amount: ExternalAmount::zero(),
+				repayments: Repayments::default(),
+				accrual: None,
 			};
 
 			Self::deposit_event(Event::<T>::DealOrderAdded(
@@ -833,6 +1240,61 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Cancels an ask order owned by the caller. Offers that were made against the ask order
+		/// can no longer be turned into deal orders.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_ask_order())]
+		pub fn cancel_ask_order(
+			origin: OriginFor<T>,
+			ask_order_id: AskOrderId<T::BlockNumber, T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let ask_order = try_get_id!(AskOrders<T>, &ask_order_id, NonExistentAskOrder)?;
+			ensure!(ask_order.lender == who, Error::<T>::NotLender);
+
+			Self::close_ask_order(&ask_order_id, &ask_order);
+			AskOrders::<T>::remove(ask_order_id.expiration(), ask_order_id.hash());
+			Self::deposit_event(Event::<T>::AskOrderCancelled(ask_order_id));
+
+			Ok(())
+		}
+
+		/// Cancels a bid order owned by the caller. Offers that were made against the bid order
+		/// can no longer be turned into deal orders.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_bid_order())]
+		pub fn cancel_bid_order(
+			origin: OriginFor<T>,
+			bid_order_id: BidOrderId<T::BlockNumber, T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let bid_order = try_get_id!(BidOrders<T>, &bid_order_id, NonExistentBidOrder)?;
+			ensure!(bid_order.borrower == who, Error::<T>::NotBorrower);
+
+			Self::close_bid_order(&bid_order_id, &bid_order);
+			BidOrders::<T>::remove(bid_order_id.expiration(), bid_order_id.hash());
+			Self::deposit_event(Event::<T>::BidOrderCancelled(bid_order_id));
+
+			Ok(())
+		}
+
+		/// Cancels an offer made by the caller, so the borrower can no longer accept it.
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_offer())]
+		pub fn cancel_offer(
+			origin: OriginFor<T>,
+			offer_id: OfferId<T::BlockNumber, T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let offer = try_get_id!(Offers<T>, &offer_id, NonExistentOffer)?;
+			ensure!(offer.lender == who, Error::<T>::NotLender);
+
+			Offers::<T>::remove(offer_id.expiration(), offer_id.hash());
+			Self::deposit_event(Event::<T>::OfferCancelled(offer_id));
+
+			Ok(())
+		}
+
 		#[pallet::weight(<T as Config>::WeightInfo::lock_deal_order())]
 		pub fn lock_deal_order(
 			origin: OriginFor<T>,
@@ -888,6 +1350,7 @@ pub mod pallet {
 
 					deal_order.funding_transfer_id = Some(transfer_id.clone());
 					deal_order.timestamp = now;
+					Self::schedule_maturity(&deal_order_id, deal_order);
 
 					Ok(Some(Event::<T>::DealOrderFunded(deal_order_id.clone())))
 				},
@@ -904,6 +1367,7 @@ pub mod pallet {
 					ensure!(!transfer.is_processed, Error::<T>::TransferAlreadyProcessed);
 
 					transfer.is_processed = true;
+					Self::record_loan_taken(deal_order);
 					Ok(Some(Event::<T>::TransferProcessed(transfer_id.clone())))
 				},
 			)?;
@@ -1005,6 +1469,9 @@ pub mod pallet {
 				funding_transfer_id: None,
 				lock: None,
 				repayment_transfer_id: None,
+				repaid_amount: ExternalAmount::zero(),
+				repayments: Repayments::default(),
+				accrual: None,
 			};
 
 			AskOrders::<T>::insert_id(ask_order_id.clone(), ask_order.clone());
@@ -1021,6 +1488,72 @@ pub mod pallet {
 			Ok(())
 		}
 
+		#[pallet::weight(<T as Config>::WeightInfo::repay_installment())]
+		pub fn repay_installment(
+			origin: OriginFor<T>,
+			deal_order_id: DealOrderId<T::BlockNumber, T::Hash>,
+			transfer_id: TransferId<T::Hash>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let transfer = try_get!(Transfers<T>, &transfer_id, NonExistentTransfer)?;
+
+			Self::try_mutate_deal_order_and_transfer(
+				&deal_order_id,
+				&transfer_id,
+				|deal_order| {
+					let borrower = try_get!(
+						Addresses<T>,
+						&deal_order.borrower_address_id,
+						NonExistentAddress
+					)?;
+
+					ensure!(borrower.owner == who, Error::<T>::NotBorrower);
+
+					let now = Self::timestamp();
+					ensure!(now >= deal_order.timestamp, Error::<T>::MalformedDealOrder);
+
+					ensure!(deal_order.funding_transfer_id.is_some(), Error::<T>::DealNotFunded);
+					ensure!(
+						deal_order.repayment_transfer_id.is_none(),
+						Error::<T>::DealOrderAlreadyClosed
+					);
+
+					let repaid_at = transfer.timestamp.unwrap_or(now);
+					Self::record_installment(deal_order, &transfer_id, transfer.amount, repaid_at)?;
+					let outstanding = Self::deal_order_outstanding(deal_order, repaid_at)?;
+					if outstanding.is_zero() {
+						Self::clear_default(&deal_order_id);
+					}
+
+					Ok(Some(Event::<T>::InstallmentRepaid(
+						deal_order_id.clone(),
+						transfer_id.clone(),
+						outstanding,
+					)))
+				},
+				|transfer, deal_order| {
+					ensure!(
+						transfer.deal_order_id == deal_order_id.clone(),
+						Error::<T>::TransferDealOrderMismatch
+					);
+
+					ensure!(transfer.block <= Self::block_number(), Error::<T>::MalformedTransfer);
+					ensure!(transfer.account_id == who, Error::<T>::TransferAccountMismatch);
+					ensure!(!transfer.is_processed, Error::<T>::TransferAlreadyProcessed);
+					ensure!(
+						transfer.to == deal_order.lender_address_id,
+						Error::<T>::TransferRecipientMismatch
+					);
+
+					transfer.is_processed = true;
+					Ok(Some(Event::<T>::TransferProcessed(transfer_id.clone())))
+				},
+			)?;
+
+			Ok(())
+		}
+
 		#[pallet::weight(<T as Config>::WeightInfo::close_deal_order())]
 		pub fn close_deal_order(
 			origin: OriginFor<T>,
@@ -1029,6 +1562,8 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
+			let transfer = try_get!(Transfers<T>, &transfer_id, NonExistentTransfer)?;
+
 			Self::try_mutate_deal_order_and_transfer(
 				&deal_order_id,
 				&transfer_id,
@@ -1051,11 +1586,17 @@ pub mod pallet {
 
 					ensure!(deal_order.lock.is_some(), Error::<T>::DealOrderMustBeLocked);
 
+					let repaid_at = transfer.timestamp.unwrap_or(now);
+					let outstanding = Self::deal_order_outstanding(deal_order, repaid_at)?;
+					ensure!(transfer.amount >= outstanding, Error::<T>::TransferAmountInsufficient);
+
+					Self::record_installment(deal_order, &transfer_id, transfer.amount, repaid_at)?;
 					deal_order.repayment_transfer_id = Some(transfer_id.clone());
+					Self::clear_default(&deal_order_id);
 
 					Ok(Some(Event::<T>::DealOrderClosed(deal_order_id.clone())))
 				},
-				|transfer, _deal_order| {
+				|transfer, deal_order| {
 					ensure!(
 						transfer.deal_order_id == deal_order_id.clone(),
 						Error::<T>::TransferDealOrderMismatch
@@ -1064,8 +1605,14 @@ pub mod pallet {
 					ensure!(transfer.block <= Self::block_number(), Error::<T>::MalformedTransfer);
 					ensure!(transfer.account_id == who, Error::<T>::TransferAccountMismatch);
 					ensure!(!transfer.is_processed, Error::<T>::TransferAlreadyProcessed);
+					ensure!(
+						transfer.to == deal_order.lender_address_id,
+						Error::<T>::TransferRecipientMismatch
+					);
 
+					let repaid_at = transfer.timestamp.unwrap_or_else(Self::timestamp);
 					transfer.is_processed = true;
+					Self::record_repayment(deal_order, repaid_at);
 					Ok(Some(Event::<T>::TransferProcessed(transfer_id.clone())))
 				},
 			)?;
@@ -1073,6 +1620,79 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Hands a funded deal order over to a new lender. `new_lender_signature` must be the new
+		/// lender's signature over the deal order id, the current and new lender addresses and
+		/// the number of earlier assignments of the deal order.
+		#[pallet::weight(<T as Config>::WeightInfo::assign_deal_order())]
+		pub fn assign_deal_order(
+			origin: OriginFor<T>,
+			deal_order_id: DealOrderId<T::BlockNumber, T::Hash>,
+			new_lender_address_id: AddressId<T::Hash>,
+			new_lender_key: T::Signer,
+			new_lender_signature: T::SignerSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let new_lender_account = new_lender_key.into_account();
+
+			DealOrders::<T>::try_mutate(
+				deal_order_id.expiration(),
+				deal_order_id.hash(),
+				|value| -> DispatchResult {
+					let deal_order = value.as_mut().ok_or(Error::<T>::NonExistentDealOrder)?;
+
+					let lender = Self::get_address(&deal_order.lender_address_id)?;
+					ensure!(lender.owner == who, Error::<T>::NotLender);
+
+					ensure!(deal_order.funding_transfer_id.is_some(), Error::<T>::DealNotFunded);
+					ensure!(
+						deal_order.repayment_transfer_id.is_none(),
+						Error::<T>::DealOrderAlreadyClosed
+					);
+					ensure!(deal_order.lock.is_none(), Error::<T>::DealOrderLocked);
+
+					let assignments = DealOrderAssignments::<T>::get(
+						deal_order_id.expiration(),
+						deal_order_id.hash(),
+					);
+					let message = deal_order_id
+						.encode()
+						.into_iter()
+						.chain(deal_order.lender_address_id.encode())
+						.chain(new_lender_address_id.encode())
+						.chain(assignments.encode())
+						.collect::<Vec<u8>>();
+
+					ensure!(
+						new_lender_signature.verify(message.as_slice(), &new_lender_account),
+						Error::<T>::InvalidSignature
+					);
+
+					let new_lender = Self::get_address(&new_lender_address_id)?;
+					ensure!(new_lender.owner == new_lender_account, Error::<T>::NotAddressOwner);
+					ensure!(
+						new_lender.matches_chain_of(&lender),
+						Error::<T>::AddressBlockchainMismatch
+					);
+					ensure!(new_lender_account != deal_order.borrower, Error::<T>::SameOwner);
+
+					deal_order.lender_address_id = new_lender_address_id.clone();
+					DealOrderAssignments::<T>::insert(
+						deal_order_id.expiration(),
+						deal_order_id.hash(),
+						assignments.saturating_add(1),
+					);
+
+					Ok(())
+				},
+			)?;
+
+			Self::deposit_event(Event::<T>::DealOrderAssigned(
+				deal_order_id,
+				new_lender_address_id,
+			));
+			Ok(())
+		}
+
 		#[transactional]
 		#[pallet::weight(<T as Config>::WeightInfo::request_collect_coins())]
 		pub fn request_collect_coins(
@@ -1105,6 +1725,8 @@ pub mod pallet {
 			ensure!(address.owner == who, Error::<T>::NotAddressOwner);
 
 			T::TaskScheduler::insert(&deadline, &collect_coins_id, Task::from(pending.clone()));
+			Self::track_task(deadline, CollectedCoinsId::from(collect_coins_id).into());
+			Self::charge_task_fee(&who, CollectedCoinsId::from(collect_coins_id).into())?;
 
 			Self::deposit_event(Event::<T>::CollectCoinsRegistered(
 				collect_coins_id.into(),
@@ -1137,6 +1759,7 @@ pub mod pallet {
 				deal_order_id,
 				blockchain_tx_id,
 			)?;
+			Self::charge_task_fee(&transfer.account_id, transfer_id.clone().into())?;
 			Self::deposit_event(Event::<T>::TransferRegistered(transfer_id, transfer));
 
 			Ok(())
@@ -1166,11 +1789,17 @@ pub mod pallet {
 				deal_order_id,
 				blockchain_tx_id,
 			)?;
+			Self::charge_task_fee(&transfer.account_id, transfer_id.clone().into())?;
 			Self::deposit_event(Event::<T>::TransferRegistered(transfer_id, transfer));
 
 			Ok(())
 		}
 
+		/// Registers the lender's transfer of the loan amount to the borrower, to be verified by the
+		/// authorities. The transfer must be bound to the deal order so that it cannot be registered
+		/// against another one. Bitcoin transactions have no calldata, so a Bitcoin transfer must
+		/// carry the hash of the deal order in an `OP_RETURN` output; it must also only spend
+		/// outputs paying the sender's registered address.
 		#[transactional]
 		#[pallet::weight(<T as Config>::WeightInfo::register_funding_transfer())]
 		pub fn register_funding_transfer(
@@ -1193,11 +1822,15 @@ pub mod pallet {
 				blockchain_tx_id,
 				&order.terms.currency,
 			)?;
+			Self::charge_task_fee(&transfer.account_id, transfer_id.clone().into())?;
 			Self::deposit_event(Event::<T>::TransferRegistered(transfer_id, transfer));
 
 			Ok(())
 		}
 
+		/// Registers the borrower's repayment of `repayment_amount` to the lender, to be verified by
+		/// the authorities. The transfer must meet the same requirements as in
+		/// [`Pallet::register_funding_transfer`].
 		#[transactional]
 		#[pallet::weight(<T as Config>::WeightInfo::register_repayment_transfer())]
 		pub fn register_repayment_transfer(
@@ -1221,6 +1854,7 @@ pub mod pallet {
 				blockchain_tx_id,
 				&order.terms.currency,
 			)?;
+			Self::charge_task_fee(&transfer.account_id, transfer_id.clone().into())?;
 			Self::deposit_event(Event::<T>::TransferRegistered(transfer_id, transfer));
 
 			Ok(())
@@ -1266,6 +1900,8 @@ pub mod pallet {
 						TransferId::new::<T>(&fake_transfer.blockchain, &fake_transfer.tx_id);
 
 					deal_order.repayment_transfer_id = Some(fake_transfer_id);
+					Self::record_exemption(&deal_order.borrower);
+					Self::clear_default(&deal_order_id);
 
 					Ok(())
 				},
@@ -1288,6 +1924,14 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			ensure!(T::TaskScheduler::is_authority(&who), Error::<T>::InsufficientAuthority);
+			Self::ensure_bonded(&who)?;
+
+			let vote = T::Hashing::hash_of(&task_output);
+			let task_id = TaskId::from(&task_output);
+			let authorities = match Self::vote_on_task_output(&who, deadline, &task_id, vote)? {
+				Some(authorities) => authorities,
+				None => return Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No }),
+			};
 
 			let (task_id, event) = match task_output {
 				TaskOutput::VerifyTransfer(id, transfer) => {
@@ -1324,9 +1968,12 @@ pub mod pallet {
 				},
 			};
 
-			PendingTasks::<T>::remove(deadline, task_id);
+			PendingTasks::<T>::remove(deadline, &task_id);
+			T::TaskScheduler::complete(&deadline, task_id.as_ref());
+			Self::finish_task(&task_id, TaskStatus::Completed);
 
 			Self::deposit_event(event);
+			Self::reward_authorities(deadline, &task_id, &authorities);
 
 			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
 		}
@@ -1344,6 +1991,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 
 			ensure!(T::TaskScheduler::is_authority(&who), Error::<T>::InsufficientAuthority);
+			Self::ensure_bonded(&who)?;
 
 			let event = match &task_id {
 				TaskId::VerifyTransfer(transfer_id) => {
@@ -1361,8 +2009,17 @@ pub mod pallet {
 					Event::<T>::CollectCoinsFailedVerification(collected_coins_id.clone(), cause)
 				},
 			};
+
+			let vote = T::Hashing::hash_of(&(&task_id, cause));
+			if Self::vote_on_task_output(&who, deadline, &task_id, vote)?.is_none() {
+				return Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No });
+			}
+
 			PendingTasks::<T>::remove(deadline, &task_id);
+			T::TaskScheduler::complete(&deadline, task_id.as_ref());
+			Self::finish_task(&task_id, TaskStatus::Failed(cause));
 			Self::deposit_event(event);
+			Self::refund_task_fee(deadline, &task_id);
 
 			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
 		}
@@ -1376,6 +2033,14 @@ pub mod pallet {
 
 			ensure!(!T::TaskScheduler::is_authority(&who), Error::<T>::AlreadyAuthority);
 
+			let bond = Self::authority_bond();
+			if !bond.is_zero() {
+				<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(
+					&who, bond,
+				)?;
+				AuthorityBonds::<T>::insert(&who, bond);
+			}
+
 			T::TaskScheduler::insert_authority(&who);
 
 			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
@@ -1415,10 +2080,245 @@ pub mod pallet {
 			ensure_root(origin)?;
 
 			ensure!(T::TaskScheduler::is_authority(&who), Error::<T>::NotAnAuthority);
+			Self::ensure_quorum_after_removal()?;
+
+			if let Some(bond) = AuthorityBonds::<T>::take(&who) {
+				<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::unreserve(
+					&who, bond,
+				);
+			}
 
 			T::TaskScheduler::remove_authority(&who);
 
 			Ok(PostDispatchInfo { actual_weight: None, pays_fee: Pays::No })
 		}
+
+		/// Enables or disables on-chain matching of new ask and bid orders. While enabled,
+		/// a compatible ask and bid are turned into an offer without the lender calling `add_offer`.
+		#[pallet::weight(<T as Config>::WeightInfo::set_order_matching())]
+		pub fn set_order_matching(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			ensure_root(origin)?;
+
+			OrderMatching::<T>::put(enabled);
+			Self::deposit_event(Event::<T>::OrderMatchingSet(enabled));
+
+			Ok(())
+		}
+
+		/// Replaces the term length and interest rate of a funded deal order. The caller must be
+		/// the lender or the borrower, and `counterparty_signature` must be the other party's
+		/// signature over the deal order id, the number of earlier renegotiations and the new
+		/// terms. The previous terms are kept in `DealOrderAmendments`, and interest accrued
+		/// before the renegotiation is charged at the previous rate. Defaulted deal orders can't be
+		/// renegotiated.
+		#[pallet::weight(<T as Config>::WeightInfo::renegotiate_deal_order())]
+		pub fn renegotiate_deal_order(
+			origin: OriginFor<T>,
+			deal_order_id: DealOrderId<T::BlockNumber, T::Hash>,
+			term_length: Duration,
+			interest_rate: InterestRate,
+			counterparty_key: T::Signer,
+			counterparty_signature: T::SignerSignature,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let counterparty_account = counterparty_key.into_account();
+
+			ensure!(!term_length.is_zero(), Error::<T>::InvalidTermLength);
+
+			let mut deal_order = try_get_id!(DealOrders<T>, &deal_order_id, NonExistentDealOrder)?;
+			let lender = Self::get_address(&deal_order.lender_address_id)?;
+
+			let counterparty = if who == lender.owner {
+				deal_order.borrower.clone()
+			} else if who == deal_order.borrower {
+				lender.owner
+			} else {
+				return Err(Error::<T>::NotDealParticipant.into());
+			};
+
+			ensure!(deal_order.funding_transfer_id.is_some(), Error::<T>::DealNotFunded);
+			ensure!(deal_order.repayment_transfer_id.is_none(), Error::<T>::DealOrderAlreadyClosed);
+			ensure!(deal_order.lock.is_none(), Error::<T>::DealOrderLocked);
+			ensure!(
+				!DefaultedDealOrders::<T>::contains_key(
+					deal_order_id.expiration(),
+					deal_order_id.hash()
+				),
+				Error::<T>::DealOrderDefaulted
+			);
+			ensure!(term_length >= deal_order.terms.term_length, Error::<T>::TermLengthShortened);
+
+			let mut amendments =
+				DealOrderAmendments::<T>::get(deal_order_id.expiration(), deal_order_id.hash());
+
+			let message = deal_order_id
+				.encode()
+				.into_iter()
+				.chain((amendments.len() as u32).encode())
+				.chain(term_length.encode())
+				.chain(interest_rate.encode())
+				.collect::<Vec<u8>>();
+
+			ensure!(
+				counterparty_signature.verify(message.as_slice(), &counterparty_account),
+				Error::<T>::InvalidSignature
+			);
+			ensure!(counterparty_account == counterparty, Error::<T>::NotDealParticipant);
+
+			amendments
+				.try_push(DealOrderAmendment {
+					term_length: deal_order.terms.term_length,
+					interest_rate: deal_order.terms.interest_rate.clone(),
+					block: Self::block_number(),
+					timestamp: Self::timestamp(),
+				})
+				.map_err(|_| Error::<T>::TooManyAmendments)?;
+
+			// interest owed until now stays at the previous rate, the new rate applies from now on
+			let now = Self::timestamp();
+			let accrual = Self::deal_order_accrual(&deal_order, now)?;
+			deal_order.accrual = Some(Accrual { since: now, periods: 0, ..accrual });
+
+			let previous_maturity = Self::deal_order_maturity(&deal_order);
+			deal_order.terms.term_length = term_length;
+			deal_order.terms.interest_rate = interest_rate;
+			Self::reschedule_maturity(&deal_order_id, previous_maturity, &deal_order);
+
+			DealOrderAmendments::<T>::insert_id(deal_order_id.clone(), amendments);
+			Self::deposit_event(Event::<T>::DealOrderRenegotiated(
+				deal_order_id.clone(),
+				deal_order.terms.clone(),
+			));
+			DealOrders::<T>::insert_id(deal_order_id, deal_order);
+
+			Ok(())
+		}
+
+		/// Sets how deep transactions on `blockchain` must be before the offchain worker verifies
+		/// them. `None` goes back to the default number of confirmations.
+		#[pallet::weight(<T as Config>::WeightInfo::set_confirmation_policy())]
+		pub fn set_confirmation_policy(
+			origin: OriginFor<T>,
+			blockchain: Blockchain,
+			policy: Option<ConfirmationPolicy>,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			if let Some(policy) = &policy {
+				ensure!(
+					policy.is_supported_by(&blockchain),
+					Error::<T>::UnsupportedConfirmationPolicy
+				);
+			}
+
+			ConfirmationPolicies::<T>::set(&blockchain, policy);
+			Self::deposit_event(Event::<T>::ConfirmationPolicySet(blockchain, policy));
+
+			Ok(())
+		}
+
+		/// Sets how many authorities must submit the same output, or the same failure, for a task
+		/// before it is acted on. A threshold below 2 acts on the first one any authority submits.
+		/// The threshold cannot exceed the number of authorities.
+		#[pallet::weight(<T as Config>::WeightInfo::set_quorum_threshold())]
+		pub fn set_quorum_threshold(origin: OriginFor<T>, threshold: u32) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(
+				threshold <= T::TaskScheduler::authority_count(),
+				Error::<T>::QuorumThresholdTooHigh
+			);
+
+			QuorumThreshold::<T>::put(threshold);
+			Self::deposit_event(Event::<T>::QuorumThresholdSet(threshold));
+
+			Ok(())
+		}
+
+		/// Sets the amount accounts must reserve to be added as authorities. Authorities which
+		/// are already bonded keep their current bond.
+		#[pallet::weight(<T as Config>::WeightInfo::set_authority_bond())]
+		pub fn set_authority_bond(origin: OriginFor<T>, bond: T::Balance) -> DispatchResult {
+			ensure_root(origin)?;
+
+			AuthorityBond::<T>::put(bond);
+			Self::deposit_event(Event::<T>::AuthorityBondSet(bond));
+
+			Ok(())
+		}
+
+		/// Sets the fee accounts pay to register a task. The fee is split between the
+		/// authorities that complete the task, or returned if the task fails or expires.
+		#[pallet::weight(<T as Config>::WeightInfo::set_task_fee())]
+		pub fn set_task_fee(origin: OriginFor<T>, fee: T::Balance) -> DispatchResult {
+			ensure_root(origin)?;
+
+			TaskFee::<T>::put(fee);
+			Self::deposit_event(Event::<T>::TaskFeeSet(fee));
+
+			Ok(())
+		}
+
+		/// Removes an authority which voted `vote` on the task `task_id` due at `deadline`, and
+		/// burns its whole bond. The vote must either have been contradicted by a quorum of other
+		/// authorities or, without a quorum threshold of at least 2, have been acted on by itself.
+		/// Votes are only kept for `TaskStatusRetention` blocks past the task's deadline.
+		#[pallet::weight(<T as Config>::WeightInfo::slash_authority())]
+		pub fn slash_authority(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			deadline: T::BlockNumber,
+			task_id: TaskId<T::Hash>,
+			vote: T::Hash,
+		) -> DispatchResult {
+			ensure_root(origin)?;
+
+			ensure!(T::TaskScheduler::is_authority(&who), Error::<T>::NotAnAuthority);
+			ensure!(
+				TaskOutputVotes::<T>::get((deadline, &task_id, &who)) == Some(vote),
+				Error::<T>::VoteNotRecorded
+			);
+			match Self::task_outcomes(deadline, &task_id) {
+				Some(outcome) => ensure!(outcome != vote, Error::<T>::VoteNotContradicted),
+				None => ensure!(Self::quorum_threshold() < 2, Error::<T>::VoteNotContradicted),
+			}
+			Self::ensure_quorum_after_removal()?;
+
+			let bond = AuthorityBonds::<T>::take(&who).unwrap_or_default();
+			let (_, unslashed) =
+				<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::slash_reserved(
+					&who, bond,
+				);
+			T::TaskScheduler::remove_authority(&who);
+
+			Self::deposit_event(Event::<T>::AuthoritySlashed(
+				who,
+				task_id,
+				bond.saturating_sub(unslashed),
+			));
+
+			Ok(())
+		}
+
+		/// Reserves the authority bond from an authority which was added without one, such as the
+		/// authorities added at genesis or before a bond was set. Until they do, authorities
+		/// without a bond cannot submit task outputs. Does nothing while the bond is zero.
+		#[pallet::weight(<T as Config>::WeightInfo::bond_authority())]
+		pub fn bond_authority(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(T::TaskScheduler::is_authority(&who), Error::<T>::NotAnAuthority);
+			ensure!(!AuthorityBonds::<T>::contains_key(&who), Error::<T>::AuthorityAlreadyBonded);
+
+			let bond = Self::authority_bond();
+			if bond.is_zero() {
+				return Ok(());
+			}
+			<pallet_balances::Pallet<T> as ReservableCurrency<T::AccountId>>::reserve(&who, bond)?;
+			AuthorityBonds::<T>::insert(&who, bond);
+			Self::deposit_event(Event::<T>::AuthorityBonded(who, bond));
+
+			Ok(())
+		}
 	}
 }
@@ -2,12 +2,16 @@ use crate::{Config, Pallet};
 use frame_support::{traits::StorageVersion, weights::Weight};
 
 mod v1;
+mod v10;
+mod v11;
 mod v2;
 mod v3;
 mod v4;
 mod v5;
 mod v6;
 mod v7;
+mod v8;
+mod v9;
 
 pub(crate) fn migrate<T: Config>() -> Weight {
 	let version = StorageVersion::get::<Pallet<T>>();
@@ -41,6 +45,22 @@ pub(crate) fn migrate<T: Config>() -> Weight {
 		weight.saturating_accrue(v7::migrate::<T>());
 		StorageVersion::new(7).put::<Pallet<T>>();
 	}
+	if version < 8 {
+		weight.saturating_accrue(v8::migrate::<T>());
+		StorageVersion::new(8).put::<Pallet<T>>();
+	}
+	if version < 9 {
+		weight.saturating_accrue(v9::migrate::<T>());
+		StorageVersion::new(9).put::<Pallet<T>>();
+	}
+	if version < 10 {
+		weight.saturating_accrue(v10::migrate::<T>());
+		StorageVersion::new(10).put::<Pallet<T>>();
+	}
+	if version < 11 {
+		weight.saturating_accrue(v11::migrate::<T>());
+		StorageVersion::new(11).put::<Pallet<T>>();
+	}
 
 	weight
 }
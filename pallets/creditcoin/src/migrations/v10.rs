@@ -0,0 +1,118 @@
+// ask and bid orders now carry ranges of acceptable terms
+use super::{v6, AccountIdOf, BlockNumberOf, HashOf};
+use crate::{AskOrder, AskTerms, BidOrder, BidTerms, Config};
+use frame_support::pallet_prelude::*;
+
+use v6::AskOrder as OldAskOrder;
+use v6::BidOrder as OldBidOrder;
+
+pub(crate) fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+	let weight_each = T::DbWeight::get().reads_writes(1, 1);
+
+	crate::AskOrders::<T>::translate::<OldAskOrder<AccountIdOf<T>, BlockNumberOf<T>, HashOf<T>>, _>(
+		|_exp, _hash, ask_order| {
+			weight = weight.saturating_add(weight_each);
+			Some(AskOrder {
+				lender_address_id: ask_order.lender_address_id,
+				terms: AskTerms::try_from(ask_order.terms.0).expect(
+					"terms are checked for validity on creation so they must be valid on an existing ask order; qed",
+				),
+				expiration_block: ask_order.expiration_block,
+				block: ask_order.block,
+				lender: ask_order.lender,
+			})
+		},
+	);
+
+	crate::BidOrders::<T>::translate::<OldBidOrder<AccountIdOf<T>, BlockNumberOf<T>, HashOf<T>>, _>(
+		|_exp, _hash, bid_order| {
+			weight = weight.saturating_add(weight_each);
+			Some(BidOrder {
+				borrower_address_id: bid_order.borrower_address_id,
+				terms: BidTerms::try_from(bid_order.terms.0).expect(
+					"terms are checked for validity on creation so they must be valid on an existing bid order; qed",
+				),
+				expiration_block: bid_order.expiration_block,
+				block: bid_order.block,
+				borrower: bid_order.borrower,
+			})
+		},
+	);
+
+	weight
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{AccountId, BlockNumber, ExtBuilder, Hash, Test},
+		tests::TestInfo,
+		AskOrderId, BidOrderId, LoanTermRanges,
+	};
+	use frame_support::{storage_alias, Identity, Twox64Concat};
+
+	#[storage_alias]
+	type AskOrders<T: Config> = StorageDoubleMap<
+		crate::Pallet<T>,
+		Twox64Concat,
+		BlockNumberOf<T>,
+		Identity,
+		HashOf<T>,
+		OldAskOrder<AccountIdOf<T>, BlockNumberOf<T>, HashOf<T>>,
+	>;
+
+	#[storage_alias]
+	type BidOrders<T: Config> = StorageDoubleMap<
+		crate::Pallet<T>,
+		Twox64Concat,
+		BlockNumberOf<T>,
+		Identity,
+		HashOf<T>,
+		OldBidOrder<AccountIdOf<T>, BlockNumberOf<T>, HashOf<T>>,
+	>;
+
+	type OldAskOrders = AskOrders<Test>;
+	type OldBidOrders = BidOrders<Test>;
+
+	#[test]
+	fn ask_and_bid_orders_migrate_to_exact_ranges() {
+		ExtBuilder::default().build_and_execute(|| {
+			let test_info = TestInfo::new_defaults();
+			let expiration_block = 1_000;
+
+			let ask_id = AskOrderId::<BlockNumber, Hash>::new::<Test>(expiration_block, b"asdf");
+			let old_ask = OldAskOrder::<AccountId, BlockNumber, Hash> {
+				lender_address_id: test_info.lender.address_id.clone(),
+				terms: v6::AskTerms(test_info.loan_terms.clone()),
+				expiration_block,
+				block: 1,
+				lender: test_info.lender.account_id.clone(),
+			};
+			OldAskOrders::insert(ask_id.expiration(), ask_id.hash(), &old_ask);
+
+			let bid_id = BidOrderId::<BlockNumber, Hash>::new::<Test>(expiration_block, b"qwer");
+			let old_bid = OldBidOrder::<AccountId, BlockNumber, Hash> {
+				borrower_address_id: test_info.borrower.address_id.clone(),
+				terms: v6::BidTerms(test_info.loan_terms.clone()),
+				expiration_block,
+				block: 1,
+				borrower: test_info.borrower.account_id.clone(),
+			};
+			OldBidOrders::insert(bid_id.expiration(), bid_id.hash(), &old_bid);
+
+			migrate::<Test>();
+
+			let ask = crate::AskOrders::<Test>::get(ask_id.expiration(), ask_id.hash()).unwrap();
+			let bid = crate::BidOrders::<Test>::get(bid_id.expiration(), bid_id.hash()).unwrap();
+
+			let ranges = LoanTermRanges::from(test_info.loan_terms.clone());
+			assert_eq!(*ask.terms, ranges);
+			assert_eq!(*bid.terms, ranges);
+			assert_eq!(ask.lender, old_ask.lender);
+			assert_eq!(bid.borrower, old_bid.borrower);
+			assert_eq!(ask.terms.agreed_terms(bid.terms), Some(test_info.loan_terms));
+		});
+	}
+}
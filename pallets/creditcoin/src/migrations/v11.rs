@@ -0,0 +1,86 @@
+// credit records are built up from the existing deal orders
+use crate::{Config, Pallet};
+use frame_support::pallet_prelude::*;
+
+pub(crate) fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+	let read = T::DbWeight::get().reads(1);
+	let record = T::DbWeight::get().reads_writes(1, 1);
+
+	for (expiration, hash, deal_order) in crate::DealOrders::<T>::iter() {
+		weight = weight.saturating_add(read);
+		if deal_order.funding_transfer_id.is_none() {
+			continue;
+		}
+
+		weight = weight.saturating_add(record).saturating_add(record);
+		Pallet::<T>::record_loan_taken(&deal_order);
+
+		if let Some(repayment_transfer_id) = &deal_order.repayment_transfer_id {
+			weight = weight.saturating_add(read).saturating_add(record);
+			// exempted deal orders reference a transfer that was never stored
+			match crate::Transfers::<T>::get(repayment_transfer_id) {
+				Some(transfer) => Pallet::<T>::record_repayment(
+					&deal_order,
+					transfer.timestamp.unwrap_or(deal_order.timestamp),
+				),
+				None => Pallet::<T>::record_exemption(&deal_order.borrower),
+			}
+		}
+
+		weight = weight.saturating_add(read);
+		if crate::DefaultedDealOrders::<T>::contains_key(expiration, hash) {
+			weight = weight.saturating_add(record);
+			Pallet::<T>::record_default(&deal_order.borrower);
+		}
+	}
+
+	weight
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{ExtBuilder, Test},
+		tests::TestInfo,
+		types::DoubleMapExt,
+		CreditRecord, TransferId,
+	};
+
+	#[test]
+	fn credit_records_are_built_from_deal_orders() {
+		ExtBuilder::default().build_and_execute(|| {
+			let test_info = TestInfo::new_defaults();
+			let (deal_order_id, deal_order) = test_info.create_deal_order();
+			let borrower = test_info.borrower.account_id.clone();
+
+			migrate::<Test>();
+			assert_eq!(crate::CreditRecords::<Test>::get(&borrower), CreditRecord::default());
+
+			let funding_transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+			let exempt_transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"0");
+			crate::DealOrders::<Test>::mutate(
+				deal_order_id.expiration(),
+				deal_order_id.hash(),
+				|deal_order| {
+					let deal_order = deal_order.as_mut().unwrap();
+					deal_order.funding_transfer_id = Some(funding_transfer_id);
+					deal_order.repayment_transfer_id = Some(exempt_transfer_id);
+				},
+			);
+			crate::DefaultedDealOrders::<Test>::insert_id(deal_order_id, 1);
+
+			migrate::<Test>();
+
+			assert_eq!(
+				crate::CreditRecords::<Test>::get(&borrower),
+				CreditRecord { loans_taken: 1, defaulted: 1, exempted: 1, ..Default::default() }
+			);
+			assert_eq!(
+				crate::BorrowedVolume::<Test>::get(&borrower, &deal_order.terms.currency),
+				deal_order.terms.amount
+			);
+		});
+	}
+}
@@ -32,16 +32,11 @@ pub use v5::UnverifiedTransfer as OldUnverifiedTransfer;
 
 use crate::Address;
 use crate::AddressId;
-use crate::AskOrder;
 use crate::AskOrderId;
-use crate::AskTerms;
-use crate::BidOrder;
 use crate::BidOrderId;
-use crate::BidTerms;
 use crate::Blockchain;
 use crate::Currency;
 use crate::CurrencyId;
-use crate::DealOrder;
 use crate::LegacyTransferKind;
 use crate::LoanTerms;
 use crate::Task;
@@ -52,6 +47,50 @@ use crate::TransferKind;
 use crate::UnverifiedCollectedCoins;
 use crate::UnverifiedTransfer;
 
+#[derive(Encode, Decode)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct DealOrder<AccountId, BlockNum, Hash, Moment> {
+	pub offer_id: crate::OfferId<BlockNum, Hash>,
+	pub lender_address_id: AddressId<Hash>,
+	pub borrower_address_id: AddressId<Hash>,
+	pub terms: LoanTerms<Hash>,
+	pub expiration_block: BlockNum,
+	pub timestamp: Moment,
+	pub block: Option<BlockNum>,
+	pub funding_transfer_id: Option<TransferId<Hash>>,
+	pub repayment_transfer_id: Option<TransferId<Hash>>,
+	pub lock: Option<AccountId>,
+	pub borrower: AccountId,
+}
+
+#[derive(Encode, Decode)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct AskTerms<Hash>(pub LoanTerms<Hash>);
+
+#[derive(Encode, Decode)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct BidTerms<Hash>(pub LoanTerms<Hash>);
+
+#[derive(Encode, Decode)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct AskOrder<AccountId, BlockNum, Hash> {
+	pub lender_address_id: AddressId<Hash>,
+	pub terms: AskTerms<Hash>,
+	pub expiration_block: BlockNum,
+	pub block: BlockNum,
+	pub lender: AccountId,
+}
+
+#[derive(Encode, Decode)]
+#[cfg_attr(test, derive(Debug, PartialEq, Eq))]
+pub struct BidOrder<AccountId, BlockNum, Hash> {
+	pub borrower_address_id: AddressId<Hash>,
+	pub terms: BidTerms<Hash>,
+	pub expiration_block: BlockNum,
+	pub block: BlockNum,
+	pub borrower: AccountId,
+}
+
 fn translate_blockchain(old: OldBlockchain) -> Option<Blockchain> {
 	match old {
 		OldBlockchain::Ethereum => Some(Blockchain::ETHEREUM),
@@ -296,10 +335,7 @@ pub(crate) fn migrate<T: Config>() -> Weight {
 				.unwrap_or_else(CurrencyId::placeholder);
 			Some(AskOrder {
 				lender_address_id: ask_order.lender_address_id,
-				terms: AskTerms::try_from(translate_loan_terms::<T>(
-					ask_order.terms.0,
-					currency,
-				)).expect("terms are checked for validity on creation so they must be valid on an existing ask order; qed"),
+				terms: AskTerms(translate_loan_terms::<T>(ask_order.terms.0, currency)),
 				expiration_block: ask_order.expiration_block,
 				block: ask_order.block,
 				lender: ask_order.lender,
@@ -316,7 +352,7 @@ pub(crate) fn migrate<T: Config>() -> Weight {
 				.unwrap_or_else(CurrencyId::placeholder);
 			Some(BidOrder {
 				borrower_address_id: bid_order.borrower_address_id,
-				terms: BidTerms::try_from(translate_loan_terms::<T>(bid_order.terms.0, currency)).expect("terms are checked on creation so they must be valid on existing bid order; qed"),
+				terms: BidTerms(translate_loan_terms::<T>(bid_order.terms.0, currency)),
 				expiration_block: bid_order.expiration_block,
 				block: bid_order.block,
 				borrower: bid_order.borrower,
@@ -618,7 +654,7 @@ mod tests {
 	) -> super::AskOrder<AccountId, BlockNumber, Hash> {
 		super::AskOrder {
 			lender_address_id: ask.lender_address_id,
-			terms: crate::AskTerms::try_from(old_to_new_terms(ask.terms.0, currency)).unwrap(),
+			terms: super::AskTerms(old_to_new_terms(ask.terms.0, currency)),
 			expiration_block: ask.expiration_block,
 			block: ask.block,
 			lender: ask.lender,
@@ -631,7 +667,7 @@ mod tests {
 	) -> super::BidOrder<AccountId, BlockNumber, Hash> {
 		super::BidOrder {
 			borrower_address_id: bid.borrower_address_id,
-			terms: crate::BidTerms::try_from(old_to_new_terms(bid.terms.0, currency)).unwrap(),
+			terms: super::BidTerms(old_to_new_terms(bid.terms.0, currency)),
 			expiration_block: bid.expiration_block,
 			block: bid.block,
 			borrower: bid.borrower,
@@ -0,0 +1,110 @@
+// deal orders now track installment repayments
+use super::{v6, AccountIdOf, BlockNumberOf, HashOf, MomentOf};
+use crate::{Config, DealOrder, ExternalAmount, Repayments};
+use frame_support::pallet_prelude::*;
+
+use v6::DealOrder as OldDealOrder;
+
+pub(crate) fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+	let weight_each = T::DbWeight::get().reads_writes(1, 1);
+
+	crate::DealOrders::<T>::translate::<
+		OldDealOrder<AccountIdOf<T>, BlockNumberOf<T>, HashOf<T>, MomentOf<T>>,
+		_,
+	>(|_exp, _hash, deal_order| {
+		weight = weight.saturating_add(weight_each);
+		Some(DealOrder {
+			offer_id: deal_order.offer_id,
+			lender_address_id: deal_order.lender_address_id,
+			borrower_address_id: deal_order.borrower_address_id,
+			terms: deal_order.terms,
+			expiration_block: deal_order.expiration_block,
+			timestamp: deal_order.timestamp,
+			block: deal_order.block,
+			funding_transfer_id: deal_order.funding_transfer_id,
+			repayment_transfer_id: deal_order.repayment_transfer_id,
+			lock: deal_order.lock,
+			borrower: deal_order.borrower,
+			repaid_amount: ExternalAmount::zero(),
+			repayments: Repayments::default(),
+			accrual: None,
+		})
+	});
+
+	weight
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{AccountId, BlockNumber, ExtBuilder, Hash, Moment, Test},
+		tests::TestInfo,
+		DealOrderId,
+	};
+	use frame_support::{storage_alias, Identity, Twox64Concat};
+
+	#[storage_alias]
+	type DealOrders<T: Config> = StorageDoubleMap<
+		crate::Pallet<T>,
+		Twox64Concat,
+		BlockNumberOf<T>,
+		Identity,
+		HashOf<T>,
+		OldDealOrder<AccountIdOf<T>, BlockNumberOf<T>, HashOf<T>, MomentOf<T>>,
+	>;
+
+	type OldDealOrders = DealOrders<Test>;
+
+	#[test]
+	fn deal_order_migrates() {
+		ExtBuilder::default().build_and_execute(|| {
+			let test_info = TestInfo::new_defaults();
+			let (offer_id, _) = test_info.create_offer();
+			let expiration_block = 1_000;
+			let deal_id =
+				DealOrderId::<BlockNumber, Hash>::new::<Test>(expiration_block, &offer_id);
+
+			let old_deal = OldDealOrder::<AccountId, BlockNumber, Hash, Moment> {
+				offer_id,
+				lender_address_id: test_info.lender.address_id.clone(),
+				borrower_address_id: test_info.borrower.address_id.clone(),
+				terms: test_info.loan_terms.clone(),
+				expiration_block,
+				timestamp: 100_000,
+				block: Some(100),
+				funding_transfer_id: None,
+				repayment_transfer_id: None,
+				lock: None,
+				borrower: test_info.borrower.account_id.clone(),
+			};
+			OldDealOrders::insert(deal_id.expiration(), deal_id.hash(), &old_deal);
+
+			migrate::<Test>();
+
+			let migrated_deal =
+				crate::DealOrders::<Test>::get(deal_id.expiration(), deal_id.hash()).unwrap();
+
+			assert_eq!(
+				migrated_deal,
+				DealOrder {
+					offer_id: old_deal.offer_id,
+					lender_address_id: old_deal.lender_address_id,
+					borrower_address_id: old_deal.borrower_address_id,
+					terms: old_deal.terms,
+					expiration_block: old_deal.expiration_block,
+					timestamp: old_deal.timestamp,
+					block: old_deal.block,
+					funding_transfer_id: old_deal.funding_transfer_id,
+					repayment_transfer_id: old_deal.repayment_transfer_id,
+					lock: old_deal.lock,
+					borrower: old_deal.borrower,
+					repaid_amount: ExternalAmount::zero(),
+					repayments: Repayments::default(),
+					accrual: None,
+				}
+			);
+		});
+	}
+}
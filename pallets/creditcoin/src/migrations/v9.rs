@@ -0,0 +1,61 @@
+// open funded deal orders are now watched for defaults
+use crate::{Config, DealOrderId, Pallet};
+use frame_support::pallet_prelude::*;
+
+pub(crate) fn migrate<T: Config>() -> Weight {
+	let mut weight: Weight = Weight::zero();
+	let read = T::DbWeight::get().reads(1);
+	let schedule = T::DbWeight::get().reads_writes(1, 2);
+
+	for (expiration, hash, deal_order) in crate::DealOrders::<T>::iter() {
+		weight = weight.saturating_add(read);
+		if deal_order.funding_transfer_id.is_none() || deal_order.repayment_transfer_id.is_some() {
+			continue;
+		}
+
+		weight = weight.saturating_add(schedule);
+		let deal_order_id = DealOrderId::with_expiration_hash::<T>(expiration, hash);
+		Pallet::<T>::schedule_maturity(&deal_order_id, &deal_order);
+	}
+
+	weight
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{ExtBuilder, Test},
+		tests::TestInfo,
+		types::DoubleMapExt,
+		TransferId,
+	};
+
+	#[test]
+	fn funded_deal_orders_are_scheduled() {
+		ExtBuilder::default().build_and_execute(|| {
+			let test_info = TestInfo::new_defaults();
+			let (deal_order_id, _) = test_info.create_deal_order();
+
+			migrate::<Test>();
+			assert!(crate::DealOrderMaturities::<Test>::iter().next().is_none());
+
+			let funding_transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+			crate::DealOrders::<Test>::mutate(
+				deal_order_id.expiration(),
+				deal_order_id.hash(),
+				|deal_order| {
+					deal_order.as_mut().unwrap().funding_transfer_id = Some(funding_transfer_id);
+				},
+			);
+
+			migrate::<Test>();
+
+			let deal_order = crate::DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+			let bucket = Pallet::<Test>::deal_order_maturity(&deal_order)
+				/ crate::helpers::MATURITY_BUCKET_MILLIS;
+			assert!(crate::DealOrderMaturities::<Test>::contains_key(bucket, &deal_order_id));
+			assert_eq!(crate::NextMaturityBucket::<Test>::get(), Some(bucket));
+		});
+	}
+}
@@ -7,7 +7,7 @@ use ethereum_types::U256;
 use frame_support::{
 	once_cell::sync::Lazy,
 	parameter_types,
-	traits::{ConstU32, ConstU64, GenesisBuild, Hooks},
+	traits::{ConstU32, ConstU64, GenesisBuild, Get, Hooks},
 };
 use frame_system as system;
 use pallet_offchain_task_scheduler::crypto::AuthorityId;
@@ -111,14 +111,33 @@ impl pallet_creditcoin::Config for Test {
 
 	type UnverifiedTaskTimeout = ConstU64<5>;
 
+	type RewardEraLength = ConstU64<10>;
+	type RewardEraRetention = ConstU64<2>;
+	type TaskStatusRetention = ConstU64<10>;
+
 	type WeightInfo = super::weights::WeightInfo<Test>;
 
 	type TaskScheduler = Self;
 }
 
+pub struct QuorumThreshold;
+
+impl Get<u32> for QuorumThreshold {
+	fn get() -> u32 {
+		Creditcoin::quorum_threshold()
+	}
+}
+
 impl pallet_offchain_task_scheduler::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type UnverifiedTaskTimeout = ConstU64<5>;
+	type MaxTasksPerRun = ConstU32<100>;
+	type MaxRunDuration = ConstU64<30_000>;
+	type AuthoritiesPerTask = ConstU32<1>;
+	type QuorumThreshold = QuorumThreshold;
+	type ShardingFallback = ConstU64<2>;
+	type MaxTaskAttempts = ConstU32<3>;
+	type RetryBackoff = ConstU64<1>;
 	type AuthorityId = AuthorityId;
 	type TaskCall = RuntimeCall;
 	type WeightInfo = pallet_offchain_task_scheduler::weights::WeightInfo<Self>;
@@ -369,10 +388,19 @@ pub fn pending_rpc_request(
 	params: impl IntoIterator<Item = serde_json::Value>,
 	uri: &str,
 	responses: &HashMap<String, JsonRpcResponse<serde_json::Value>>,
+) -> PendingRequest {
+	pending_rpc_request_with_response(method, params, uri, &responses[method])
+}
+
+/// Like [`pending_rpc_request`], for a `response` that is not keyed by the method name.
+pub fn pending_rpc_request_with_response(
+	method: &str,
+	params: impl IntoIterator<Item = serde_json::Value>,
+	uri: &str,
+	response: &JsonRpcResponse<serde_json::Value>,
 ) -> PendingRequest {
 	let x = JsonRpcRequest::new(method, params);
 	let rpc = x.to_bytes();
-	let response = &responses[method];
 	let response_body = serde_json::to_vec(response).unwrap();
 	PendingRequest {
 		method: "POST".into(),
@@ -389,6 +417,42 @@ pub fn pending_rpc_request(
 pub(crate) static ETHLESS_RESPONSES: Lazy<HashMap<String, JsonRpcResponse<serde_json::Value>>> =
 	Lazy::new(|| serde_json::from_slice(include_bytes!("tests/ethlessTransfer.json")).unwrap());
 
+pub(crate) static BITCOIN_RESPONSES: Lazy<HashMap<String, JsonRpcResponse<serde_json::Value>>> =
+	Lazy::new(|| serde_json::from_slice(include_bytes!("tests/bitcoinTransfer.json")).unwrap());
+
+pub(crate) fn get_mock_btc_txid() -> String {
+	let responses = &*BITCOIN_RESPONSES;
+	responses["getrawtransaction"].result.clone().unwrap()["txid"]
+		.clone()
+		.as_str()
+		.unwrap()
+		.to_string()
+}
+
+pub(crate) fn get_mock_btc_to_address() -> String {
+	let responses = &*BITCOIN_RESPONSES;
+	responses["getrawtransaction"].result.clone().unwrap()["vout"][0]["scriptPubKey"]["address"]
+		.clone()
+		.as_str()
+		.unwrap()
+		.to_string()
+}
+
+pub(crate) fn get_mock_btc_from_address() -> String {
+	let responses = &*BITCOIN_RESPONSES;
+	responses["getrawtransaction_spent"].result.clone().unwrap()["vout"][1]["scriptPubKey"]
+		["address"]
+		.clone()
+		.as_str()
+		.unwrap()
+		.to_string()
+}
+
+pub(crate) fn get_mock_btc_amount() -> U256 {
+	// 0.015 BTC
+	U256::from(1_500_000u64)
+}
+
 pub(crate) fn get_mock_tx_hash() -> String {
 	let responses = &*ETHLESS_RESPONSES;
 	responses["eth_getTransactionByHash"].result.clone().unwrap()["hash"]
@@ -569,6 +633,53 @@ impl MockedRpcRequests {
 	}
 }
 
+pub(crate) struct MockedBitcoinRpcRequests {
+	pub(crate) get_raw_transaction: Option<PendingRequest>,
+	pub(crate) get_block_count: Option<PendingRequest>,
+	pub(crate) get_spent_transaction: Option<PendingRequest>,
+}
+
+impl MockedBitcoinRpcRequests {
+	pub(crate) fn new<'a>(
+		rpc_uri: impl Into<Option<&'a str>>,
+		txid: &str,
+		responses: &HashMap<String, JsonRpcResponse<Value>>,
+	) -> Self {
+		let uri = rpc_uri.into().unwrap_or("dummy");
+		let get_raw_transaction = Some(pending_rpc_request(
+			"getrawtransaction",
+			vec![txid.into(), true.into()],
+			uri,
+			responses,
+		));
+		let get_block_count = Some(pending_rpc_request("getblockcount", None, uri, responses));
+		let spent = &responses["getrawtransaction_spent"];
+		let spent_txid = spent.result.as_ref().unwrap()["txid"].clone();
+		let get_spent_transaction = Some(pending_rpc_request_with_response(
+			"getrawtransaction",
+			vec![spent_txid, true.into()],
+			uri,
+			spent,
+		));
+		Self { get_raw_transaction, get_block_count, get_spent_transaction }
+	}
+
+	/// Mocks only the RPC response for get_raw_transaction
+	pub(crate) fn mock_get_raw_transaction(&mut self, state: &mut OffchainState) {
+		let get_raw_transaction = self.get_raw_transaction.take().unwrap();
+		state.expect_request(get_raw_transaction);
+	}
+
+	/// Mocks all of the RPC responses
+	pub(crate) fn mock_all(mut self, state: &mut OffchainState) {
+		self.mock_get_raw_transaction(state);
+		let get_block_count = self.get_block_count.take().unwrap();
+		state.expect_request(get_block_count);
+		let get_spent_transaction = self.get_spent_transaction.take().unwrap();
+		state.expect_request(get_spent_transaction);
+	}
+}
+
 #[test]
 #[tracing_test::traced_test]
 fn offchain_worker_should_log_when_authority_is_missing() {
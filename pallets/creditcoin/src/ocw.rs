@@ -4,9 +4,11 @@ pub(crate) mod tasks;
 
 use self::{errors::RpcUrlError, rpc::errors::RpcError};
 use super::ExternalAddress;
-use crate::{Blockchain, ExternalTxId};
+use crate::{Blockchain, Config, ConfirmationPolicy, ExternalTxId, Pallet};
 use alloc::string::String;
 pub(crate) use errors::{OffchainError, VerificationFailureCause, VerificationResult};
+use ethereum_types::U64;
+use frame_support::ensure;
 use sp_runtime::offchain::storage::StorageValueRef;
 use sp_std::prelude::*;
 
@@ -31,7 +33,68 @@ impl Blockchain {
 	}
 }
 
+/// The default number of blocks that must be built on top of a transaction's block, as with
+/// `ConfirmationPolicy::Blocks`.
 const ETH_CONFIRMATIONS: u64 = 12;
+/// bitcoind counts the transaction's own block too, so this is what it reports as 6 confirmations.
+const BTC_CONFIRMATIONS: u64 = 5;
+
+/// The code bitcoind responds with when a transaction cannot be found.
+const BTC_INVALID_ADDRESS_OR_KEY: i32 = -5;
+
+/// The block of an EVM chain that transactions are checked against.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EthTip {
+	/// The latest block, which must be at least `confirmations` blocks past the transaction's.
+	Latest { number: U64, confirmations: u64 },
+	/// The latest finalized block, which must not be before the transaction's.
+	Finalized(U64),
+}
+
+impl From<U64> for EthTip {
+	fn from(number: U64) -> Self {
+		EthTip::Latest { number, confirmations: ETH_CONFIRMATIONS }
+	}
+}
+
+impl EthTip {
+	fn ensure_confirmed(self, block_number: U64) -> OffchainResult<()> {
+		match self {
+			EthTip::Latest { number, confirmations } => {
+				let diff = number
+					.checked_sub(block_number)
+					.ok_or(VerificationFailureCause::TaskInFuture)?;
+				ensure!(diff.as_u64() >= confirmations, VerificationFailureCause::TaskUnconfirmed);
+			},
+			EthTip::Finalized(number) => {
+				ensure!(block_number <= number, VerificationFailureCause::TaskUnconfirmed);
+			},
+		}
+		Ok(())
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	fn eth_tip(blockchain: &Blockchain, rpc_url: &str) -> OffchainResult<EthTip> {
+		let tip = match Self::confirmation_policy(blockchain) {
+			Some(ConfirmationPolicy::Finalized) => {
+				EthTip::Finalized(rpc::eth_get_finalized_block_number(rpc_url)?)
+			},
+			Some(ConfirmationPolicy::Blocks(confirmations)) => {
+				EthTip::Latest { number: rpc::eth_get_block_number(rpc_url)?, confirmations }
+			},
+			None => EthTip::from(rpc::eth_get_block_number(rpc_url)?),
+		};
+		Ok(tip)
+	}
+
+	fn btc_confirmations(blockchain: &Blockchain) -> u64 {
+		match Self::confirmation_policy(blockchain) {
+			Some(ConfirmationPolicy::Blocks(confirmations)) => confirmations,
+			Some(ConfirmationPolicy::Finalized) | None => BTC_CONFIRMATIONS,
+		}
+	}
+}
 
 fn parse_eth_address(address: &ExternalAddress) -> OffchainResult<rpc::Address> {
 	let address_bytes = <[u8; 20]>::try_from(address.as_slice())
@@ -50,5 +113,20 @@ fn eth_get_transaction(tx_id: &ExternalTxId, rpc_url: &str) -> OffchainResult<rp
 	})
 }
 
+fn btc_get_raw_transaction(
+	tx_id: &ExternalTxId,
+	rpc_url: &str,
+) -> OffchainResult<rpc::BtcTransaction> {
+	rpc::btc_get_raw_transaction(tx_id, rpc_url).map_err(|e| match e {
+		RpcError::NoResult => {
+			OffchainError::InvalidTask(VerificationFailureCause::TransactionNotFound)
+		},
+		RpcError::FailureResponse(rpc::JsonRpcError {
+			code: BTC_INVALID_ADDRESS_OR_KEY, ..
+		}) => OffchainError::InvalidTask(VerificationFailureCause::TransactionNotFound),
+		e => e.into(),
+	})
+}
+
 #[cfg(test)]
 mod tests;
@@ -25,6 +25,7 @@ pub enum SchedulerError {
 pub type VerificationResult<T> = Result<T, OffchainError>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub enum VerificationFailureCause {
 	TaskNonexistent,
 	TaskFailed,
@@ -45,6 +46,8 @@ pub enum VerificationFailureCause {
 	InvalidAddress,
 	UnsupportedMethod,
 	TransactionNotFound,
+	MissingTransferLog,
+	RetriesExhausted,
 }
 
 impl VerificationFailureCause {
@@ -54,7 +57,8 @@ impl VerificationFailureCause {
 			TaskFailed | IncorrectContract | MissingSender | MissingReceiver | AbiMismatch
 			| IncorrectInputLength | IncorrectInputType | IncorrectAmount | IncorrectNonce
 			| InvalidAddress | UnsupportedMethod | TaskInFuture | IncorrectSender | EmptyInput
-			| IncorrectReceiver | TaskNonexistent | TransactionNotFound => true,
+			| IncorrectReceiver | TaskNonexistent | TransactionNotFound | MissingTransferLog
+			| RetriesExhausted => true,
 			TaskPending | TaskUnconfirmed => false,
 		}
 	}
@@ -40,7 +40,7 @@ pub mod errors {
 }
 
 #[repr(transparent)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default)]
 pub struct VecString(Vec<u8>, ());
 
 impl TryFrom<&[u8]> for VecString {
@@ -203,7 +203,9 @@ impl JsonRpcRequest {
 
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug)]
 pub struct JsonRpcResponse<T> {
+	/// Note: empty if the server did not return this value (bitcoind speaks JSON-RPC 1.0)
 	#[allow(dead_code)]
+	#[serde(default)]
 	pub jsonrpc: VecString,
 	#[allow(dead_code)]
 	pub id: u64,
@@ -261,6 +263,11 @@ impl EthTransaction {
 	pub fn input(&self) -> &[u8] {
 		&self.input.0[4..]
 	}
+
+	/// The complete calldata, including the selector if there is one
+	pub fn data(&self) -> &[u8] {
+		&self.input.0
+	}
 }
 
 #[cfg(test)]
@@ -290,10 +297,26 @@ pub struct EthTransactionReceipt {
 	pub to: Option<Address>,
 	/// Status: either 1 (success) or 0 (failure).
 	pub status: Option<U64>,
+	/// Logs emitted while executing the transaction.
+	#[serde(default)]
+	pub logs: Vec<EthLog>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct EthLog {
+	/// Address of the contract that emitted the log.
+	pub address: Address,
+	/// Indexed event parameters, preceded by the event signature.
+	pub topics: Vec<H256>,
+	/// Non-indexed event parameters.
+	pub data: Bytes,
 }
 
 #[derive(serde::Deserialize, Clone, Debug, Default)]
 pub struct EthBlock {
+	/// Block number. None when pending.
+	#[serde(default)]
+	pub number: Option<U64>,
 	/// Timestamp of the block's collation.
 	pub timestamp: U64,
 }
@@ -308,6 +331,88 @@ impl EthTransactionReceipt {
 	}
 }
 
+const SATS_PER_BTC: f64 = 100_000_000.0;
+
+/// Converts an amount of bitcoin as reported by bitcoind into satoshis.
+fn deserialize_btc_amount<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+	D: Deserializer<'de>,
+{
+	let btc = f64::deserialize(deserializer)?;
+	if !btc.is_finite() || btc < 0.0 {
+		return Err(D::Error::invalid_value(Unexpected::Float(btc), &"a non-negative amount"));
+	}
+	// every amount of bitcoin fits exactly within the 53 bit mantissa once rounded
+	Ok((btc * SATS_PER_BTC + 0.5) as u64)
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct BtcScriptPubKey {
+	/// Receiving address, reported by bitcoind 22.0 and later
+	#[serde(default)]
+	pub address: Option<String>,
+	/// Receiving addresses, reported by bitcoind before 22.0
+	#[serde(default)]
+	pub addresses: Vec<String>,
+	/// Hex encoded script
+	#[serde(default)]
+	pub hex: String,
+}
+
+const OP_RETURN: u8 = 0x6a;
+const OP_PUSHDATA1: u8 = 0x4c;
+
+impl BtcScriptPubKey {
+	pub fn pays(&self, address: &[u8]) -> bool {
+		self.address.iter().chain(&self.addresses).any(|a| a.as_bytes() == address)
+	}
+
+	/// The data pushed by an `OP_RETURN` script, or None if the script is anything else.
+	pub fn null_data(&self) -> Option<Vec<u8>> {
+		let script = hex::decode(&self.hex).ok()?;
+		let (len, data) = match script.as_slice() {
+			[OP_RETURN, OP_PUSHDATA1, len, data @ ..] => (*len, data),
+			[OP_RETURN, len @ 1..=75, data @ ..] => (*len, data),
+			_ => return None,
+		};
+		(data.len() == usize::from(len)).then(|| data.to_vec())
+	}
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct BtcOutput {
+	/// Value in satoshis
+	#[serde(deserialize_with = "deserialize_btc_amount")]
+	pub value: u64,
+	#[serde(rename = "scriptPubKey")]
+	pub script_pub_key: BtcScriptPubKey,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct BtcInput {
+	/// Id of the transaction whose output is spent. None for coinbase inputs.
+	#[serde(default)]
+	pub txid: Option<String>,
+	/// Index of the spent output in that transaction. None for coinbase inputs.
+	#[serde(default)]
+	pub vout: Option<u32>,
+}
+
+#[derive(serde::Deserialize, Clone, Debug, Default)]
+pub struct BtcTransaction {
+	/// Inputs
+	#[serde(default)]
+	pub vin: Vec<BtcInput>,
+	/// Outputs
+	pub vout: Vec<BtcOutput>,
+	/// Number of blocks mined on top of and including the transaction's block. None when pending.
+	#[serde(default)]
+	pub confirmations: Option<u64>,
+	/// Timestamp of the transaction's block, in seconds. None when pending.
+	#[serde(default)]
+	pub blocktime: Option<u64>,
+}
+
 fn to_json_hex(bytes: &[u8]) -> String {
 	use core::ops::Not;
 	let hex = hex::encode(bytes);
@@ -363,11 +468,37 @@ pub fn eth_get_block_by_number(
 	rpc_req.send(rpc_url)
 }
 
+/// Fetches the number of the most recent block the node considers final.
+pub fn eth_get_finalized_block_number(rpc_url: &str) -> OffchainResult<U64, RpcError> {
+	let rpc_req = JsonRpcRequest::new(
+		"eth_getBlockByNumber",
+		[serde_json::Value::String("finalized".into()), serde_json::Value::Bool(false)],
+	);
+	let block: EthBlock = rpc_req.send(rpc_url)?;
+	block.number.ok_or(RpcError::NoResult)
+}
+
 pub fn eth_chain_id(rpc_url: &str) -> OffchainResult<U64, RpcError> {
 	let rpc_req = JsonRpcRequest::new("eth_chainId", None);
 	rpc_req.send(rpc_url)
 }
 
+pub fn btc_get_raw_transaction(
+	tx_id: &ExternalTxId,
+	rpc_url: &str,
+) -> OffchainResult<BtcTransaction, RpcError> {
+	let rpc_req = JsonRpcRequest::new(
+		"getrawtransaction",
+		[serde_json::Value::String(hex::encode(tx_id.as_slice())), serde_json::Value::Bool(true)],
+	);
+	rpc_req.send(rpc_url)
+}
+
+pub fn btc_get_block_count(rpc_url: &str) -> OffchainResult<u64, RpcError> {
+	let rpc_req = JsonRpcRequest::new("getblockcount", None);
+	rpc_req.send(rpc_url)
+}
+
 #[cfg(test)]
 mod tests {
 	#[test]
@@ -381,4 +512,43 @@ mod tests {
 	fn format_as_hex_works() {
 		assert_eq!(super::format_as_hex(0x123456789abcdefu64), "0x123456789abcdef");
 	}
+
+	#[test]
+	fn btc_output_value_is_converted_to_satoshis() {
+		let parse = |value: &str| {
+			let output = alloc::format!(r#"{{"value":{value},"scriptPubKey":{{}}}}"#);
+			serde_json::from_str::<super::BtcOutput>(&output).map(|output| output.value)
+		};
+
+		assert_eq!(parse("0.00000001").unwrap(), 1);
+		assert_eq!(parse("0.015").unwrap(), 1_500_000);
+		assert_eq!(parse("20999999.9769").unwrap(), 2_099_999_997_690_000);
+		assert!(parse("-1.0").is_err());
+	}
+
+	#[test]
+	fn btc_script_pub_key_pays_works() {
+		let current: super::BtcScriptPubKey =
+			serde_json::from_str(r#"{"address":"bc1qexample"}"#).unwrap();
+		assert!(current.pays(b"bc1qexample"));
+		assert!(!current.pays(b"bc1qother"));
+
+		let legacy: super::BtcScriptPubKey =
+			serde_json::from_str(r#"{"addresses":["1Example"]}"#).unwrap();
+		assert!(legacy.pays(b"1Example"));
+	}
+
+	#[test]
+	fn btc_script_pub_key_null_data_works() {
+		let parse = |hex: &str| {
+			let script = alloc::format!(r#"{{"hex":"{hex}"}}"#);
+			serde_json::from_str::<super::BtcScriptPubKey>(&script).unwrap().null_data()
+		};
+
+		assert_eq!(parse("6a03abcdef"), Some(vec![0xab, 0xcd, 0xef]));
+		assert_eq!(parse("6a4c03abcdef"), Some(vec![0xab, 0xcd, 0xef]));
+		assert_eq!(parse("6a04abcdef"), None);
+		assert_eq!(parse("0014751e76e8199196d454941c45d1b3a323f1433bd6"), None);
+		assert_eq!(parse(""), None);
+	}
 }
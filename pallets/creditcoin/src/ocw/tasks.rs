@@ -11,8 +11,7 @@ pub(crate) trait OffchainVerification<T: Config> {
 	fn verify(&self) -> VerificationResult<Self::Output>;
 }
 
-use crate::ocw::errors::SchedulerError;
-use crate::ocw::VerificationFailureCause;
+use crate::ocw::errors::{OffchainError, SchedulerError};
 use pallet_offchain_task_scheduler::tasks::error::TaskError;
 pub use pallet_offchain_task_scheduler::tasks::ForwardTask;
 use pallet_offchain_task_scheduler::tasks::TaskV2;
@@ -24,7 +23,7 @@ where
 	<T as TaskConfig>::TaskCall: From<crate::pallet::Call<T>>,
 {
 	type Call = T::TaskCall;
-	type EvaluationError = VerificationFailureCause;
+	type EvaluationError = OffchainError;
 	type SchedulerError = SchedulerError;
 	fn forward_task(
 		&self,
@@ -40,6 +39,23 @@ where
 			},
 		}
 	}
+
+	fn retries_exhausted(&self, deadline: T::BlockNumber) -> Option<Self::Call> {
+		use Task::*;
+		let call: Option<crate::pallet::Call<T>> = match self {
+			VerifyTransfer(unverified) => TaskV2::<T>::retries_exhausted(
+				unverified,
+				deadline,
+				&TaskV2::<T>::to_id(unverified),
+			),
+			CollectCoins(unverified) => TaskV2::<T>::retries_exhausted(
+				unverified,
+				deadline,
+				&TaskV2::<T>::to_id(unverified),
+			),
+		};
+		call.map(|c| c.into())
+	}
 }
 
 mod tests;
@@ -4,14 +4,13 @@ use crate::{
 		self,
 		errors::{VerificationFailureCause, VerificationResult},
 		rpc::{self, EthTransaction, EthTransactionReceipt},
-		OffchainResult, ETH_CONFIRMATIONS,
+		EthTip, OffchainResult,
 	},
 	Blockchain,
 };
 use crate::{types::UnverifiedCollectedCoins, ExternalAddress, ExternalAmount};
 use core::default::Default;
 use ethabi::{Function, Param, ParamType, StateMutability, Token};
-use ethereum_types::U64;
 use frame_support::{ensure, RuntimeDebug};
 use hex_literal::hex;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -66,17 +65,14 @@ pub fn validate_collect_coins(
 	to: &ExternalAddress,
 	receipt: &EthTransactionReceipt,
 	transaction: &EthTransaction,
-	eth_tip: U64,
+	eth_tip: impl Into<EthTip>,
 	contract_address: &H160,
 ) -> OffchainResult<ExternalAmount> {
 	ensure!(receipt.is_success(), VerificationFailureCause::TaskFailed);
 
 	let block_number = transaction.block_number.ok_or(VerificationFailureCause::TaskPending)?;
 
-	let diff = (eth_tip)
-		.checked_sub(block_number)
-		.ok_or(VerificationFailureCause::TaskInFuture)?;
-	ensure!(diff.as_u64() >= ETH_CONFIRMATIONS, VerificationFailureCause::TaskUnconfirmed);
+	eth_tip.into().ensure_confirmed(block_number)?;
 
 	if let Some(to) = &transaction.to {
 		ensure!(to == contract_address, VerificationFailureCause::IncorrectContract);
@@ -127,7 +123,7 @@ impl<T: CreditcoinConfig> Pallet<T> {
 		let rpc_url = &chain.rpc_url()?;
 		let tx = ocw::eth_get_transaction(tx_id, rpc_url)?;
 		let tx_receipt = rpc::eth_get_transaction_receipt(tx_id, rpc_url)?;
-		let eth_tip = rpc::eth_get_block_number(rpc_url)?;
+		let eth_tip = Self::eth_tip(chain, rpc_url)?;
 
 		let amount = validate_collect_coins(to, &tx_receipt, &tx, eth_tip, address)?;
 
@@ -150,6 +146,7 @@ pub(crate) mod tests {
 	use super::*;
 	use crate::mock::PendingRequestExt;
 	use crate::TaskId;
+	use ethereum_types::U64;
 	use std::collections::HashMap;
 
 	// txn.from has been overriden by 'generate_address_with_proof("collector")'
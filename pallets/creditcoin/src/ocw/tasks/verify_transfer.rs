@@ -1,4 +1,4 @@
-use ethabi::{Function, Param, ParamType, StateMutability, Token};
+use ethabi::{Event, EventParam, Function, Param, ParamType, RawLog, StateMutability, Token};
 use ethereum_types::U64;
 use frame_support::ensure;
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -10,12 +10,15 @@ use sp_std::prelude::*;
 use crate::{
 	ocw::{
 		self, parse_eth_address,
-		rpc::{self, Address, EthBlock, EthTransaction, EthTransactionReceipt},
-		OffchainError, OffchainResult, VerificationFailureCause, VerificationResult,
-		ETH_CONFIRMATIONS,
+		rpc::{
+			self, Address, BtcInput, BtcOutput, BtcTransaction, EthBlock, EthTransaction,
+			EthTransactionReceipt,
+		},
+		EthTip, OffchainError, OffchainResult, VerificationFailureCause, VerificationResult,
 	},
-	Blockchain, Config, Currency, DealOrderId, EvmChainId, EvmInfo, ExternalAddress,
-	ExternalAmount, ExternalTxId, Id, LegacyTransferKind, Transfer, UnverifiedTransfer,
+	Blockchain, Config, Currency, DealOrderId, EvmChainId, EvmInfo, EvmTransferKind,
+	ExternalAddress, ExternalAmount, ExternalTxId, Id, LegacyTransferKind, Transfer, TransferKind,
+	UnverifiedTransfer,
 };
 
 pub(crate) fn ethless_transfer_function_abi() -> Function {
@@ -36,6 +39,185 @@ pub(crate) fn ethless_transfer_function_abi() -> Function {
 	}
 }
 
+pub(crate) fn erc20_transfer_function_abi() -> Function {
+	#[allow(deprecated)]
+	Function {
+		name: "transfer".into(),
+		inputs: vec![
+			Param { name: "_to".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "_value".into(), kind: ParamType::Uint(256), internal_type: None },
+		],
+		outputs: vec![Param { name: "success".into(), kind: ParamType::Bool, internal_type: None }],
+		constant: Some(false),
+		state_mutability: StateMutability::NonPayable,
+	}
+}
+
+pub(crate) fn erc20_transfer_from_function_abi() -> Function {
+	#[allow(deprecated)]
+	Function {
+		name: "transferFrom".into(),
+		inputs: vec![
+			Param { name: "_from".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "_to".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "_value".into(), kind: ParamType::Uint(256), internal_type: None },
+		],
+		outputs: vec![Param { name: "success".into(), kind: ParamType::Bool, internal_type: None }],
+		constant: Some(false),
+		state_mutability: StateMutability::NonPayable,
+	}
+}
+
+/// `execute(address,bytes)` of a relayer, which forwards `_data` to `_target`.
+pub(crate) fn relayer_execute_function_abi() -> Function {
+	#[allow(deprecated)]
+	Function {
+		name: "execute".into(),
+		inputs: vec![
+			Param { name: "_target".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "_data".into(), kind: ParamType::Bytes, internal_type: None },
+		],
+		outputs: vec![Param {
+			name: "response".into(),
+			kind: ParamType::Bytes,
+			internal_type: None,
+		}],
+		constant: Some(false),
+		state_mutability: StateMutability::Payable,
+	}
+}
+
+/// `aggregate((address,bytes)[])` of a multicall contract, which makes each of `calls` in turn.
+pub(crate) fn multicall_aggregate_function_abi() -> Function {
+	#[allow(deprecated)]
+	Function {
+		name: "aggregate".into(),
+		inputs: vec![Param {
+			name: "calls".into(),
+			kind: ParamType::Array(Box::new(ParamType::Tuple(vec![
+				ParamType::Address,
+				ParamType::Bytes,
+			]))),
+			internal_type: None,
+		}],
+		outputs: vec![
+			Param { name: "blockNumber".into(), kind: ParamType::Uint(256), internal_type: None },
+			Param {
+				name: "returnData".into(),
+				kind: ParamType::Array(Box::new(ParamType::Bytes)),
+				internal_type: None,
+			},
+		],
+		constant: Some(false),
+		state_mutability: StateMutability::NonPayable,
+	}
+}
+
+/// `execTransaction` of a Gnosis Safe multisig wallet, which calls `to` with `data` when
+/// `operation` is 0 and delegates to it otherwise.
+pub(crate) fn safe_exec_transaction_function_abi() -> Function {
+	#[allow(deprecated)]
+	Function {
+		name: "execTransaction".into(),
+		inputs: vec![
+			Param { name: "to".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "value".into(), kind: ParamType::Uint(256), internal_type: None },
+			Param { name: "data".into(), kind: ParamType::Bytes, internal_type: None },
+			Param { name: "operation".into(), kind: ParamType::Uint(8), internal_type: None },
+			Param { name: "safeTxGas".into(), kind: ParamType::Uint(256), internal_type: None },
+			Param { name: "baseGas".into(), kind: ParamType::Uint(256), internal_type: None },
+			Param { name: "gasPrice".into(), kind: ParamType::Uint(256), internal_type: None },
+			Param { name: "gasToken".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "refundReceiver".into(), kind: ParamType::Address, internal_type: None },
+			Param { name: "signatures".into(), kind: ParamType::Bytes, internal_type: None },
+		],
+		outputs: vec![Param { name: "success".into(), kind: ParamType::Bool, internal_type: None }],
+		constant: Some(false),
+		state_mutability: StateMutability::Payable,
+	}
+}
+
+pub(crate) fn erc20_transfer_event_abi() -> Event {
+	Event {
+		name: "Transfer".into(),
+		inputs: vec![
+			EventParam { name: "_from".into(), kind: ParamType::Address, indexed: true },
+			EventParam { name: "_to".into(), kind: ParamType::Address, indexed: true },
+			EventParam { name: "_value".into(), kind: ParamType::Uint(256), indexed: false },
+		],
+		anonymous: false,
+	}
+}
+
+fn validate_eth_confirmations(
+	receipt: &EthTransactionReceipt,
+	transaction: &EthTransaction,
+	eth_tip: EthTip,
+) -> OffchainResult<()> {
+	ensure!(receipt.is_success(), VerificationFailureCause::TaskFailed);
+
+	let block_number = transaction.block_number.ok_or(VerificationFailureCause::TaskPending)?;
+
+	eth_tip.ensure_confirmed(block_number)
+}
+
+/// Checks that `contract` logged a `Transfer` of `amount` from `from` to `to`.
+fn validate_transfer_logs(
+	receipt: &EthTransactionReceipt,
+	contract: &Address,
+	from: &Address,
+	to: &Address,
+	amount: &ExternalAmount,
+) -> OffchainResult<()> {
+	let transfer_event = erc20_transfer_event_abi();
+	let signature = transfer_event.signature();
+	let transfers = receipt
+		.logs
+		.iter()
+		.filter(|log| &log.address == contract && log.topics.first() == Some(&signature))
+		.map(|log| {
+			let raw = RawLog { topics: log.topics.clone(), data: log.data.0.clone() };
+			transfer_event.parse_log(raw).map_err(|e| {
+				log::error!("failed to decode transfer log: {:?}", e);
+				VerificationFailureCause::AbiMismatch
+			})
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	ensure!(!transfers.is_empty(), VerificationFailureCause::MissingTransferLog);
+
+	let mut cause = VerificationFailureCause::IncorrectSender;
+	for transfer in transfers {
+		let (log_from, log_to, log_amount) = match &transfer.params[..] {
+			[sender, receiver, value] => match (&sender.value, &receiver.value, &value.value) {
+				(Token::Address(sender), Token::Address(receiver), Token::Uint(value)) => {
+					(sender, receiver, ExternalAmount::from(value))
+				},
+				_ => return Err(VerificationFailureCause::IncorrectInputType.into()),
+			},
+			_ => return Err(VerificationFailureCause::IncorrectInputLength.into()),
+		};
+		if log_from != from {
+			continue;
+		}
+		if log_to != to {
+			cause = VerificationFailureCause::IncorrectReceiver;
+			continue;
+		}
+		if &log_amount != amount {
+			cause = VerificationFailureCause::IncorrectAmount;
+			continue;
+		}
+		return Ok(());
+	}
+
+	Err(cause.into())
+}
+
+/// Ethless transfers submitted through a relayer, a multisig wallet or a multicall contract do not
+/// call the Ethless contract directly, so when the contract logged any transfers the sender,
+/// receiver and moved funds are read from its `Transfer` logs alone. The contract does not log the
+/// nonce, so the nonce is read from the Ethless call found by [`ethless_calls`]. Transfers without
+/// logs are checked against the calldata alone.
 pub(in crate::ocw) fn validate_ethless_transfer(
 	from: &Address,
 	to: &Address,
@@ -43,19 +225,122 @@ pub(in crate::ocw) fn validate_ethless_transfer(
 	amount: &ExternalAmount,
 	receipt: &EthTransactionReceipt,
 	transaction: &EthTransaction,
-	eth_tip: U64,
+	eth_tip: impl Into<EthTip>,
 	id_hash: impl ethereum_types::BigEndianHash<Uint = U256>,
 ) -> OffchainResult<()> {
+	validate_eth_confirmations(receipt, transaction, eth_tip.into())?;
+
+	let signature = erc20_transfer_event_abi().signature();
+	let has_transfer_logs = receipt
+		.logs
+		.iter()
+		.any(|log| &log.address == contract && log.topics.first() == Some(&signature));
+
+	if has_transfer_logs {
+		validate_ethless_logs(from, to, contract, amount, receipt, transaction, id_hash)
+	} else {
+		validate_ethless_input(from, to, contract, amount, transaction, id_hash)
+	}
+}
+
+fn validate_ethless_logs(
+	from: &Address,
+	to: &Address,
+	contract: &Address,
+	amount: &ExternalAmount,
+	receipt: &EthTransactionReceipt,
+	transaction: &EthTransaction,
+	id_hash: impl ethereum_types::BigEndianHash<Uint = U256>,
+) -> OffchainResult<()> {
+	validate_transfer_logs(receipt, contract, from, to, amount)?;
+
 	let transfer_fn = ethless_transfer_function_abi();
-	ensure!(receipt.is_success(), VerificationFailureCause::TaskFailed);
+	let selector = transfer_fn.short_signature();
+	let expected_nonce = id_hash.into_uint();
+	let is_bound = ethless_calls(transaction, contract)
+		.iter()
+		.filter_map(|call| call.strip_prefix(&selector[..]))
+		.filter_map(|input| transfer_fn.decode_input(input).ok())
+		.any(
+			|inputs| matches!(inputs.get(4), Some(Token::Uint(nonce)) if nonce == &expected_nonce),
+		);
+	ensure!(is_bound, VerificationFailureCause::IncorrectNonce);
 
-	let block_number = transaction.block_number.ok_or(VerificationFailureCause::TaskPending)?;
+	Ok(())
+}
+
+/// The calls made to `contract` by `transaction`, which is either a direct call to `contract` or
+/// a call to one of the known forwarding wrappers:
+/// - a relayer's `execute(address,bytes)`;
+/// - a multicall contract's `aggregate((address,bytes)[])`;
+/// - a Gnosis Safe's `execTransaction`, when it calls rather than delegates.
+///
+/// Wrapper calls must decode to exactly their calldata; calls that do not, and calls forwarded to
+/// any other target, are ignored.
+fn ethless_calls(transaction: &EthTransaction, contract: &Address) -> Vec<Vec<u8>> {
+	let data = transaction.data();
+	if transaction.to.as_ref() == Some(contract) {
+		return vec![data.to_vec()];
+	}
+
+	if let Some(inputs) = decode_wrapper_call(&relayer_execute_function_abi(), data) {
+		return match &inputs[..] {
+			[Token::Address(target), Token::Bytes(call)] if target == contract => {
+				vec![call.clone()]
+			},
+			_ => vec![],
+		};
+	}
+
+	if let Some(inputs) = decode_wrapper_call(&multicall_aggregate_function_abi(), data) {
+		return match &inputs[..] {
+			[Token::Array(calls)] => calls
+				.iter()
+				.filter_map(|call| match call {
+					Token::Tuple(call) => match &call[..] {
+						[Token::Address(target), Token::Bytes(call)] if target == contract => {
+							Some(call.clone())
+						},
+						_ => None,
+					},
+					_ => None,
+				})
+				.collect(),
+			_ => vec![],
+		};
+	}
 
-	ensure!(block_number <= eth_tip, VerificationFailureCause::TaskInFuture);
+	if let Some(inputs) = decode_wrapper_call(&safe_exec_transaction_function_abi(), data) {
+		return match (inputs.get(0), inputs.get(2), inputs.get(3)) {
+			(
+				Some(Token::Address(target)),
+				Some(Token::Bytes(call)),
+				Some(Token::Uint(operation)),
+			) if target == contract && operation.is_zero() => vec![call.clone()],
+			_ => vec![],
+		};
+	}
 
-	let diff = eth_tip - block_number;
+	vec![]
+}
 
-	ensure!(diff.as_u64() >= ETH_CONFIRMATIONS, VerificationFailureCause::TaskUnconfirmed);
+/// The inputs of the call to `function` in `data`, provided `data` is exactly their encoding.
+fn decode_wrapper_call(function: &Function, data: &[u8]) -> Option<Vec<Token>> {
+	let input = data.strip_prefix(&function.short_signature()[..])?;
+	let inputs = function.decode_input(input).ok()?;
+	let encoded = function.encode_input(&inputs).ok()?;
+	(encoded == data).then_some(inputs)
+}
+
+fn validate_ethless_input(
+	from: &Address,
+	to: &Address,
+	contract: &Address,
+	amount: &ExternalAmount,
+	transaction: &EthTransaction,
+	id_hash: impl ethereum_types::BigEndianHash<Uint = U256>,
+) -> OffchainResult<()> {
+	let transfer_fn = ethless_transfer_function_abi();
 
 	if let Some(to) = &transaction.to {
 		ensure!(to == contract, VerificationFailureCause::IncorrectContract);
@@ -103,6 +388,139 @@ pub(in crate::ocw) fn validate_ethless_transfer(
 	Ok(())
 }
 
+/// A native transfer is a plain value transfer whose calldata is the hash of the deal order it
+/// pays, so that the same transaction cannot be registered against another deal order.
+pub(in crate::ocw) fn validate_native_transfer(
+	from: &Address,
+	to: &Address,
+	amount: &ExternalAmount,
+	receipt: &EthTransactionReceipt,
+	transaction: &EthTransaction,
+	eth_tip: impl Into<EthTip>,
+	id_hash: impl ethereum_types::BigEndianHash<Uint = U256>,
+) -> OffchainResult<()> {
+	validate_eth_confirmations(receipt, transaction, eth_tip.into())?;
+
+	let sender = transaction.from.as_ref().ok_or(VerificationFailureCause::MissingSender)?;
+	ensure!(sender == from, VerificationFailureCause::IncorrectSender);
+
+	let receiver = transaction.to.as_ref().ok_or(VerificationFailureCause::MissingReceiver)?;
+	ensure!(receiver == to, VerificationFailureCause::IncorrectReceiver);
+
+	ensure!(&transaction.value == amount, VerificationFailureCause::IncorrectAmount);
+
+	let data = transaction.data();
+	ensure!(data.len() == 32, VerificationFailureCause::IncorrectNonce);
+	let nonce = U256::from_big_endian(data);
+	ensure!(nonce == id_hash.into_uint(), VerificationFailureCause::IncorrectNonce);
+
+	Ok(())
+}
+
+/// An ERC-20 transfer is a call to the token's `transfer` or `transferFrom` with the hash of the
+/// deal order appended to the calldata, so that the same transaction cannot be registered against
+/// another deal order. Tokens ignore the trailing word. The moved funds are read from the
+/// `Transfer` log rather than the calldata, so that fee-on-transfer or otherwise unusual tokens are
+/// judged by what they actually did.
+pub(in crate::ocw) fn validate_erc20_transfer(
+	from: &Address,
+	to: &Address,
+	contract: &Address,
+	amount: &ExternalAmount,
+	receipt: &EthTransactionReceipt,
+	transaction: &EthTransaction,
+	eth_tip: impl Into<EthTip>,
+	id_hash: impl ethereum_types::BigEndianHash<Uint = U256>,
+) -> OffchainResult<()> {
+	validate_eth_confirmations(receipt, transaction, eth_tip.into())?;
+
+	if let Some(to) = &transaction.to {
+		ensure!(to == contract, VerificationFailureCause::IncorrectContract);
+	} else {
+		return Err(VerificationFailureCause::MissingReceiver.into());
+	}
+
+	let data = transaction.data();
+	let transfer_fn = erc20_transfer_function_abi();
+	let transfer_from_fn = erc20_transfer_from_function_abi();
+	let call_len = match data.get(..4) {
+		Some(selector) if selector == transfer_fn.short_signature() => 4 + 2 * 32,
+		Some(selector) if selector == transfer_from_fn.short_signature() => 4 + 3 * 32,
+		Some(_) => return Err(VerificationFailureCause::AbiMismatch.into()),
+		None => return Err(VerificationFailureCause::EmptyInput.into()),
+	};
+	match data.len().checked_sub(call_len) {
+		Some(32) => {
+			let nonce = U256::from_big_endian(&data[call_len..]);
+			ensure!(nonce == id_hash.into_uint(), VerificationFailureCause::IncorrectNonce);
+		},
+		Some(0) => return Err(VerificationFailureCause::IncorrectNonce.into()),
+		_ => return Err(VerificationFailureCause::IncorrectInputLength.into()),
+	}
+
+	validate_transfer_logs(receipt, contract, from, to, amount)
+}
+
+/// Bitcoin transactions do not name the addresses of the outputs they spend, so instead of checking
+/// the sender the transaction must carry the deal order hash in an `OP_RETURN` output, which binds
+/// it to the deal order it is registered against.
+pub(in crate::ocw) fn validate_bitcoin_transfer(
+	to: &ExternalAddress,
+	amount: &ExternalAmount,
+	transaction: &BtcTransaction,
+	btc_tip: u64,
+	required_confirmations: u64,
+	id_hash: &[u8],
+) -> OffchainResult<()> {
+	let confirmations = transaction
+		.confirmations
+		.filter(|confirmations| *confirmations > 0)
+		.ok_or(VerificationFailureCause::TaskPending)?;
+
+	// the transaction's block is at height `btc_tip + 1 - confirmations`
+	ensure!(confirmations <= btc_tip.saturating_add(1), VerificationFailureCause::TaskInFuture);
+
+	// bitcoind counts the transaction's own block, `required_confirmations` only those on top of it
+	ensure!(confirmations > required_confirmations, VerificationFailureCause::TaskUnconfirmed);
+
+	let mut outputs_to_receiver = transaction
+		.vout
+		.iter()
+		.filter(|output| output.script_pub_key.pays(to))
+		.peekable();
+	ensure!(outputs_to_receiver.peek().is_some(), VerificationFailureCause::IncorrectReceiver);
+
+	ensure!(
+		outputs_to_receiver.any(|output| &ExternalAmount::from(output.value) == amount),
+		VerificationFailureCause::IncorrectAmount
+	);
+
+	ensure!(
+		transaction
+			.vout
+			.iter()
+			.any(|output| output.script_pub_key.null_data().as_deref() == Some(id_hash)),
+		VerificationFailureCause::IncorrectNonce
+	);
+
+	Ok(())
+}
+
+/// Checks that every input of a bitcoin transfer spends an output paying `from`, given the
+/// `spent_outputs` of its inputs.
+pub(in crate::ocw) fn validate_bitcoin_sender(
+	from: &ExternalAddress,
+	spent_outputs: &[BtcOutput],
+) -> OffchainResult<()> {
+	ensure!(!spent_outputs.is_empty(), VerificationFailureCause::MissingSender);
+	ensure!(
+		spent_outputs.iter().all(|output| output.script_pub_key.pays(from)),
+		VerificationFailureCause::IncorrectSender
+	);
+
+	Ok(())
+}
+
 fn verify_chain_id(rpc_url: &str, expected: EvmChainId) -> VerificationResult<()> {
 	let id = rpc::eth_chain_id(rpc_url)?.as_u64();
 	if id == expected.as_u64() {
@@ -117,7 +535,7 @@ impl<T: Config> crate::Pallet<T> {
 		transfer: &UnverifiedTransfer<T::AccountId, BlockNumberFor<T>, T::Hash, T::Moment>,
 	) -> VerificationResult<Option<T::Moment>> {
 		let UnverifiedTransfer {
-			transfer: Transfer { blockchain, deal_order_id, amount, tx_id: tx, .. },
+			transfer: Transfer { blockchain, kind, deal_order_id, amount, tx_id: tx, .. },
 			from_external: from,
 			to_external: to,
 			currency_to_check,
@@ -136,14 +554,33 @@ impl<T: Config> crate::Pallet<T> {
 					tx,
 					None,
 				),
-				LegacyTransferKind::Native
-				| LegacyTransferKind::Erc20(_)
-				| LegacyTransferKind::Other(_) => Err(VerificationFailureCause::UnsupportedMethod.into()),
+				LegacyTransferKind::Native => Self::verify_native_transfer(
+					blockchain,
+					from,
+					to,
+					deal_order_id,
+					amount,
+					tx,
+					None,
+				),
+				LegacyTransferKind::Erc20(contract) => Self::verify_erc20_transfer(
+					blockchain,
+					contract,
+					from,
+					to,
+					deal_order_id,
+					amount,
+					tx,
+					None,
+				),
+				LegacyTransferKind::Other(_) => {
+					Err(VerificationFailureCause::UnsupportedMethod.into())
+				},
 			},
 			crate::CurrencyOrLegacyTransferKind::Currency(currency) => match currency {
 				Currency::Evm(currency_type, EvmInfo { chain_id }) => match currency_type {
-					crate::EvmCurrencyType::SmartContract(contract, _) => {
-						Self::verify_ethless_transfer(
+					crate::EvmCurrencyType::SmartContract(contract, _) => match kind {
+						TransferKind::Evm(EvmTransferKind::Erc20) => Self::verify_erc20_transfer(
 							blockchain,
 							contract,
 							from,
@@ -152,8 +589,30 @@ impl<T: Config> crate::Pallet<T> {
 							amount,
 							tx,
 							Some(*chain_id),
-						)
+						),
+						_ => Self::verify_ethless_transfer(
+							blockchain,
+							contract,
+							from,
+							to,
+							deal_order_id,
+							amount,
+							tx,
+							Some(*chain_id),
+						),
 					},
+					crate::EvmCurrencyType::Native => Self::verify_native_transfer(
+						blockchain,
+						from,
+						to,
+						deal_order_id,
+						amount,
+						tx,
+						Some(*chain_id),
+					),
+				},
+				Currency::Bitcoin(_) => {
+					Self::verify_bitcoin_transfer(blockchain, from, to, deal_order_id, amount, tx)
 				},
 			},
 		}
@@ -177,7 +636,7 @@ impl<T: Config> crate::Pallet<T> {
 
 		let tx = ocw::eth_get_transaction(tx_id, &rpc_url)?;
 		let tx_receipt = rpc::eth_get_transaction_receipt(tx_id, &rpc_url)?;
-		let eth_tip = rpc::eth_get_block_number(&rpc_url)?;
+		let eth_tip = Self::eth_tip(blockchain, &rpc_url)?;
 
 		let tx_block_num = tx.block_number;
 
@@ -197,19 +656,138 @@ impl<T: Config> crate::Pallet<T> {
 			T::HashIntoNonce::from(deal_order_id.hash()),
 		)?;
 
-		let timestamp = if let Some(num) = tx_block_num {
-			if let Ok(EthBlock { timestamp: block_timestamp }) =
-				rpc::eth_get_block_by_number(num, &rpc_url)
-			{
-				Some(T::Moment::unique_saturated_from(block_timestamp.as_u64()))
-			} else {
-				None
-			}
-		} else {
-			None
-		};
+		Ok(Self::eth_block_timestamp(tx_block_num, &rpc_url))
+	}
+
+	pub fn verify_erc20_transfer(
+		blockchain: &Blockchain,
+		contract_address: &ExternalAddress,
+		from: &ExternalAddress,
+		to: &ExternalAddress,
+		deal_order_id: &DealOrderId<BlockNumberFor<T>, T::Hash>,
+		amount: &ExternalAmount,
+		tx_id: &ExternalTxId,
+		chain_id: Option<EvmChainId>,
+	) -> VerificationResult<Option<T::Moment>> {
+		let rpc_url = blockchain.rpc_url()?;
+
+		if let Some(chain_id) = chain_id {
+			verify_chain_id(&rpc_url, chain_id)?;
+		}
+
+		let tx = ocw::eth_get_transaction(tx_id, &rpc_url)?;
+		let tx_receipt = rpc::eth_get_transaction_receipt(tx_id, &rpc_url)?;
+		let eth_tip = Self::eth_tip(blockchain, &rpc_url)?;
+
+		let from_addr = parse_eth_address(from)?;
+		let to_addr = parse_eth_address(to)?;
+
+		let token_contract = parse_eth_address(contract_address)?;
+
+		validate_erc20_transfer(
+			&from_addr,
+			&to_addr,
+			&token_contract,
+			amount,
+			&tx_receipt,
+			&tx,
+			eth_tip,
+			T::HashIntoNonce::from(deal_order_id.hash()),
+		)?;
+
+		Ok(Self::eth_block_timestamp(tx.block_number, &rpc_url))
+	}
+
+	pub fn verify_native_transfer(
+		blockchain: &Blockchain,
+		from: &ExternalAddress,
+		to: &ExternalAddress,
+		deal_order_id: &DealOrderId<BlockNumberFor<T>, T::Hash>,
+		amount: &ExternalAmount,
+		tx_id: &ExternalTxId,
+		chain_id: Option<EvmChainId>,
+	) -> VerificationResult<Option<T::Moment>> {
+		let rpc_url = blockchain.rpc_url()?;
+
+		if let Some(chain_id) = chain_id {
+			verify_chain_id(&rpc_url, chain_id)?;
+		}
 
-		Ok(timestamp)
+		let tx = ocw::eth_get_transaction(tx_id, &rpc_url)?;
+		let tx_receipt = rpc::eth_get_transaction_receipt(tx_id, &rpc_url)?;
+		let eth_tip = Self::eth_tip(blockchain, &rpc_url)?;
+
+		let from_addr = parse_eth_address(from)?;
+		let to_addr = parse_eth_address(to)?;
+
+		validate_native_transfer(
+			&from_addr,
+			&to_addr,
+			amount,
+			&tx_receipt,
+			&tx,
+			eth_tip,
+			T::HashIntoNonce::from(deal_order_id.hash()),
+		)?;
+
+		Ok(Self::eth_block_timestamp(tx.block_number, &rpc_url))
+	}
+
+	fn eth_block_timestamp(block_number: Option<U64>, rpc_url: &str) -> Option<T::Moment> {
+		let num = block_number?;
+		let EthBlock { timestamp: block_timestamp, .. } =
+			rpc::eth_get_block_by_number(num, rpc_url).ok()?;
+		Some(T::Moment::unique_saturated_from(block_timestamp.as_u64()))
+	}
+
+	pub fn verify_bitcoin_transfer(
+		blockchain: &Blockchain,
+		from: &ExternalAddress,
+		to: &ExternalAddress,
+		deal_order_id: &DealOrderId<BlockNumberFor<T>, T::Hash>,
+		amount: &ExternalAmount,
+		tx_id: &ExternalTxId,
+	) -> VerificationResult<Option<T::Moment>> {
+		let rpc_url = blockchain.rpc_url()?;
+
+		let tx = ocw::btc_get_raw_transaction(tx_id, &rpc_url)?;
+		let btc_tip = rpc::btc_get_block_count(&rpc_url)?;
+
+		validate_bitcoin_transfer(
+			to,
+			amount,
+			&tx,
+			btc_tip,
+			Self::btc_confirmations(blockchain),
+			deal_order_id.hash().as_ref(),
+		)?;
+
+		let spent_outputs = tx
+			.vin
+			.iter()
+			.map(|input| Self::btc_spent_output(input, &rpc_url))
+			.collect::<OffchainResult<Vec<_>>>()?;
+		validate_bitcoin_sender(from, &spent_outputs)?;
+
+		Ok(tx.blocktime.map(T::Moment::unique_saturated_from))
+	}
+
+	/// The output spent by `input`, looked up in the transaction that created it.
+	fn btc_spent_output(input: &BtcInput, rpc_url: &str) -> OffchainResult<BtcOutput> {
+		let (txid, vout) = match (&input.txid, input.vout) {
+			(Some(txid), Some(vout)) => (txid, vout),
+			_ => return Err(VerificationFailureCause::MissingSender.into()),
+		};
+		let tx_id = hex::decode(txid)
+			.ok()
+			.and_then(|txid| ExternalTxId::try_from(txid).ok())
+			.ok_or(VerificationFailureCause::MissingSender)?;
+		let spent_tx = ocw::btc_get_raw_transaction(&tx_id, rpc_url)?;
+		let output = spent_tx
+			.vout
+			.get(vout as usize)
+			.ok_or(VerificationFailureCause::MissingSender)?;
+		Ok(output.clone())
 	}
 }
 
@@ -5,10 +5,17 @@ use super::errors::{
 use super::{
 	errors::OffchainError,
 	parse_eth_address,
-	rpc::{Address, EthTransaction, EthTransactionReceipt},
+	rpc::{
+		Address, BtcOutput, BtcTransaction, EthLog, EthTransaction, EthTransactionReceipt,
+		JsonRpcError,
+	},
 	tasks::verify_transfer::ethless_transfer_function_abi,
-	tasks::verify_transfer::validate_ethless_transfer,
-	ETH_CONFIRMATIONS,
+	tasks::verify_transfer::{
+		erc20_transfer_event_abi, erc20_transfer_from_function_abi, erc20_transfer_function_abi,
+		safe_exec_transaction_function_abi, validate_bitcoin_sender, validate_bitcoin_transfer,
+		validate_erc20_transfer, validate_ethless_transfer, validate_native_transfer,
+	},
+	EthTip, BTC_CONFIRMATIONS, ETH_CONFIRMATIONS,
 };
 use crate::tests::adjust_deal_order_to_nonce;
 use crate::Pallet as Creditcoin;
@@ -18,14 +25,14 @@ use crate::{
 		get_mock_amount, get_mock_contract, get_mock_from_address, get_mock_input_data,
 		get_mock_nonce, get_mock_timestamp, get_mock_to_address, get_mock_tx_block_num,
 		get_mock_tx_hash, roll_to, roll_to_with_ocw, set_rpc_uri, ExtBuilder, Extrinsic,
-		MockedRpcRequests, PendingRequestExt, RuntimeCall as Call, RuntimeOrigin as Origin, RwLock,
-		TaskScheduler, Test, ETHLESS_RESPONSES,
+		MockedBitcoinRpcRequests, MockedRpcRequests, PendingRequestExt, RuntimeCall as Call,
+		RuntimeOrigin as Origin, RwLock, TaskScheduler, Test, BITCOIN_RESPONSES, ETHLESS_RESPONSES,
 	},
 	ocw::rpc::{errors::RpcError, JsonRpcResponse},
 	tests::TestInfo,
 	types::{DoubleMapExt, TransferId},
-	Blockchain, CurrencyOrLegacyTransferKind, ExternalAddress, Id, LegacyTransferKind, LoanTerms,
-	TransferKind,
+	BitcoinNetwork, Blockchain, Currency, CurrencyOrLegacyTransferKind, ExternalAddress,
+	ExternalAmount, Id, LegacyTransferKind, LoanTerms, TransferKind,
 };
 use alloc::sync::Arc;
 use assert_matches::assert_matches;
@@ -310,6 +317,266 @@ fn ethless_transfer_pending() {
 	)
 }
 
+static RELAYER_ADDR: Lazy<Address> =
+	Lazy::new(|| Address::from_str("0x9fe46736679d2d9a65f0992f2272de9f3c7fa6e0").unwrap());
+
+/// A transaction that calls a relayer, which forwards `ethless_input` to the Ethless contract
+fn relayed_ethless_transaction(ethless_input: TransferContractInput) -> EthTransaction {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&ethless_input.into_tokens())
+		.unwrap();
+	let mut input = vec![0x1c, 0xff, 0x79, 0xcd];
+	input.extend(ethabi::encode(&[
+		Token::Address(*ETHLESS_CONTRACT_ADDR),
+		Token::Bytes(ethless_call),
+	]));
+
+	let mut transaction = ETH_TRANSACTION.clone();
+	transaction.to = Some(*RELAYER_ADDR);
+	transaction.set_input(&input);
+	transaction
+}
+
+fn relayed_ethless_input() -> TransferContractInput {
+	TransferContractInput {
+		value: get_mock_amount(),
+		nonce: get_mock_nonce(),
+		..Default::default()
+	}
+}
+
+fn ethless_logs_receipt(amount: U256) -> EthTransactionReceipt {
+	EthTransactionReceipt {
+		status: Some(1u64.into()),
+		logs: vec![
+			erc20_transfer_log(&ETHLESS_FROM_ADDR, &RELAYER_ADDR, U256::one()),
+			erc20_transfer_log(&ETHLESS_FROM_ADDR, &ETHLESS_TO_ADDR, amount),
+		],
+		..Default::default()
+	}
+}
+
+#[test]
+fn ethless_transfer_through_relayer_valid() {
+	assert_ok!(test_validate_ethless_transfer(EthlessTestArgs {
+		receipt: ethless_logs_receipt(get_mock_amount()),
+		transaction: relayed_ethless_transaction(relayed_ethless_input()),
+		..Default::default()
+	}));
+}
+
+#[test]
+fn ethless_transfer_direct_call_with_logs_valid() {
+	assert_ok!(test_validate_ethless_transfer(EthlessTestArgs {
+		receipt: ethless_logs_receipt(get_mock_amount()),
+		..Default::default()
+	}));
+}
+
+#[test]
+fn ethless_transfer_through_relayer_amount_mismatch() {
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount() + 1),
+			transaction: relayed_ethless_transaction(relayed_ethless_input()),
+			..Default::default()
+		}),
+		IncorrectAmount,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_to_mismatch() {
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			to: Address::from_str("0xbad4d352a95940b3cbc52a56abbe745ba6656c84").unwrap(),
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction: relayed_ethless_transaction(relayed_ethless_input()),
+			..Default::default()
+		}),
+		IncorrectReceiver,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_nonce_mismatch() {
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction: relayed_ethless_transaction(TransferContractInput {
+				nonce: get_mock_nonce() + 1,
+				..relayed_ethless_input()
+			}),
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_without_ethless_call() {
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&[0x1c, 0xff, 0x79, 0xcd]);
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_multicall_valid() {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&relayed_ethless_input().into_tokens())
+		.unwrap();
+	// aggregate((address,bytes)[]) with an unrelated call ahead of the Ethless one
+	let mut input = vec![0x25, 0x2d, 0xba, 0x42];
+	input.extend(ethabi::encode(&[Token::Array(vec![
+		Token::Tuple(vec![Token::Address(*RELAYER_ADDR), Token::Bytes(vec![0xde, 0xad])]),
+		Token::Tuple(vec![Token::Address(*ETHLESS_CONTRACT_ADDR), Token::Bytes(ethless_call)]),
+	])]));
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&input);
+
+	assert_ok!(test_validate_ethless_transfer(EthlessTestArgs {
+		receipt: ethless_logs_receipt(get_mock_amount()),
+		transaction,
+		..Default::default()
+	}));
+}
+
+#[test]
+fn ethless_transfer_through_multisig_valid() {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&relayed_ethless_input().into_tokens())
+		.unwrap();
+	let input = safe_exec_transaction_function_abi()
+		.encode_input(&[
+			Token::Address(*ETHLESS_CONTRACT_ADDR),
+			Token::Uint(U256::zero()),
+			Token::Bytes(ethless_call),
+			Token::Uint(U256::zero()),
+			Token::Uint(U256::zero()),
+			Token::Uint(U256::zero()),
+			Token::Uint(U256::zero()),
+			Token::Address(Address::zero()),
+			Token::Address(Address::zero()),
+			Token::Bytes(vec![0xde, 0xad]),
+		])
+		.unwrap();
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&input);
+
+	assert_ok!(test_validate_ethless_transfer(EthlessTestArgs {
+		receipt: ethless_logs_receipt(get_mock_amount()),
+		transaction,
+		..Default::default()
+	}));
+}
+
+#[test]
+fn ethless_transfer_through_multicall_ignores_decoy_ethless_call() {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&relayed_ethless_input().into_tokens())
+		.unwrap();
+	// a length-prefixed Ethless call with the right nonce, passed to another contract as `bytes`
+	let mut decoy = vec![0xde, 0xad, 0xbe, 0xef];
+	decoy.extend(ethabi::encode(&[Token::Bytes(ethless_call)]));
+	let mut input = vec![0x25, 0x2d, 0xba, 0x42];
+	input.extend(ethabi::encode(&[Token::Array(vec![Token::Tuple(vec![
+		Token::Address(*RELAYER_ADDR),
+		Token::Bytes(decoy),
+	])])]));
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&input);
+
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_ignores_unprefixed_ethless_call() {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&relayed_ethless_input().into_tokens())
+		.unwrap();
+	// the Ethless call is appended to the relayer's calldata rather than passed as `bytes`
+	let mut input = vec![0x1c, 0xff, 0x79, 0xcd];
+	input.extend(ethabi::encode(&[Token::Address(*ETHLESS_CONTRACT_ADDR)]));
+	input.extend(ethless_call);
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&input);
+
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_ignores_ethless_call_sent_to_relayer() {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&relayed_ethless_input().into_tokens())
+		.unwrap();
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&ethless_call);
+
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_with_truncated_ethless_call() {
+	let ethless_call = ethless_transfer_function_abi()
+		.encode_input(&relayed_ethless_input().into_tokens())
+		.unwrap();
+	let mut input = vec![0x1c, 0xff, 0x79, 0xcd];
+	input.extend(ethabi::encode(&[
+		Token::Address(*ETHLESS_CONTRACT_ADDR),
+		Token::Bytes(ethless_call[..ethless_call.len() - 32].to_vec()),
+	]));
+	let mut transaction = relayed_ethless_transaction(relayed_ethless_input());
+	transaction.set_input(&input);
+
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			receipt: ethless_logs_receipt(get_mock_amount()),
+			transaction,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn ethless_transfer_through_relayer_without_logs_falls_back_to_input() {
+	assert_invalid_task(
+		test_validate_ethless_transfer(EthlessTestArgs {
+			transaction: relayed_ethless_transaction(relayed_ethless_input()),
+			..Default::default()
+		}),
+		IncorrectContract,
+	);
+}
+
 #[test]
 fn blockchain_rpc_url_missing() {
 	ExtBuilder::default().build_offchain_and_execute(|| {
@@ -418,23 +685,6 @@ fn verify_transfer_ocw_fails_on_unsupported_method() {
 			"0xfafafa",
 			None::<TransferKind>,
 		);
-		let mut unverified = make_unverified_transfer(transfer.clone());
-		unverified.currency_to_check =
-			crate::CurrencyOrLegacyTransferKind::TransferKind(crate::LegacyTransferKind::Native);
-		assert_matches!(
-			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
-			Err(OffchainError::InvalidTask(UnsupportedMethod))
-		);
-
-		let mut unverified = make_unverified_transfer(transfer.clone());
-		unverified.currency_to_check = crate::CurrencyOrLegacyTransferKind::TransferKind(
-			LegacyTransferKind::Erc20(ExternalAddress::default()),
-		);
-		assert_matches!(
-			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
-			Err(OffchainError::InvalidTask(UnsupportedMethod))
-		);
-
 		let mut unverified = make_unverified_transfer(transfer);
 		unverified.currency_to_check = crate::CurrencyOrLegacyTransferKind::TransferKind(
 			LegacyTransferKind::Other(ExternalAddress::default()),
@@ -665,73 +915,884 @@ fn verify_transfer_get_block_invalid_address() {
 	});
 }
 
-#[test]
-#[tracing_test::traced_test]
-fn unconfirmed_verify_transfer_retries() {
-	let mut ext = ExtBuilder::default();
-	ext.generate_authority();
-	ext.build_offchain_and_execute_with_state(|state, _pool| {
-		roll_to(1);
-
-		let dummy_url = "dummy";
-		let tx_hash = get_mock_tx_hash();
-		let contract = get_mock_contract().hex_to_address();
-		let tx_block_num = get_mock_tx_block_num();
-		let blockchain = Blockchain::RINKEBY;
-
-		let tx_block_num_value =
-			u64::from_str_radix(tx_block_num.trim_start_matches("0x"), 16).unwrap();
-
-		set_rpc_uri(&Blockchain::RINKEBY, dummy_url);
-
-		let loan_amount = get_mock_amount();
-		let terms = LoanTerms { amount: loan_amount, ..Default::default() };
+static NATIVE_TRANSACTION: Lazy<EthTransaction> = Lazy::new(|| {
+	let mut transaction = EthTransaction::default();
+	transaction.block_number = Some(5u64.into());
+	transaction.from = Some(*ETHLESS_FROM_ADDR);
+	transaction.to = Some(*ETHLESS_TO_ADDR);
+	transaction.value = get_mock_amount();
+	transaction.set_input(H256::from_uint(&get_mock_nonce()).as_bytes());
+	transaction
+});
 
-		let test_info =
-			TestInfo { blockchain: blockchain.clone(), loan_terms: terms, ..Default::default() };
+struct NativeTestArgs {
+	from: Address,
+	to: Address,
+	amount: U256,
+	receipt: EthTransactionReceipt,
+	transaction: EthTransaction,
+	tip: U64,
+	nonce: U256,
+}
 
-		let (deal_order_id, _) = test_info.create_deal_order();
+impl Default for NativeTestArgs {
+	fn default() -> Self {
+		Self {
+			from: *ETHLESS_FROM_ADDR,
+			to: *ETHLESS_TO_ADDR,
+			amount: get_mock_amount(),
+			receipt: EthTransactionReceipt { status: Some(1u64.into()), ..Default::default() },
+			transaction: NATIVE_TRANSACTION.clone(),
+			tip: (NATIVE_TRANSACTION.block_number.unwrap() + ETH_CONFIRMATIONS),
+			nonce: get_mock_nonce(),
+		}
+	}
+}
 
-		let deal_order_id = adjust_deal_order_to_nonce(&deal_order_id, get_mock_nonce());
+fn test_validate_native_transfer(args: NativeTestArgs) -> Result<(), OffchainError> {
+	let NativeTestArgs { from, to, amount, receipt, transaction, tip, nonce } = args;
 
-		let lender = test_info.lender.account_id;
-		assert_ok!(Creditcoin::<Test>::register_funding_transfer_legacy(
-			Origin::signed(lender),
-			LegacyTransferKind::Ethless(contract),
-			deal_order_id,
-			tx_hash.hex_to_address(),
-		));
+	validate_native_transfer(
+		&from,
+		&to,
+		&amount,
+		&receipt,
+		&transaction,
+		tip,
+		H256::from_uint(&nonce),
+	)
+}
 
-		let mock_unconfirmed_tx = || {
-			let mut requests =
-				MockedRpcRequests::new(dummy_url, &tx_hash, &tx_block_num, &ETHLESS_RESPONSES);
-			requests.get_block_number.set_response(JsonRpcResponse {
-				jsonrpc: "2.0".into(),
-				id: 1,
-				error: None,
-				result: Some(format!("0x{:x}", tx_block_num_value + 1)),
-			});
+#[test]
+fn native_transfer_valid() {
+	assert_ok!(test_validate_native_transfer(NativeTestArgs::default()));
+}
 
-			requests.mock_get_block_number(&mut state.write());
-		};
+#[test]
+fn native_transfer_tx_failed() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			receipt: EthTransactionReceipt { status: Some(0u64.into()), ..Default::default() },
+			..Default::default()
+		}),
+		TaskFailed,
+	);
+}
 
-		let deadline = System::<Test>::block_number()
-			.saturating_add(<Test as crate::Config>::UnverifiedTaskTimeout::get());
+#[test]
+fn native_transfer_tx_unconfirmed() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			tip: (NATIVE_TRANSACTION.block_number.unwrap() + ETH_CONFIRMATIONS / 2),
+			..Default::default()
+		}),
+		TaskUnconfirmed,
+	);
+}
 
-		mock_unconfirmed_tx();
+#[test]
+fn native_transfer_tx_ahead_of_tip() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			tip: (NATIVE_TRANSACTION.block_number.unwrap() - 1),
+			..Default::default()
+		}),
+		TaskInFuture,
+	);
+}
 
-		let id = TransferId::leaked_inner_hash::<Test>(&blockchain, &tx_hash.hex_to_address());
-		let task = TaskScheduler::pending_tasks(deadline, id).unwrap();
+#[test]
+fn native_transfer_from_mismatch() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			from: Address::from_str("0xbad349B4A760F5Aed02131e0dAA9bB99a1d1d1e5").unwrap(),
+			..Default::default()
+		}),
+		IncorrectSender,
+	);
+}
 
-		let err = ForwardTask::<Test>::forward_task(&task, deadline).expect_err("TaskUnconfirmed");
-		assert_matches!(err, TaskError::Evaluation(_));
-	});
+#[test]
+fn native_transfer_to_mismatch() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			to: Address::from_str("0xbad4d352a95940b3cbc52a56abbe745ba6656c84").unwrap(),
+			..Default::default()
+		}),
+		IncorrectReceiver,
+	);
 }
 
 #[test]
-fn luniverse_succeeds_with_fake_nonce() {
-	let mut ext = ExtBuilder::default();
-	ext.generate_authority();
+fn native_transfer_tx_missing_to() {
+	let mut transaction = NATIVE_TRANSACTION.clone();
+	transaction.to = None;
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs { transaction, ..Default::default() }),
+		MissingReceiver,
+	);
+}
+
+#[test]
+fn native_transfer_amount_mismatch() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			amount: get_mock_amount() + 1,
+			..Default::default()
+		}),
+		IncorrectAmount,
+	);
+}
+
+#[test]
+fn native_transfer_nonce_mismatch() {
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs {
+			nonce: get_mock_nonce() + 1,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn native_transfer_without_nonce() {
+	let mut transaction = NATIVE_TRANSACTION.clone();
+	transaction.set_input(&[]);
+	assert_invalid_task(
+		test_validate_native_transfer(NativeTestArgs { transaction, ..Default::default() }),
+		IncorrectNonce,
+	);
+}
+
+fn mock_native_get_transaction(requests: &mut MockedRpcRequests) {
+	let mut transaction = ETHLESS_RESPONSES["eth_getTransactionByHash"].clone();
+	let result = transaction.result.as_mut().unwrap();
+	result["from"] = get_mock_from_address().into();
+	result["to"] = get_mock_to_address().into();
+	result["value"] = format!("0x{:x}", get_mock_amount()).into();
+	result["input"] = format!("0x{:x}", H256::from_uint(&get_mock_nonce())).into();
+	requests.get_transaction.set_response(transaction);
+}
+
+#[test]
+fn verify_transfer_ocw_works_for_legacy_native() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (mut unverified, mut requests) = set_up_verify_transfer_env(false);
+		unverified.transfer.kind = TransferKind::Evm(crate::EvmTransferKind::Native);
+		unverified.currency_to_check =
+			CurrencyOrLegacyTransferKind::TransferKind(LegacyTransferKind::Native);
+		mock_native_get_transaction(&mut requests);
+
+		requests.mock_all(&mut state.write());
+
+		assert_matches!(
+			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
+			Ok(Some(timestamp)) => { assert_eq!(timestamp, get_mock_timestamp()); }
+		);
+	});
+}
+
+#[test]
+fn verify_transfer_ocw_works_for_native_currency() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (mut unverified, mut requests) = set_up_verify_transfer_env(false);
+		unverified.transfer.kind = TransferKind::Evm(crate::EvmTransferKind::Native);
+		unverified.currency_to_check = CurrencyOrLegacyTransferKind::Currency(Currency::Evm(
+			crate::EvmCurrencyType::Native,
+			crate::EvmInfo::RINKEBY,
+		));
+		mock_native_get_transaction(&mut requests);
+		{
+			let mut state = state.write();
+			requests.mock_chain_id(&mut state);
+			requests.mock_all(&mut state);
+		}
+
+		assert_matches!(crate::Pallet::<Test>::verify_transfer_ocw(&unverified), Ok(Some(_)));
+	});
+}
+
+#[test]
+fn verify_transfer_ocw_rejects_native_transfer_for_another_deal_order() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (mut unverified, mut requests) = set_up_verify_transfer_env(false);
+		unverified.transfer.deal_order_id = crate::DealOrderId::with_expiration_hash::<Test>(
+			unverified.transfer.deal_order_id.expiration(),
+			H256::from_uint(&(get_mock_nonce() + 1)),
+		);
+		unverified.currency_to_check =
+			CurrencyOrLegacyTransferKind::TransferKind(LegacyTransferKind::Native);
+		mock_native_get_transaction(&mut requests);
+
+		requests.mock_get_block_number(&mut state.write());
+
+		assert_matches!(
+			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
+			Err(OffchainError::InvalidTask(IncorrectNonce))
+		);
+	});
+}
+
+fn erc20_transfer_log(from: &Address, to: &Address, amount: U256) -> EthLog {
+	EthLog {
+		address: *ETHLESS_CONTRACT_ADDR,
+		topics: vec![erc20_transfer_event_abi().signature(), (*from).into(), (*to).into()],
+		data: ethabi::encode(&[Token::Uint(amount)]).into(),
+	}
+}
+
+static ERC20_TRANSACTION: Lazy<EthTransaction> = Lazy::new(|| {
+	let mut transaction = EthTransaction::default();
+	transaction.block_number = Some(5u64.into());
+	transaction.from = Some(*ETHLESS_FROM_ADDR);
+	transaction.to = Some(*ETHLESS_CONTRACT_ADDR);
+	let mut input = erc20_transfer_function_abi()
+		.encode_input(&[Token::Address(*ETHLESS_TO_ADDR), Token::Uint(get_mock_amount())])
+		.unwrap();
+	input.extend_from_slice(H256::from_uint(&get_mock_nonce()).as_bytes());
+	transaction.set_input(&input);
+	transaction
+});
+
+static ERC20_RECEIPT: Lazy<EthTransactionReceipt> = Lazy::new(|| EthTransactionReceipt {
+	status: Some(1u64.into()),
+	logs: vec![erc20_transfer_log(&ETHLESS_FROM_ADDR, &ETHLESS_TO_ADDR, get_mock_amount())],
+	..Default::default()
+});
+
+struct Erc20TestArgs {
+	from: Address,
+	to: Address,
+	contract: Address,
+	amount: U256,
+	receipt: EthTransactionReceipt,
+	transaction: EthTransaction,
+	tip: U64,
+	nonce: U256,
+}
+
+impl Default for Erc20TestArgs {
+	fn default() -> Self {
+		Self {
+			from: *ETHLESS_FROM_ADDR,
+			to: *ETHLESS_TO_ADDR,
+			contract: *ETHLESS_CONTRACT_ADDR,
+			amount: get_mock_amount(),
+			receipt: ERC20_RECEIPT.clone(),
+			transaction: ERC20_TRANSACTION.clone(),
+			tip: (ERC20_TRANSACTION.block_number.unwrap() + ETH_CONFIRMATIONS),
+			nonce: get_mock_nonce(),
+		}
+	}
+}
+
+fn test_validate_erc20_transfer(args: Erc20TestArgs) -> Result<(), OffchainError> {
+	let Erc20TestArgs { from, to, contract, amount, receipt, transaction, tip, nonce } = args;
+
+	validate_erc20_transfer(
+		&from,
+		&to,
+		&contract,
+		&amount,
+		&receipt,
+		&transaction,
+		tip,
+		H256::from_uint(&nonce),
+	)
+}
+
+#[test]
+fn erc20_transfer_valid() {
+	assert_ok!(test_validate_erc20_transfer(Erc20TestArgs::default()));
+}
+
+#[test]
+fn erc20_transfer_from_valid() {
+	let mut transaction = ERC20_TRANSACTION.clone();
+	let mut input = erc20_transfer_from_function_abi()
+		.encode_input(&[
+			Token::Address(*ETHLESS_FROM_ADDR),
+			Token::Address(*ETHLESS_TO_ADDR),
+			Token::Uint(get_mock_amount()),
+		])
+		.unwrap();
+	input.extend_from_slice(H256::from_uint(&get_mock_nonce()).as_bytes());
+	transaction.set_input(&input);
+	assert_ok!(test_validate_erc20_transfer(Erc20TestArgs { transaction, ..Default::default() }));
+}
+
+#[test]
+fn erc20_transfer_tx_failed() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			receipt: EthTransactionReceipt { status: Some(0u64.into()), ..ERC20_RECEIPT.clone() },
+			..Default::default()
+		}),
+		TaskFailed,
+	);
+}
+
+#[test]
+fn erc20_transfer_tx_unconfirmed() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			tip: (ERC20_TRANSACTION.block_number.unwrap() + ETH_CONFIRMATIONS / 2),
+			..Default::default()
+		}),
+		TaskUnconfirmed,
+	);
+}
+
+#[test]
+fn erc20_transfer_contract_mismatch() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			contract: Address::from_str("0xbad1439a0e0bfdcd49939f9722866651a4aa9b3c").unwrap(),
+			..Default::default()
+		}),
+		IncorrectContract,
+	);
+}
+
+#[test]
+fn erc20_transfer_unknown_method() {
+	let mut transaction = ERC20_TRANSACTION.clone();
+	transaction.set_input(&hex::decode(&*ETHLESS_INPUT).unwrap());
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs { transaction, ..Default::default() }),
+		AbiMismatch,
+	);
+}
+
+#[test]
+fn erc20_transfer_nonce_mismatch() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			nonce: get_mock_nonce() + 1,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn erc20_transfer_for_another_deal_order() {
+	assert_ok!(test_validate_erc20_transfer(Erc20TestArgs::default()));
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			nonce: get_mock_nonce() + 1,
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn erc20_transfer_without_nonce() {
+	let mut transaction = ERC20_TRANSACTION.clone();
+	let input = erc20_transfer_function_abi()
+		.encode_input(&[Token::Address(*ETHLESS_TO_ADDR), Token::Uint(get_mock_amount())])
+		.unwrap();
+	transaction.set_input(&input);
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs { transaction, ..Default::default() }),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn erc20_transfer_truncated_nonce() {
+	let mut transaction = ERC20_TRANSACTION.clone();
+	let mut input = transaction.data().to_vec();
+	input.truncate(input.len() - 1);
+	transaction.set_input(&input);
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs { transaction, ..Default::default() }),
+		IncorrectInputLength,
+	);
+}
+
+#[test]
+fn erc20_transfer_missing_log() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			receipt: EthTransactionReceipt { logs: vec![], ..ERC20_RECEIPT.clone() },
+			..Default::default()
+		}),
+		MissingTransferLog,
+	);
+}
+
+#[test]
+fn erc20_transfer_ignores_logs_of_other_contracts() {
+	let mut log = ERC20_RECEIPT.logs[0].clone();
+	log.address = Address::from_str("0xbad1439a0e0bfdcd49939f9722866651a4aa9b3c").unwrap();
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			receipt: EthTransactionReceipt { logs: vec![log], ..ERC20_RECEIPT.clone() },
+			..Default::default()
+		}),
+		MissingTransferLog,
+	);
+}
+
+#[test]
+fn erc20_transfer_from_mismatch() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			from: Address::from_str("0xbad349B4A760F5Aed02131e0dAA9bB99a1d1d1e5").unwrap(),
+			..Default::default()
+		}),
+		IncorrectSender,
+	);
+}
+
+#[test]
+fn erc20_transfer_to_mismatch() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			to: Address::from_str("0xbad4d352a95940b3cbc52a56abbe745ba6656c84").unwrap(),
+			..Default::default()
+		}),
+		IncorrectReceiver,
+	);
+}
+
+#[test]
+fn erc20_transfer_amount_mismatch() {
+	assert_invalid_task(
+		test_validate_erc20_transfer(Erc20TestArgs {
+			amount: get_mock_amount() + 1,
+			..Default::default()
+		}),
+		IncorrectAmount,
+	);
+}
+
+#[test]
+fn erc20_transfer_finds_matching_log_among_several() {
+	let other = Address::from_str("0xbad4d352a95940b3cbc52a56abbe745ba6656c84").unwrap();
+	let receipt = EthTransactionReceipt {
+		logs: vec![
+			erc20_transfer_log(&ETHLESS_FROM_ADDR, &other, U256::one()),
+			erc20_transfer_log(&ETHLESS_FROM_ADDR, &ETHLESS_TO_ADDR, get_mock_amount()),
+		],
+		..ERC20_RECEIPT.clone()
+	};
+	assert_ok!(test_validate_erc20_transfer(Erc20TestArgs { receipt, ..Default::default() }));
+}
+
+fn mock_erc20_requests(requests: &mut MockedRpcRequests) {
+	let mut transaction = ETHLESS_RESPONSES["eth_getTransactionByHash"].clone();
+	let result = transaction.result.as_mut().unwrap();
+	result["from"] = get_mock_from_address().into();
+	result["input"] = format!("0x{}", hex::encode(ERC20_TRANSACTION.data())).into();
+	requests.get_transaction.set_response(transaction);
+
+	let mut receipt = ETHLESS_RESPONSES["eth_getTransactionReceipt"].clone();
+	let log = &ERC20_RECEIPT.logs[0];
+	receipt.result.as_mut().unwrap()["logs"] = serde_json::json!([{
+		"address": format!("{:?}", log.address),
+		"topics": log.topics.iter().map(|topic| format!("{topic:?}")).collect::<Vec<_>>(),
+		"data": format!("0x{}", hex::encode(&log.data.0)),
+	}]);
+	requests.get_transaction_receipt.set_response(receipt);
+}
+
+#[test]
+fn verify_transfer_ocw_works_for_erc20() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (mut unverified, mut requests) = set_up_verify_transfer_env(false);
+		unverified.transfer.kind = TransferKind::Evm(crate::EvmTransferKind::Erc20);
+		unverified.currency_to_check = CurrencyOrLegacyTransferKind::Currency(Currency::Evm(
+			crate::EvmCurrencyType::SmartContract(
+				get_mock_contract().hex_to_address(),
+				vec![crate::EvmTransferKind::Erc20].try_into().unwrap(),
+			),
+			crate::EvmInfo::RINKEBY,
+		));
+		mock_erc20_requests(&mut requests);
+		{
+			let mut state = state.write();
+			requests.mock_chain_id(&mut state);
+			requests.mock_all(&mut state);
+		}
+
+		assert_matches!(
+			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
+			Ok(Some(timestamp)) => { assert_eq!(timestamp, get_mock_timestamp()); }
+		);
+	});
+}
+
+#[test]
+fn verify_transfer_ocw_works_for_legacy_erc20() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (mut unverified, mut requests) = set_up_verify_transfer_env(false);
+		unverified.currency_to_check = CurrencyOrLegacyTransferKind::TransferKind(
+			LegacyTransferKind::Erc20(get_mock_contract().hex_to_address()),
+		);
+		mock_erc20_requests(&mut requests);
+
+		requests.mock_all(&mut state.write());
+
+		assert_matches!(crate::Pallet::<Test>::verify_transfer_ocw(&unverified), Ok(Some(_)));
+	});
+}
+
+static BTC_TRANSACTION: Lazy<BtcTransaction> = Lazy::new(|| {
+	serde_json::from_value(BITCOIN_RESPONSES["getrawtransaction"].result.clone().unwrap()).unwrap()
+});
+
+struct BitcoinTestArgs {
+	to: ExternalAddress,
+	amount: ExternalAmount,
+	transaction: BtcTransaction,
+	tip: u64,
+	confirmations: u64,
+	id_hash: Vec<u8>,
+}
+
+fn btc_deal_order_hash(transaction: &BtcTransaction) -> Option<Vec<u8>> {
+	transaction.vout.iter().find_map(|output| output.script_pub_key.null_data())
+}
+
+impl Default for BitcoinTestArgs {
+	fn default() -> Self {
+		Self {
+			to: crate::mock::get_mock_btc_to_address().as_bytes().to_vec().try_into().unwrap(),
+			amount: crate::mock::get_mock_btc_amount(),
+			transaction: BTC_TRANSACTION.clone(),
+			tip: 2_400_009,
+			confirmations: BTC_CONFIRMATIONS,
+			id_hash: btc_deal_order_hash(&BTC_TRANSACTION).unwrap(),
+		}
+	}
+}
+
+fn test_validate_bitcoin_transfer(args: BitcoinTestArgs) -> Result<(), OffchainError> {
+	let BitcoinTestArgs { to, amount, transaction, tip, confirmations, id_hash } = args;
+
+	validate_bitcoin_transfer(&to, &amount, &transaction, tip, confirmations, &id_hash)
+}
+
+#[test]
+fn bitcoin_transfer_valid() {
+	assert_ok!(test_validate_bitcoin_transfer(BitcoinTestArgs::default()));
+}
+
+#[test]
+fn bitcoin_transfer_pending() {
+	let mut transaction = BTC_TRANSACTION.clone();
+	transaction.confirmations = None;
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs { transaction, ..Default::default() }),
+		TaskPending,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_unconfirmed() {
+	let mut transaction = BTC_TRANSACTION.clone();
+	transaction.confirmations = Some(BTC_CONFIRMATIONS - 1);
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs { transaction, ..Default::default() }),
+		TaskUnconfirmed,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_confirmations_count_blocks_on_top() {
+	// bitcoind counts the transaction's block as its first confirmation
+	let mut transaction = BTC_TRANSACTION.clone();
+	transaction.confirmations = Some(BTC_CONFIRMATIONS);
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs {
+			transaction: transaction.clone(),
+			..Default::default()
+		}),
+		TaskUnconfirmed,
+	);
+	transaction.confirmations = Some(BTC_CONFIRMATIONS + 1);
+	assert_ok!(test_validate_bitcoin_transfer(BitcoinTestArgs {
+		transaction,
+		..Default::default()
+	}));
+}
+
+#[test]
+fn bitcoin_transfer_uses_configured_confirmations() {
+	let mut transaction = BTC_TRANSACTION.clone();
+	transaction.confirmations = Some(3);
+	assert_ok!(test_validate_bitcoin_transfer(BitcoinTestArgs {
+		transaction: transaction.clone(),
+		confirmations: 2,
+		..Default::default()
+	}));
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs {
+			transaction,
+			confirmations: 3,
+			..Default::default()
+		}),
+		TaskUnconfirmed,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_ahead_of_tip() {
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs { tip: 5, ..Default::default() }),
+		TaskInFuture,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_to_mismatch() {
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs {
+			to: b"tb1qbad".to_vec().try_into().unwrap(),
+			..Default::default()
+		}),
+		IncorrectReceiver,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_amount_mismatch() {
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs {
+			amount: ExternalAmount::from(1_499_999u64),
+			..Default::default()
+		}),
+		IncorrectAmount,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_deal_order_hash_mismatch() {
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs {
+			id_hash: H256::repeat_byte(1).as_bytes().to_vec(),
+			..Default::default()
+		}),
+		IncorrectNonce,
+	);
+}
+
+#[test]
+fn bitcoin_transfer_without_deal_order_hash() {
+	let mut transaction = BTC_TRANSACTION.clone();
+	transaction.vout.retain(|output| output.script_pub_key.null_data().is_none());
+	assert_invalid_task(
+		test_validate_bitcoin_transfer(BitcoinTestArgs { transaction, ..Default::default() }),
+		IncorrectNonce,
+	);
+}
+
+static BTC_SPENT_OUTPUT: Lazy<BtcOutput> = Lazy::new(|| {
+	let spent = BITCOIN_RESPONSES["getrawtransaction_spent"].result.clone().unwrap();
+	serde_json::from_value(spent["vout"][1].clone()).unwrap()
+});
+
+fn btc_from_address() -> ExternalAddress {
+	crate::mock::get_mock_btc_from_address().as_bytes().to_vec().try_into().unwrap()
+}
+
+#[test]
+fn bitcoin_sender_valid() {
+	assert_ok!(validate_bitcoin_sender(&btc_from_address(), &[BTC_SPENT_OUTPUT.clone()]));
+}
+
+#[test]
+fn bitcoin_sender_mismatch() {
+	assert_invalid_task(
+		validate_bitcoin_sender(
+			&b"tb1qbad".to_vec().try_into().unwrap(),
+			&[BTC_SPENT_OUTPUT.clone()],
+		),
+		IncorrectSender,
+	);
+}
+
+#[test]
+fn bitcoin_sender_with_input_of_another_owner() {
+	let other_owner = BTC_TRANSACTION.vout[1].clone();
+	assert_invalid_task(
+		validate_bitcoin_sender(&btc_from_address(), &[BTC_SPENT_OUTPUT.clone(), other_owner]),
+		IncorrectSender,
+	);
+}
+
+#[test]
+fn bitcoin_sender_without_inputs() {
+	assert_invalid_task(validate_bitcoin_sender(&btc_from_address(), &[]), MissingSender);
+}
+
+fn set_up_verify_bitcoin_transfer_env() -> (MockUnverifiedTransfer, MockedBitcoinRpcRequests) {
+	let rpc_uri = "http://localhost:18332";
+	set_rpc_uri(&Blockchain::BITCOIN_TESTNET, rpc_uri);
+
+	let test_info = TestInfo::new_defaults();
+	let (deal_order_id, _) = test_info.create_deal_order();
+	let txid = crate::mock::get_mock_btc_txid();
+	let (_, mut transfer) = test_info.make_transfer(
+		&test_info.lender,
+		&test_info.borrower,
+		crate::mock::get_mock_btc_amount(),
+		&deal_order_id,
+		format!("0x{txid}"),
+		Some(TransferKind::Bitcoin),
+	);
+	transfer.blockchain = Blockchain::BITCOIN_TESTNET;
+
+	let unverified = MockUnverifiedTransfer {
+		to_external: crate::mock::get_mock_btc_to_address().as_bytes().to_vec().try_into().unwrap(),
+		from_external: btc_from_address(),
+		currency_to_check: CurrencyOrLegacyTransferKind::Currency(Currency::Bitcoin(
+			BitcoinNetwork::Testnet,
+		)),
+		..make_unverified_transfer(transfer)
+	};
+
+	// bind the mocked transaction to the deal order the transfer is registered against
+	let mut responses = (*BITCOIN_RESPONSES).clone();
+	let tx = responses.get_mut("getrawtransaction").unwrap().result.as_mut().unwrap();
+	let script = format!("6a20{}", hex::encode(deal_order_id.hash()));
+	tx["vout"][2]["scriptPubKey"]["hex"] = script.into();
+
+	(unverified, MockedBitcoinRpcRequests::new(Some(rpc_uri), &txid, &responses))
+}
+
+#[test]
+fn verify_bitcoin_transfer_ocw_works() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (unverified, requests) = set_up_verify_bitcoin_transfer_env();
+
+		requests.mock_all(&mut state.write());
+
+		let blocktime = BTC_TRANSACTION.blocktime.unwrap();
+		assert_matches!(
+			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
+			Ok(Some(timestamp)) => { assert_eq!(timestamp, blocktime); }
+		);
+	});
+}
+
+#[test]
+fn verify_bitcoin_transfer_ocw_rejects_transfer_from_another_sender() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (mut unverified, requests) = set_up_verify_bitcoin_transfer_env();
+		unverified.from_external = b"tb1qbad".to_vec().try_into().unwrap();
+
+		requests.mock_all(&mut state.write());
+
+		assert_matches!(
+			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
+			Err(OffchainError::InvalidTask(IncorrectSender))
+		);
+	});
+}
+
+#[test]
+fn verify_bitcoin_transfer_get_raw_transaction_error() {
+	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _pool| {
+		crate::mock::roll_to(1);
+		let (unverified, mut requests) = set_up_verify_bitcoin_transfer_env();
+		requests.get_raw_transaction.set_response(JsonRpcResponse::<()> {
+			jsonrpc: Default::default(),
+			id: 1,
+			error: Some(JsonRpcError {
+				code: -5,
+				message: "No such mempool or blockchain transaction.".into(),
+			}),
+			result: None,
+		});
+
+		requests.mock_get_raw_transaction(&mut state.write());
+
+		assert_matches!(
+			crate::Pallet::<Test>::verify_transfer_ocw(&unverified),
+			Err(OffchainError::InvalidTask(TransactionNotFound))
+		);
+	});
+}
+
+#[test]
+#[tracing_test::traced_test]
+fn unconfirmed_verify_transfer_retries() {
+	let mut ext = ExtBuilder::default();
+	ext.generate_authority();
+	ext.build_offchain_and_execute_with_state(|state, _pool| {
+		roll_to(1);
+
+		let dummy_url = "dummy";
+		let tx_hash = get_mock_tx_hash();
+		let contract = get_mock_contract().hex_to_address();
+		let tx_block_num = get_mock_tx_block_num();
+		let blockchain = Blockchain::RINKEBY;
+
+		let tx_block_num_value =
+			u64::from_str_radix(tx_block_num.trim_start_matches("0x"), 16).unwrap();
+
+		set_rpc_uri(&Blockchain::RINKEBY, dummy_url);
+
+		let loan_amount = get_mock_amount();
+		let terms = LoanTerms { amount: loan_amount, ..Default::default() };
+
+		let test_info =
+			TestInfo { blockchain: blockchain.clone(), loan_terms: terms, ..Default::default() };
+
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		let deal_order_id = adjust_deal_order_to_nonce(&deal_order_id, get_mock_nonce());
+
+		let lender = test_info.lender.account_id;
+		assert_ok!(Creditcoin::<Test>::register_funding_transfer_legacy(
+			Origin::signed(lender),
+			LegacyTransferKind::Ethless(contract),
+			deal_order_id,
+			tx_hash.hex_to_address(),
+		));
+
+		let mock_unconfirmed_tx = || {
+			let mut requests =
+				MockedRpcRequests::new(dummy_url, &tx_hash, &tx_block_num, &ETHLESS_RESPONSES);
+			requests.get_block_number.set_response(JsonRpcResponse {
+				jsonrpc: "2.0".into(),
+				id: 1,
+				error: None,
+				result: Some(format!("0x{:x}", tx_block_num_value + 1)),
+			});
+
+			requests.mock_get_block_number(&mut state.write());
+		};
+
+		let deadline = System::<Test>::block_number()
+			.saturating_add(<Test as crate::Config>::UnverifiedTaskTimeout::get());
+
+		mock_unconfirmed_tx();
+
+		let id = TransferId::leaked_inner_hash::<Test>(&blockchain, &tx_hash.hex_to_address());
+		let task = TaskScheduler::pending_tasks(deadline, id).unwrap();
+
+		let err = ForwardTask::<Test>::forward_task(&task, deadline).expect_err("TaskUnconfirmed");
+		assert_matches!(err, TaskError::Evaluation(_));
+	});
+}
+
+#[test]
+fn luniverse_succeeds_with_fake_nonce() {
+	let mut ext = ExtBuilder::default();
+	ext.generate_authority();
 	ext.build_offchain_and_execute_with_state(|state, pool| {
 		let dummy_url = "dummy";
 		let tx_hash = get_mock_tx_hash();
@@ -896,3 +1957,41 @@ fn parallel_worker_trivial() {
 		assert_eq!(val, TOTAL);
 	});
 }
+
+#[test]
+fn eth_tip_latest_requires_confirmations() {
+	let tip = EthTip::Latest { number: 100.into(), confirmations: 5 };
+	assert_ok!(tip.ensure_confirmed(95.into()));
+	assert_invalid_task(tip.ensure_confirmed(96.into()), TaskUnconfirmed);
+	assert_invalid_task(tip.ensure_confirmed(101.into()), TaskInFuture);
+}
+
+#[test]
+fn eth_tip_finalized_requires_finalized_block() {
+	let tip = EthTip::Finalized(100.into());
+	assert_ok!(tip.ensure_confirmed(100.into()));
+	assert_ok!(tip.ensure_confirmed(1.into()));
+	assert_invalid_task(tip.ensure_confirmed(101.into()), TaskUnconfirmed);
+}
+
+#[test]
+fn ethless_transfer_accepts_finalized_block() {
+	let block_number = ETH_TRANSACTION.block_number.unwrap();
+	let validate = |finalized: U64| {
+		let EthlessTestArgs { from, to, contract, amount, receipt, transaction, nonce, .. } =
+			default();
+		validate_ethless_transfer(
+			&from,
+			&to,
+			&contract,
+			&amount,
+			&receipt,
+			&transaction,
+			EthTip::Finalized(finalized),
+			H256::from_uint(&nonce),
+		)
+	};
+
+	assert_ok!(validate(block_number));
+	assert_invalid_task(validate(block_number - 1), TaskUnconfirmed);
+}
@@ -1,26 +1,35 @@
 use crate::{
 	helpers::{
 		extensions::{HexToAddress, IntoBounded},
-		non_paying_error, EVMAddress, PublicToAddress,
+		non_paying_error, BTCAddress, BTCAddressType, EVMAddress, PublicToAddress,
+		MATURITY_BUCKET_MILLIS, MAX_MATCH_CANDIDATES,
 	},
 	mock::{RuntimeOrigin as Origin, *},
 	types::DoubleMapExt,
-	AddressId, AskOrder, AskOrderId, BidOrder, BidOrderId, Blockchain, Config, Currencies,
-	Currency, CurrencyId, DealOrder, DealOrderId, DealOrders, Duration, EvmCurrencyType, EvmInfo,
-	EvmTransferKind, ExternalAddress, ExternalAmount, Guid, Id, LegacySighash, LegacyTransferKind,
-	LoanTerms, Offer, OfferId, Transfer, TransferId, TransferKind, Transfers, WeightInfo,
+	AddressId, AskOrder, AskOrderId, BidOrder, BidOrderId, BitcoinNetwork, Blockchain, Config,
+	ConfirmationPolicy, CreditRecord, Currencies, Currency, CurrencyId, DealOrder, DealOrderId,
+	DealOrders, Duration, EvmCurrencyType, EvmInfo, EvmTransferKind, ExternalAddress,
+	ExternalAmount, Guid, Id, InterestRate, InterestType, LegacySighash, LegacyTransferKind,
+	LoanTermRanges, LoanTerms, Offer, OfferId, Transfer, TransferId, TransferKind, Transfers,
+	WeightInfo,
 };
 use assert_matches::assert_matches;
 use bstr::B;
 use ethereum_types::{BigEndianHash, H256, U256};
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::Dispatchable,
+	traits::{Currency as CurrencyTrait, Get, Hooks},
+	weights::Weight,
+};
 use frame_system::RawOrigin;
 use pallet_offchain_task_scheduler::authority::AuthorityController;
+use pallet_offchain_task_scheduler::tasks::ForwardTask;
 use parity_scale_codec::Encode;
 use sp_core::Pair;
 use sp_runtime::{
 	offchain::storage::StorageValueRef,
-	traits::{BadOrigin, IdentifyAccount},
+	traits::{BadOrigin, Hash as _, IdentifyAccount},
 	MultiSigner,
 };
 use std::convert::{TryFrom, TryInto};
@@ -201,7 +210,7 @@ impl TestInfo {
 		assert_ok!(Creditcoin::add_ask_order(
 			Origin::signed(account_id.clone()),
 			address_id.clone(),
-			loan_terms.clone(),
+			loan_terms.clone().into(),
 			*expiration_block,
 			ask_guid.clone()
 		));
@@ -222,7 +231,7 @@ impl TestInfo {
 		assert_ok!(Creditcoin::add_bid_order(
 			Origin::signed(account_id.clone()),
 			address_id.clone(),
-			loan_terms.clone(),
+			loan_terms.clone().into(),
 			*expiration_block,
 			bid_guid.clone()
 		));
@@ -489,6 +498,111 @@ fn register_address_should_error_when_signature_is_invalid() {
 	})
 }
 
+#[test]
+fn register_address_should_work_for_bitcoin_addresses() {
+	ExtBuilder::default().build_and_execute(|| {
+		let (who, _, ownership_proof, key_pair) = generate_address_with_proof("owner");
+
+		for (blockchain, address_type) in [
+			(Blockchain::BITCOIN, BTCAddressType::P2pkh(BitcoinNetwork::Mainnet)),
+			(Blockchain::BITCOIN, BTCAddressType::P2wpkh(BitcoinNetwork::Mainnet)),
+			(Blockchain::BITCOIN_TESTNET, BTCAddressType::P2pkh(BitcoinNetwork::Testnet)),
+			(Blockchain::BITCOIN_TESTNET, BTCAddressType::P2wpkh(BitcoinNetwork::Testnet)),
+		] {
+			let address = BTCAddress::from_public(&key_pair.public(), address_type);
+			assert_ok!(Creditcoin::register_address(
+				Origin::signed(who.clone()),
+				blockchain.clone(),
+				address.clone(),
+				ownership_proof.clone()
+			));
+
+			let address_id = crate::AddressId::new::<Test>(&blockchain, &address);
+			assert_eq!(
+				Creditcoin::addresses(address_id),
+				Some(crate::Address { blockchain, value: address, owner: who.clone() })
+			);
+		}
+	});
+}
+
+#[test]
+fn add_ask_order_should_accept_bitcoin_currency() {
+	ExtBuilder::default().build_and_execute(|| {
+		let currency = Currency::Bitcoin(BitcoinNetwork::Testnet);
+		assert_ok!(Creditcoin::register_currency(Origin::root(), currency.clone()));
+
+		let (who, _, ownership_proof, key_pair) = generate_address_with_proof("lender");
+		let address = BTCAddress::from_public(
+			&key_pair.public(),
+			BTCAddressType::P2wpkh(BitcoinNetwork::Testnet),
+		);
+		assert_ok!(Creditcoin::register_address(
+			Origin::signed(who.clone()),
+			Blockchain::BITCOIN_TESTNET,
+			address.clone(),
+			ownership_proof
+		));
+		let address_id = AddressId::new::<Test>(&Blockchain::BITCOIN_TESTNET, &address);
+
+		let terms = LoanTerms {
+			currency: CurrencyId::new::<Test>(&currency),
+			..TestInfo::new_defaults().loan_terms
+		};
+		assert_ok!(Creditcoin::add_ask_order(
+			Origin::signed(who),
+			address_id,
+			terms.into(),
+			1_000,
+			"btc_ask_guid".into_bounded()
+		));
+	});
+}
+
+#[test]
+fn register_address_should_error_when_bitcoin_network_does_not_match() {
+	ExtBuilder::default().build_and_execute(|| {
+		let (who, _, ownership_proof, key_pair) = generate_address_with_proof("owner");
+		let address = BTCAddress::from_public(
+			&key_pair.public(),
+			BTCAddressType::P2wpkh(BitcoinNetwork::Mainnet),
+		);
+
+		assert_noop!(
+			Creditcoin::register_address(
+				Origin::signed(who),
+				Blockchain::BITCOIN_TESTNET,
+				address,
+				ownership_proof
+			),
+			TestError::AddressFormatNotSupported
+		);
+	});
+}
+
+#[test]
+fn register_address_should_error_for_bitcoin_address_of_another_key() {
+	ExtBuilder::default().build_and_execute(|| {
+		let (who, _, _, _) = generate_address_with_proof("owner");
+		let (_, _, ownership_proof, _) = generate_address_with_proof("bogus");
+		let (_, _, _, key_pair) = generate_address_with_proof("owner");
+		let address = BTCAddress::from_public(
+			&key_pair.public(),
+			BTCAddressType::P2pkh(BitcoinNetwork::Mainnet),
+		);
+
+		assert_noop!(
+			Creditcoin::register_address(
+				Origin::signed(who),
+				Blockchain::BITCOIN,
+				address,
+				ownership_proof
+			),
+			TestError::OwnershipNotSatisfied
+		);
+	});
+}
+
 #[test]
 fn verify_ethless_transfer() {
 	ExtBuilder::default().build_offchain_and_execute_with_state(|state, _| {
@@ -576,7 +690,7 @@ fn add_ask_order_expired() {
 			Creditcoin::add_ask_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				ask_guid
 			),
@@ -600,7 +714,7 @@ fn add_ask_order_used_guid() {
 			Creditcoin::add_ask_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				ask_guid
 			),
@@ -628,7 +742,7 @@ fn add_ask_order_pre_existing() {
 			Creditcoin::add_ask_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				ask_guid
 			),
@@ -669,7 +783,7 @@ fn add_ask_order_fails_if_currency_unregistered() {
 			Creditcoin::add_ask_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				ask_guid
 			),
@@ -692,7 +806,7 @@ fn add_ask_order_fails_if_blockchain_mismatch() {
 			Creditcoin::add_ask_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				ask_guid
 			),
@@ -740,7 +854,7 @@ fn add_bid_order_expired() {
 			Creditcoin::add_bid_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				bid_guid
 			),
@@ -764,7 +878,7 @@ fn add_bid_order_used_guid() {
 			Creditcoin::add_ask_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				bid_guid
 			),
@@ -792,7 +906,7 @@ fn add_bid_order_pre_existing() {
 			Creditcoin::add_bid_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				bid_guid
 			),
@@ -830,7 +944,7 @@ fn add_bid_order_fails_if_currency_unregistered() {
 			Creditcoin::add_bid_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				bid_guid
 			),
@@ -853,7 +967,7 @@ fn add_bid_order_fails_if_blockchain_mismatch() {
 			Creditcoin::add_bid_order(
 				Origin::signed(account_id),
 				address_id,
-				loan_terms,
+				loan_terms.into(),
 				expiration_block,
 				bid_guid
 			),
@@ -941,6 +1055,9 @@ fn add_deal_order_basic() {
 			lock: None,
 			repayment_transfer_id: None,
 			block: Some(Creditcoin::block_number()),
+			repaid_amount: ExternalAmount::zero(),
+			repayments: Default::default(),
+			accrual: None,
 		};
 
 		assert_eq!(new_deal_order, deal_order);
@@ -948,1113 +1065,1067 @@ fn add_deal_order_basic() {
 }
 
 #[test]
-fn add_deal_order_existing() {
-	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-
-		let (_, deal_order) = test_info.create_deal_order();
-		let DealOrder { expiration_block, borrower, offer_id, .. } = deal_order;
-
-		assert_noop!(
-			Creditcoin::add_deal_order(Origin::signed(borrower), offer_id, expiration_block),
-			TestError::DuplicateDealOrder
-		);
-	});
-}
-
-#[test]
-fn lock_deal_order_should_emit_deal_order_locked_event() {
+fn add_deal_order_should_use_agreed_terms_of_term_ranges() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
 		let test_info = TestInfo::new_defaults();
+		let TestInfo { lender, borrower, loan_terms, expiration_block, .. } = test_info.clone();
+		let amount = loan_terms.amount;
+		let term_millis = loan_terms.term_length.as_millis() as u64;
+		let exact = LoanTermRanges::from(loan_terms.clone());
+
+		let ask_terms = LoanTermRanges {
+			min_amount: amount / 2u64,
+			max_amount: amount * 2u64,
+			min_term_length: Duration::from_millis(term_millis / 2),
+			..exact.clone()
+		};
+		assert_ok!(Creditcoin::add_ask_order(
+			Origin::signed(lender.account_id.clone()),
+			lender.address_id.clone(),
+			ask_terms,
+			expiration_block,
+			test_info.ask_guid.clone()
+		));
 
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
+		let bid_terms = LoanTermRanges {
+			max_amount: amount * 3u64 / 2u64,
+			min_term_length: Duration::from_millis(term_millis / 2),
+			max_term_length: Duration::from_millis(term_millis * 2),
+			..exact
+		};
+		assert_ok!(Creditcoin::add_bid_order(
+			Origin::signed(borrower.account_id.clone()),
+			borrower.address_id.clone(),
+			bid_terms,
+			expiration_block,
+			test_info.bid_guid.clone()
+		));
 
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().funding_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
-			},
-		);
+		let ask_order_id = AskOrderId::new::<Test>(expiration_block, &test_info.ask_guid);
+		let bid_order_id = BidOrderId::new::<Test>(expiration_block, &test_info.bid_guid);
+		assert_ok!(Creditcoin::add_offer(
+			Origin::signed(lender.account_id),
+			ask_order_id.clone(),
+			bid_order_id.clone(),
+			expiration_block,
+		));
+		let offer_id = OfferId::new::<Test>(expiration_block, &ask_order_id, &bid_order_id);
 
-		assert_ok!(Creditcoin::lock_deal_order(
-			Origin::signed(deal_order.borrower),
-			deal_order_id.clone()
+		assert_ok!(Creditcoin::add_deal_order(
+			Origin::signed(borrower.account_id),
+			offer_id.clone(),
+			expiration_block,
 		));
-		let event = <frame_system::Pallet<Test>>::events().pop().expect("expected an event").event;
 
-		assert_matches!(event, crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderLocked(id))=>{
-			assert_eq!(id,deal_order_id);
-		});
+		let deal_order_id = DealOrderId::new::<Test>(expiration_block, &offer_id);
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(deal_order.terms, LoanTerms { amount: amount * 3u64 / 2u64, ..loan_terms });
 	});
 }
 
 #[test]
-fn lock_deal_order_should_error_when_not_signed() {
+fn add_ask_order_should_reject_empty_term_range() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
+		let TestInfo { lender, loan_terms, expiration_block, ask_guid, .. } = test_info;
+		let terms = LoanTermRanges {
+			min_amount: loan_terms.amount + 1u64,
+			..LoanTermRanges::from(loan_terms)
+		};
 
-		let (deal_order_id, _) = test_info.create_deal_order();
-
-		assert_noop!(Creditcoin::lock_deal_order(Origin::none(), deal_order_id), BadOrigin);
+		assert_noop!(
+			Creditcoin::add_ask_order(
+				Origin::signed(lender.account_id),
+				lender.address_id,
+				terms,
+				expiration_block,
+				ask_guid
+			),
+			TestError::InvalidTermRange
+		);
 	});
 }
 
 #[test]
-fn lock_deal_order_should_error_for_non_existent_deal_order() {
+fn add_deal_order_existing() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
 
 		let (_, deal_order) = test_info.create_deal_order();
-		let DealOrder { borrower, offer_id, .. } = deal_order;
-		// expiration_block set to 0
-		let deal_order_id = DealOrderId::new::<Test>(0, &offer_id);
+		let DealOrder { expiration_block, borrower, offer_id, .. } = deal_order;
 
 		assert_noop!(
-			Creditcoin::lock_deal_order(Origin::signed(borrower), deal_order_id),
-			TestError::NonExistentDealOrder
+			Creditcoin::add_deal_order(Origin::signed(borrower), offer_id, expiration_block),
+			TestError::DuplicateDealOrder
 		);
 	});
 }
 
 #[test]
-fn lock_deal_order_should_error_when_not_funded() {
+fn cancel_ask_order_should_error_for_non_lender() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-
-		let (deal_order_id, _) = test_info.create_deal_order();
+		let (ask_order_id, _) = test_info.create_ask_order();
 
 		assert_noop!(
-			Creditcoin::lock_deal_order(
+			Creditcoin::cancel_ask_order(
 				Origin::signed(test_info.borrower.account_id),
-				deal_order_id
+				ask_order_id
 			),
-			TestError::DealNotFunded
+			TestError::NotLender
 		);
 	});
 }
 
 #[test]
-fn lock_deal_order_should_fail_for_non_borrower() {
+fn cancel_ask_order_should_prevent_offers() {
 	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
 		let test_info = TestInfo::new_defaults();
+		let (ask_order_id, _) = test_info.create_ask_order();
+		let (bid_order_id, _) = test_info.create_bid_order();
 
-		let (deal_order_id, _) = test_info.create_deal_order();
+		assert_ok!(Creditcoin::cancel_ask_order(
+			Origin::signed(test_info.lender.account_id.clone()),
+			ask_order_id.clone()
+		));
+		assert!(!crate::AskOrders::<Test>::contains_id(&ask_order_id));
 
-		// simulate deal transfer
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().funding_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
-			},
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("EventRecord").event;
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::AskOrderCancelled(id)) => {
+				assert_eq!(id, ask_order_id);
+			}
 		);
 
 		assert_noop!(
-			Creditcoin::lock_deal_order(Origin::signed(test_info.lender.account_id), deal_order_id),
-			TestError::NotBorrower
+			Creditcoin::add_offer(
+				Origin::signed(test_info.lender.account_id),
+				ask_order_id,
+				bid_order_id,
+				1_000,
+			),
+			TestError::NonExistentAskOrder
 		);
 	});
 }
 
 #[test]
-fn lock_deal_order_should_fail_if_already_locked() {
+fn cancel_bid_order_should_error_for_non_borrower() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
+		let (bid_order_id, _) = test_info.create_bid_order();
 
-		let (deal_order_id, _) = test_info.create_deal_order();
-
-		// simulate deal transfer
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().funding_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
-			},
+		assert_noop!(
+			Creditcoin::cancel_bid_order(Origin::signed(test_info.lender.account_id), bid_order_id),
+			TestError::NotBorrower
 		);
+	});
+}
 
-		assert_ok!(Creditcoin::lock_deal_order(
+#[test]
+fn cancel_bid_order_should_prevent_deal_orders() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (offer_id, offer) = test_info.create_offer();
+
+		assert_ok!(Creditcoin::cancel_bid_order(
 			Origin::signed(test_info.borrower.account_id.clone()),
-			deal_order_id.clone()
+			offer.bid_id.clone()
 		));
+		assert!(!crate::BidOrders::<Test>::contains_id(&offer.bid_id));
 
 		assert_noop!(
-			Creditcoin::lock_deal_order(
+			Creditcoin::add_deal_order(
 				Origin::signed(test_info.borrower.account_id),
-				deal_order_id
+				offer_id,
+				1_000
 			),
-			TestError::DealOrderAlreadyLocked
+			TestError::NonExistentBidOrder
 		);
 	});
 }
 
 #[test]
-fn lock_deal_order_locks_by_borrower() {
+fn cancel_offer_should_error_for_non_lender() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
+		let (offer_id, _) = test_info.create_offer();
 
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		// simulate deal transfer
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().funding_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
-			},
+		assert_noop!(
+			Creditcoin::cancel_offer(Origin::signed(test_info.borrower.account_id), offer_id),
+			TestError::NotLender
 		);
-
-		assert_ok!(Creditcoin::lock_deal_order(
-			Origin::signed(test_info.borrower.account_id.clone()),
-			deal_order_id.clone()
-		));
-		let locked_deal_order =
-			crate::Pallet::<Test>::deal_orders(deal_order.expiration_block, deal_order_id.hash())
-				.unwrap();
-		assert_eq!(locked_deal_order.lock, Some(test_info.borrower.account_id));
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_not_signed() {
+fn cancel_offer_should_prevent_deal_orders() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _deal_order) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		let (offer_id, _) = test_info.create_offer();
+
+		assert_ok!(Creditcoin::cancel_offer(
+			Origin::signed(test_info.lender.account_id.clone()),
+			offer_id.clone()
+		));
+		assert!(!crate::Offers::<Test>::contains_id(&offer_id));
 
 		assert_noop!(
-			Creditcoin::fund_deal_order(Origin::none(), deal_order_id, transfer_id),
-			BadOrigin
+			Creditcoin::add_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				offer_id,
+				1_000
+			),
+			TestError::NonExistentOffer
 		);
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_address_not_registered() {
+fn set_order_matching_should_error_for_non_root() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
-
-		// simulate deal with an address that isn't registered
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				let blockchain = Blockchain::RINKEBY;
-
-				deal_order_storage.as_mut().unwrap().lender_address_id =
-					AddressId::new::<Test>(&blockchain, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
-			},
-		);
 
 		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::NonExistentAddress
+			Creditcoin::set_order_matching(Origin::signed(test_info.lender.account_id), true),
+			BadOrigin
 		);
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_for_non_lender() {
+fn order_matching_should_create_offer_for_compatible_orders() {
 	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
 
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::NotLender
+		let (ask_order_id, _) = test_info.create_ask_order();
+		let (bid_order_id, _) = test_info.create_bid_order();
+		assert_eq!(crate::MatchQueue::<Test>::get().len(), 2);
+
+		Creditcoin::on_initialize(System::block_number());
+
+		let offer_id =
+			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
+		let offer = crate::Offers::<Test>::try_get_id(&offer_id).unwrap();
+		assert_eq!(offer.lender, test_info.lender.account_id);
+		assert!(crate::MatchQueue::<Test>::get().is_empty());
+		assert!(crate::OpenAskOrders::<Test>::iter().next().is_none());
+		assert!(crate::OpenBidOrders::<Test>::iter().next().is_none());
+
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("expected an event").event;
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::OfferAdded(id, _)) => {
+				assert_eq!(id, offer_id);
+			}
 		);
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_timestamp_is_in_the_future() {
+fn order_matching_should_leave_unmatched_orders_open() {
 	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
+
+		let (ask_order_id, ask_order) = test_info.create_ask_order();
+		Creditcoin::on_initialize(System::block_number());
+
+		let rate = &ask_order.terms.interest_rate;
+		assert!(crate::OpenAskOrders::<Test>::contains_key((
+			&ask_order.terms.currency,
+			rate.class(),
+			rate.sort_key(),
+			&ask_order_id
+		)));
+		assert!(crate::Offers::<Test>::iter().next().is_none());
+	});
+}
 
-		// simulate deal with a timestamp in the future
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().timestamp = Creditcoin::timestamp() + 99999;
-			},
-		);
+#[test]
+fn order_matching_should_skip_asks_above_the_borrower_rate() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
 
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::MalformedDealOrder
-		);
+		let expensive_terms = LoanTerms {
+			interest_rate: InterestRate { rate_per_period: 5, ..InterestRate::default() },
+			..test_info.loan_terms.clone()
+		};
+		for i in 0..MAX_MATCH_CANDIDATES + 1 {
+			let guid = format!("expensive_ask_guid_{i}");
+			TestInfo {
+				ask_guid: guid.as_str().into_bounded(),
+				loan_terms: expensive_terms.clone(),
+				..test_info.clone()
+			}
+			.create_ask_order();
+		}
+		let (ask_order_id, _) = test_info.create_ask_order();
+		while !crate::MatchQueue::<Test>::get().is_empty() {
+			Creditcoin::on_initialize(System::block_number());
+		}
+
+		let (bid_order_id, _) = test_info.create_bid_order();
+		Creditcoin::on_initialize(System::block_number());
+
+		let offer_id =
+			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
+		assert!(crate::Offers::<Test>::contains_id(&offer_id));
+		assert_eq!(crate::OpenAskOrders::<Test>::iter().count(), MAX_MATCH_CANDIDATES + 1);
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_deal_is_funded() {
+fn order_matching_should_prefer_the_cheapest_ask() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		System::set_block_number(1);
+		let defaults = TestInfo::new_defaults();
+		let test_info = TestInfo {
+			loan_terms: LoanTerms {
+				interest_rate: InterestRate { rate_per_period: 5, ..InterestRate::default() },
+				..defaults.loan_terms.clone()
+			},
+			..defaults
+		};
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
 
-		// simulate a funded deal
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().funding_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+		let (pricier_ask_order_id, _) = test_info.create_ask_order();
+		let (cheaper_ask_order_id, _) = TestInfo {
+			ask_guid: "cheaper_ask_guid".into_bounded(),
+			loan_terms: LoanTerms {
+				interest_rate: InterestRate { rate_per_period: 3, ..InterestRate::default() },
+				..test_info.loan_terms.clone()
 			},
-		);
+			..test_info.clone()
+		}
+		.create_ask_order();
+		Creditcoin::on_initialize(System::block_number());
 
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::DealOrderAlreadyFunded
-		);
+		let (bid_order_id, _) = test_info.create_bid_order();
+		Creditcoin::on_initialize(System::block_number());
+
+		let offer_id =
+			OfferId::new::<Test>(test_info.expiration_block, &cheaper_ask_order_id, &bid_order_id);
+		assert!(crate::Offers::<Test>::contains_id(&offer_id));
+		let open: Vec<_> = crate::OpenAskOrders::<Test>::iter_keys().map(|key| key.3).collect();
+		assert_eq!(open, vec![pricier_ask_order_id]);
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_deal_has_expired() {
+fn cancel_ask_order_should_close_open_ask_order() {
 	ExtBuilder::default().build_and_execute(|| {
-		roll_to(4); // advance head so we have something to compare to
-
+		System::set_block_number(1);
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
 
-		// simulate an expired deal by setting expiration_block < head
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().expiration_block = 0;
-			},
-		);
+		let (ask_order_id, _) = test_info.create_ask_order();
+		Creditcoin::on_initialize(System::block_number());
+		assert!(crate::OpenAskOrders::<Test>::iter().next().is_some());
 
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::DealOrderExpired
-		);
+		assert_ok!(Creditcoin::cancel_ask_order(
+			Origin::signed(test_info.lender.account_id.clone()),
+			ask_order_id
+		));
+		assert!(crate::OpenAskOrders::<Test>::iter().next().is_none());
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_transfer_order_id_doesnt_match_deal_order_id() {
+fn expired_orders_should_be_closed() {
 	ExtBuilder::default().build_and_execute(|| {
-		// this is the primary deal_order
-		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
-		let currency = ethless_currency(contract);
-		let test_info = TestInfo::with_currency(currency);
-		let (deal_order_id, _) = test_info.create_deal_order();
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
 
-		// this is a deal_order from another person
-		let second_test_info = TestInfo {
-			lender: RegisteredAddress::new("lender2", Blockchain::RINKEBY),
-			borrower: RegisteredAddress::new("borrower2", Blockchain::RINKEBY),
-			blockchain: Blockchain::RINKEBY,
+		// the ask is above the borrower's rate, so both orders are left open
+		TestInfo {
 			loan_terms: LoanTerms {
-				amount: 2_000_000u64.into(),
-				interest_rate: Default::default(),
-				term_length: Duration::from_millis(1_000_000),
-				currency: CurrencyId::new::<Test>(&Default::default()),
+				interest_rate: InterestRate { rate_per_period: 5, ..InterestRate::default() },
+				..test_info.loan_terms.clone()
 			},
-			ask_guid: "second-ask-guid".into_bounded(),
-			bid_guid: "second-bid-guid".into_bounded(),
-			expiration_block: 3_333,
-			currency: Default::default(),
-		};
+			..test_info.clone()
+		}
+		.create_ask_order();
+		test_info.create_bid_order();
+		Creditcoin::on_initialize(System::block_number());
+		assert!(crate::OpenAskOrders::<Test>::iter().next().is_some());
+		assert!(crate::OpenBidOrders::<Test>::iter().next().is_some());
 
-		let (bogus_deal_order_id, _) = second_test_info.create_deal_order();
+		Creditcoin::on_initialize(test_info.expiration_block);
 
-		//  insert as exemption to bypass transfer verification
-		let tx_hash = "0".into_bounded();
+		assert!(crate::OpenAskOrders::<Test>::iter().next().is_none());
+		assert!(crate::OpenBidOrders::<Test>::iter().next().is_none());
+	});
+}
 
-		assert_ok!(Creditcoin::register_funding_transfer(
-			Origin::signed(second_test_info.lender.account_id.clone()),
-			TransferKind::Evm(EvmTransferKind::Ethless),
-			bogus_deal_order_id.clone(),
-			tx_hash
-		));
-		let (transfer_id, _) = second_test_info.create_funding_transfer(&bogus_deal_order_id);
+#[test]
+fn order_matching_should_open_orders_when_queue_is_full() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		assert_ok!(Creditcoin::set_order_matching(RawOrigin::Root.into(), true));
 
-		// try funding DealOrder from Person1 with the transfer from Person2,
-		// which points to a different deal_order_id
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::TransferDealOrderMismatch
-		);
+		let queued =
+			crate::OrderId::Ask(AskOrderId::new::<Test>(test_info.expiration_block, b"queued"));
+		let full = crate::QueuedOrders::try_from(vec![queued; 1024]).unwrap();
+		crate::MatchQueue::<Test>::put(full.clone());
+
+		let (ask_order_id, _) = test_info.create_ask_order();
+		assert_eq!(crate::MatchQueue::<Test>::get(), full);
+		let open: Vec<_> = crate::OpenAskOrders::<Test>::iter_keys().map(|key| key.3).collect();
+		assert_eq!(open, vec![ask_order_id.clone()]);
+
+		crate::MatchQueue::<Test>::kill();
+		let (bid_order_id, _) = test_info.create_bid_order();
+		Creditcoin::on_initialize(System::block_number());
+
+		let offer_id =
+			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
+		assert!(crate::Offers::<Test>::contains_id(&offer_id));
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_transfer_amount_doesnt_match() {
+fn order_matching_disabled_should_not_queue_orders() {
 	ExtBuilder::default().build_and_execute(|| {
-		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
-		let currency = ethless_currency(contract);
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
 
-		let test_info = TestInfo::with_currency(currency);
-		let (deal_order_id, _) = test_info.create_deal_order();
+		test_info.create_ask_order();
+		test_info.create_bid_order();
+		assert!(crate::MatchQueue::<Test>::get().is_empty());
 
-		//  insert as exemption to bypass transfer verification
-		let tx_hash = "0".into_bounded();
+		Creditcoin::on_initialize(System::block_number());
 
-		assert_ok!(Creditcoin::register_funding_transfer(
-			Origin::signed(test_info.lender.account_id.clone()),
-			EvmTransferKind::Ethless.into(),
-			deal_order_id.clone(),
-			tx_hash
-		));
+		assert!(crate::Offers::<Test>::iter().next().is_none());
+	});
+}
 
-		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+#[test]
+fn lock_deal_order_should_emit_deal_order_locked_event() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
 
-		// modify deal amount in order to cause transfer mismatch
 		crate::DealOrders::<Test>::mutate(
 			deal_order_id.expiration(),
 			deal_order_id.hash(),
 			|deal_order_storage| {
-				// note: the transfer above has amount of 0 b/c it is an exemption!
-				deal_order_storage.as_mut().unwrap().terms.amount = ExternalAmount::from(4444u64);
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
 			},
 		);
 
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::TransferAmountMismatch
-		);
+		assert_ok!(Creditcoin::lock_deal_order(
+			Origin::signed(deal_order.borrower),
+			deal_order_id.clone()
+		));
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("expected an event").event;
+
+		assert_matches!(event, crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderLocked(id))=>{
+			assert_eq!(id,deal_order_id);
+		});
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_transfer_sighash_doesnt_match_lender() {
+fn lock_deal_order_should_error_when_not_signed() {
 	ExtBuilder::default().build_and_execute(|| {
-		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
-		let currency = ethless_currency(contract);
-
-		let test_info = TestInfo::with_currency(currency);
-
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		//  insert as exemption to bypass transfer verification
-		let tx_hash = "0".into_bounded();
-
-		assert_ok!(Creditcoin::register_funding_transfer(
-			Origin::signed(test_info.lender.account_id.clone()),
-			EvmTransferKind::Ethless.into(),
-			deal_order_id.clone(),
-			tx_hash
-		));
-
-		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+		let test_info = TestInfo::new_defaults();
 
-		// modify transfer in order to cause transfer mismatch
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			let mut ts = transfer_storage.as_mut().unwrap();
-			// b/c amount above is 0
-			ts.amount = deal_order.terms.amount;
-			ts.account_id = AccountId::new([4; 32]);
-		});
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::TransferAccountMismatch
-		);
+		assert_noop!(Creditcoin::lock_deal_order(Origin::none(), deal_order_id), BadOrigin);
 	});
 }
 
 #[test]
-fn fund_deal_order_should_error_when_transfer_has_been_processed() {
+fn lock_deal_order_should_error_for_non_existent_deal_order() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
 
-		// modify transfer in order to cause an error
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			let mut ts = transfer_storage.as_mut().unwrap();
-			// b/c amount above is 0
-			ts.amount = deal_order.terms.amount;
-			ts.is_processed = true;
-		});
+		let (_, deal_order) = test_info.create_deal_order();
+		let DealOrder { borrower, offer_id, .. } = deal_order;
+		// expiration_block set to 0
+		let deal_order_id = DealOrderId::new::<Test>(0, &offer_id);
 
 		assert_noop!(
-			Creditcoin::fund_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id
-			),
-			TestError::TransferAlreadyProcessed
+			Creditcoin::lock_deal_order(Origin::signed(borrower), deal_order_id),
+			TestError::NonExistentDealOrder
 		);
 	});
 }
 
 #[test]
-fn fund_deal_order_works() {
+fn lock_deal_order_should_error_when_not_funded() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
 
-		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
-		let currency = ethless_currency(contract);
-		let test_info = TestInfo::with_currency(currency);
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		//  insert as exemption to bypass transfer verification
-		let tx_hash = "0".into_bounded();
-
-		assert_ok!(Creditcoin::register_funding_transfer(
-			Origin::signed(test_info.lender.account_id.clone()),
-			EvmTransferKind::Ethless.into(),
-			deal_order_id.clone(),
-			tx_hash
-		));
-
-		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
-
-		// modify transfer b/c amount above is 0
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			transfer_storage.as_mut().unwrap().amount = deal_order.terms.amount;
-		});
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-		assert_ok!(Creditcoin::fund_deal_order(
-			Origin::signed(test_info.lender.account_id),
-			deal_order_id.clone(),
-			transfer_id.clone()
-		));
+		assert_noop!(
+			Creditcoin::lock_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id
+			),
+			TestError::DealNotFunded
+		);
+	});
+}
 
-		// assert events in reversed order
-		let mut all_events = <frame_system::Pallet<Test>>::events();
+#[test]
+fn lock_deal_order_should_fail_for_non_borrower() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
 
-		let event2 = all_events.pop().expect("Second EventRecord").event;
-		assert_matches!(
-			event2,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::TransferProcessed(id)) =>{
-				assert_eq!(transfer_id, id)
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-			}
+		// simulate deal transfer
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+			},
 		);
 
-		let event1 = all_events.pop().expect("First EventRecord").event;
-		assert_matches!(
-			event1,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderFunded(id))=>{
-				assert_eq!(deal_order_id, id)
-			}
+		assert_noop!(
+			Creditcoin::lock_deal_order(Origin::signed(test_info.lender.account_id), deal_order_id),
+			TestError::NotBorrower
 		);
 	});
 }
 
 #[test]
-fn claim_legacy_wallet_works() {
-	let keeper = AccountId::from([0; 32]);
-	let legacy_amount = 1000000;
-	let sighash =
-		LegacySighash::try_from("f0bdc887e4d7928623081f30b1bc87b9e4443cca6b52c4364ce578cb6bf4")
-			.unwrap();
-	let pub_key = sp_core::ecdsa::Public::from_full(
-		&hex::decode("0399d6e7c784494fd7edc26fc9ca460a68c97cc64c49c85dfbb68148f0607893bf").unwrap(),
-	)
-	.unwrap();
-	let claimer = MultiSigner::from(pub_key).into_account();
+fn lock_deal_order_should_fail_if_already_locked() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
 
-	let mut ext = ExtBuilder::default();
-	ext.fund(keeper.clone(), legacy_amount)
-		.legacy_balance_keeper(keeper)
-		.legacy_wallets(vec![(sighash, legacy_amount)]);
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-	ext.build_and_execute(|| {
-		System::set_block_number(1);
+		// simulate deal transfer
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+			},
+		);
 
-		assert_ok!(Creditcoin::claim_legacy_wallet(Origin::signed(claimer.clone()), pub_key));
-		// assert events in reversed order
-		let mut all_events = <frame_system::Pallet<Test>>::events();
-		let event = all_events.pop().expect("Expected at least one EventRecord to be found").event;
-		assert!(matches!(
-			event,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::LegacyWalletClaimed(..))
+		assert_ok!(Creditcoin::lock_deal_order(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deal_order_id.clone()
 		));
-		assert_eq!(frame_system::pallet::Account::<Test>::get(&claimer).data.free, 1000000);
+
+		assert_noop!(
+			Creditcoin::lock_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id
+			),
+			TestError::DealOrderAlreadyLocked
+		);
 	});
 }
 
 #[test]
-fn add_authority_errors_for_non_root() {
+fn lock_deal_order_locks_by_borrower() {
 	ExtBuilder::default().build_and_execute(|| {
-		let acct: AccountId = AccountId::new([0; 32]);
+		let test_info = TestInfo::new_defaults();
 
-		assert_noop!(Creditcoin::add_authority(Origin::signed(acct.clone()), acct), BadOrigin);
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// simulate deal transfer
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+			},
+		);
+
+		assert_ok!(Creditcoin::lock_deal_order(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deal_order_id.clone()
+		));
+		let locked_deal_order =
+			crate::Pallet::<Test>::deal_orders(deal_order.expiration_block, deal_order_id.hash())
+				.unwrap();
+		assert_eq!(locked_deal_order.lock, Some(test_info.borrower.account_id));
 	});
 }
 
 #[test]
-fn add_authority_should_fail_when_authority_already_exists() {
+fn fund_deal_order_should_error_when_not_signed() {
 	ExtBuilder::default().build_and_execute(|| {
-		let root = RawOrigin::Root;
-		let acct: AccountId = AccountId::new([0; 32]);
-
-		assert_ok!(Creditcoin::add_authority(
-			crate::mock::RuntimeOrigin::from(root.clone()),
-			acct.clone(),
-		));
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _deal_order) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
 
-		// try again
 		assert_noop!(
-			Creditcoin::add_authority(crate::mock::RuntimeOrigin::from(root), acct,),
-			TestError::AlreadyAuthority,
+			Creditcoin::fund_deal_order(Origin::none(), deal_order_id, transfer_id),
+			BadOrigin
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_not_signed() {
+fn fund_deal_order_should_error_when_address_not_registered() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
 
-		let (key_pair, _) = sp_core::ecdsa::Pair::generate();
-		let message = test_info.get_register_deal_msg();
-		let signature = key_pair.sign(&message);
+		// simulate deal with an address that isn't registered
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				let blockchain = Blockchain::RINKEBY;
+
+				deal_order_storage.as_mut().unwrap().lender_address_id =
+					AddressId::new::<Test>(&blockchain, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
+			},
+		);
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
-				Origin::none(),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				key_pair.public().into(),
-				signature.into(),
+			Creditcoin::fund_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				deal_order_id,
+				transfer_id
 			),
-			BadOrigin
+			TestError::NonExistentAddress
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_signature_is_invalid() {
+fn fund_deal_order_should_error_for_non_lender() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-
-		let (key_pair, _) = sp_core::ecdsa::Pair::generate();
-		let (wrong_key, _) = sp_core::ecdsa::Pair::generate();
-		let message = test_info.get_register_deal_msg();
-		let signature = key_pair.sign(&message);
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
-				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				wrong_key.public().into(),
-				signature.into(),
+			Creditcoin::fund_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::InvalidSignature
+			TestError::NotLender
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_borrower_address_doesnt_match_signature() {
+fn fund_deal_order_should_error_when_timestamp_is_in_the_future() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
 
-		let (key_pair, _) = sp_core::ecdsa::Pair::generate();
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		// simulate deal with a timestamp in the future
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().timestamp = Creditcoin::timestamp() + 99999;
+			},
+		);
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id.clone(),
-				test_info.lender.address_id.clone(), // <-- bogus address
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				key_pair.public().into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::NotAddressOwner
+			TestError::MalformedDealOrder
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_lender_address_doesnt_match_sender() {
+fn fund_deal_order_should_error_when_deal_is_funded() {
 	ExtBuilder::default().build_and_execute(|| {
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(
-				key_pair.public(),
-				Blockchain::RINKEBY,
-				ownership_proof,
-			),
-			..TestInfo::new_defaults()
-		};
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
 
-		let lender = RegisteredAddress::new("lender2", Blockchain::RINKEBY);
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		// simulate a funded deal
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+			},
+		);
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				key_pair.public().into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::NotAddressOwner
+			TestError::DealOrderAlreadyFunded
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_lender_and_borrower_are_on_different_chains() {
+fn fund_deal_order_should_error_when_deal_has_expired() {
 	ExtBuilder::default().build_and_execute(|| {
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
+		roll_to(4); // advance head so we have something to compare to
 
-		let test_info = TestInfo {
-			lender: RegisteredAddress::new("lender2", Blockchain::ETHEREUM),
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
-		};
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		// simulate an expired deal by setting expiration_block < head
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().expiration_block = 0;
+			},
+		);
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				pub_key.into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::AddressBlockchainMismatch
+			TestError::DealOrderExpired
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_ask_order_id_exists() {
+fn fund_deal_order_should_error_when_transfer_order_id_doesnt_match_deal_order_id() {
 	ExtBuilder::default().build_and_execute(|| {
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
+		// this is the primary deal_order
+		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
+		let currency = ethless_currency(contract);
+		let test_info = TestInfo::with_currency(currency);
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
+		// this is a deal_order from another person
+		let second_test_info = TestInfo {
+			lender: RegisteredAddress::new("lender2", Blockchain::RINKEBY),
+			borrower: RegisteredAddress::new("borrower2", Blockchain::RINKEBY),
+			blockchain: Blockchain::RINKEBY,
+			loan_terms: LoanTerms {
+				amount: 2_000_000u64.into(),
+				interest_rate: Default::default(),
+				term_length: Duration::from_millis(1_000_000),
+				currency: CurrencyId::new::<Test>(&Default::default()),
+			},
+			ask_guid: "second-ask-guid".into_bounded(),
+			bid_guid: "second-bid-guid".into_bounded(),
+			expiration_block: 3_333,
+			currency: Default::default(),
 		};
-		// create AskOrder which will use-up the default ID
-		test_info.create_ask_order();
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		let (bogus_deal_order_id, _) = second_test_info.create_deal_order();
+
+		//  insert as exemption to bypass transfer verification
+		let tx_hash = "0".into_bounded();
+
+		assert_ok!(Creditcoin::register_funding_transfer(
+			Origin::signed(second_test_info.lender.account_id.clone()),
+			TransferKind::Evm(EvmTransferKind::Ethless),
+			bogus_deal_order_id.clone(),
+			tx_hash
+		));
+		let (transfer_id, _) = second_test_info.create_funding_transfer(&bogus_deal_order_id);
 
+		// try funding DealOrder from Person1 with the transfer from Person2,
+		// which points to a different deal_order_id
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				pub_key.into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::DuplicateId
+			TestError::TransferDealOrderMismatch
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_bid_order_id_exists() {
+fn fund_deal_order_should_error_when_transfer_amount_doesnt_match() {
 	ExtBuilder::default().build_and_execute(|| {
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
+		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
+		let currency = ethless_currency(contract);
 
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
-		};
+		let test_info = TestInfo::with_currency(currency);
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-		// create BidOrder which will use-up the default ID
-		test_info.create_bid_order();
+		//  insert as exemption to bypass transfer verification
+		let tx_hash = "0".into_bounded();
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		assert_ok!(Creditcoin::register_funding_transfer(
+			Origin::signed(test_info.lender.account_id.clone()),
+			EvmTransferKind::Ethless.into(),
+			deal_order_id.clone(),
+			tx_hash
+		));
+
+		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+
+		// modify deal amount in order to cause transfer mismatch
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				// note: the transfer above has amount of 0 b/c it is an exemption!
+				deal_order_storage.as_mut().unwrap().terms.amount = ExternalAmount::from(4444u64);
+			},
+		);
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				key_pair.public().into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::DuplicateId
+			TestError::TransferAmountMismatch
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_offer_id_exists() {
+fn fund_deal_order_should_error_when_transfer_sighash_doesnt_match_lender() {
 	ExtBuilder::default().build_and_execute(|| {
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
+		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
+		let currency = ethless_currency(contract);
 
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
-		};
+		let test_info = TestInfo::with_currency(currency);
 
-		// create Offer w/o creating AskOrder & BidOrder to avoid
-		// erroring out when checking for their existence
-		let ask_order_id = AskOrderId::new::<Test>(test_info.expiration_block, &test_info.ask_guid);
-		let bid_order_id = BidOrderId::new::<Test>(test_info.expiration_block, &test_info.bid_guid);
-		let offer_id =
-			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
-		let current_block = Creditcoin::block_number();
-		let offer = Offer {
-			ask_id: ask_order_id,
-			bid_id: bid_order_id,
-			block: current_block,
-			expiration_block: test_info.expiration_block,
-			lender: test_info.lender.account_id.clone(),
-		};
-		// insert this offer into storage which will use-up the ID
-		// register_deal_order() will reconstruct the same ID later
-		crate::Offers::<Test>::insert_id(offer_id, offer);
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		//  insert as exemption to bypass transfer verification
+		let tx_hash = "0".into_bounded();
+
+		assert_ok!(Creditcoin::register_funding_transfer(
+			Origin::signed(test_info.lender.account_id.clone()),
+			EvmTransferKind::Ethless.into(),
+			deal_order_id.clone(),
+			tx_hash
+		));
+
+		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+
+		// modify transfer in order to cause transfer mismatch
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let mut ts = transfer_storage.as_mut().unwrap();
+			// b/c amount above is 0
+			ts.amount = deal_order.terms.amount;
+			ts.account_id = AccountId::new([4; 32]);
+		});
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				pub_key.into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::DuplicateOffer
+			TestError::TransferAccountMismatch
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_deal_order_id_exists() {
+fn fund_deal_order_should_error_when_transfer_has_been_processed() {
 	ExtBuilder::default().build_and_execute(|| {
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
-
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
-		};
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
 
-		// create DealOrder w/o creating AskOrder, BidOrder & Offer to avoid
-		// erroring out when checking for their existence
-		let ask_order_id = AskOrderId::new::<Test>(test_info.expiration_block, &test_info.ask_guid);
-		let bid_order_id = BidOrderId::new::<Test>(test_info.expiration_block, &test_info.bid_guid);
-		let offer_id =
-			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
-		let deal_order_id = DealOrderId::new::<Test>(test_info.expiration_block, &offer_id);
-
-		let deal_order = DealOrder {
-			offer_id,
-			lender_address_id: test_info.lender.address_id.clone(),
-			borrower_address_id: test_info.borrower.address_id.clone(),
-			terms: test_info.loan_terms.clone(),
-			expiration_block: test_info.expiration_block,
-			timestamp: Creditcoin::timestamp(),
-			borrower: test_info.borrower.account_id,
-			block: Some(Creditcoin::block_number()),
-			funding_transfer_id: None,
-			lock: None,
-			repayment_transfer_id: None,
-		};
-
-		// insert this DealOrder into storage which will use-up the ID
-		// register_deal_order() will reconstruct the same ID later
-		crate::DealOrders::<Test>::insert_id(deal_order_id, deal_order);
+		// modify transfer in order to cause an error
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let mut ts = transfer_storage.as_mut().unwrap();
+			// b/c amount above is 0
+			ts.amount = deal_order.terms.amount;
+			ts.is_processed = true;
+		});
 
 		assert_noop!(
-			Creditcoin::register_deal_order(
+			Creditcoin::fund_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				test_info.lender.address_id,
-				test_info.borrower.address_id,
-				test_info.loan_terms,
-				test_info.expiration_block,
-				test_info.ask_guid,
-				test_info.bid_guid,
-				pub_key.into(),
-				compliance_proof.into(),
+				deal_order_id,
+				transfer_id
 			),
-			TestError::DuplicateDealOrder
+			TestError::TransferAlreadyProcessed
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_succeed() {
+fn fund_deal_order_works() {
 	ExtBuilder::default().build_and_execute(|| {
 		System::set_block_number(1);
 
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
+		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
+		let currency = ethless_currency(contract);
+		let test_info = TestInfo::with_currency(currency);
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
 
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
-		};
+		//  insert as exemption to bypass transfer verification
+		let tx_hash = "0".into_bounded();
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		assert_ok!(Creditcoin::register_funding_transfer(
+			Origin::signed(test_info.lender.account_id.clone()),
+			EvmTransferKind::Ethless.into(),
+			deal_order_id.clone(),
+			tx_hash
+		));
 
-		assert_ok!(Creditcoin::register_deal_order(
+		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+
+		// modify transfer b/c amount above is 0
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			transfer_storage.as_mut().unwrap().amount = deal_order.terms.amount;
+		});
+
+		assert_ok!(Creditcoin::fund_deal_order(
 			Origin::signed(test_info.lender.account_id),
-			test_info.lender.address_id,
-			test_info.borrower.address_id,
-			test_info.loan_terms,
-			test_info.expiration_block,
-			test_info.ask_guid,
-			test_info.bid_guid,
-			pub_key.into(),
-			compliance_proof.into(),
+			deal_order_id.clone(),
+			transfer_id.clone()
 		));
 
 		// assert events in reversed order
 		let mut all_events = <frame_system::Pallet<Test>>::events();
-		let event4 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
-		assert!(matches!(
-			event4,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderAdded(..))
-		));
-
-		let event3 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
-		assert!(matches!(
-			event3,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::OfferAdded(..))
-		));
 
-		let event2 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
-		assert!(matches!(
+		let event2 = all_events.pop().expect("Second EventRecord").event;
+		assert_matches!(
 			event2,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::BidOrderAdded(..))
-		));
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::TransferProcessed(id)) =>{
+				assert_eq!(transfer_id, id)
 
-		let event1 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
-		assert!(matches!(
+			}
+		);
+
+		let event1 = all_events.pop().expect("First EventRecord").event;
+		assert_matches!(
 			event1,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::AskOrderAdded(..))
-		));
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderFunded(id))=>{
+				assert_eq!(deal_order_id, id)
+			}
+		);
 	});
 }
 
 #[test]
-fn register_deal_order_accepts_sr25519() {
-	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let (owners_key_pair, _) = sp_core::sr25519::Pair::generate();
-		let o_pubkey = owners_key_pair.public();
-		let o_signer: MultiSigner = o_pubkey.into();
-		let owners_account = o_signer.into_account();
+fn claim_legacy_wallet_works() {
+	let keeper = AccountId::from([0; 32]);
+	let legacy_amount = 1000000;
+	let sighash =
+		LegacySighash::try_from("f0bdc887e4d7928623081f30b1bc87b9e4443cca6b52c4364ce578cb6bf4")
+			.unwrap();
+	let pub_key = sp_core::ecdsa::Public::from_full(
+		&hex::decode("0399d6e7c784494fd7edc26fc9ca460a68c97cc64c49c85dfbb68148f0607893bf").unwrap(),
+	)
+	.unwrap();
+	let claimer = MultiSigner::from(pub_key).into_account();
 
-		let test_info = {
-			let (b_key_pair, _) = sp_core::ecdsa::Pair::generate();
-			let b_pubkey = b_key_pair.public();
-			let message = get_register_address_message(owners_account.clone());
-			let ownership_proof = b_key_pair.sign(message.as_slice());
-			TestInfo {
-				borrower: RegisteredAddress::from_pubkey_distinct_owner(
-					owners_account,
-					Blockchain::RINKEBY,
-					b_pubkey,
-					ownership_proof,
-				),
-				..TestInfo::new_defaults()
-			}
-		};
+	let mut ext = ExtBuilder::default();
+	ext.fund(keeper.clone(), legacy_amount)
+		.legacy_balance_keeper(keeper)
+		.legacy_wallets(vec![(sighash, legacy_amount)]);
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = owners_key_pair.sign(&message);
+	ext.build_and_execute(|| {
+		System::set_block_number(1);
 
-		assert_ok!(Creditcoin::register_deal_order(
-			Origin::signed(test_info.lender.account_id),
-			test_info.lender.address_id,
-			test_info.borrower.address_id,
-			test_info.loan_terms,
-			test_info.expiration_block,
-			test_info.ask_guid,
-			test_info.bid_guid,
-			o_pubkey.into(),
-			compliance_proof.into(),
+		assert_ok!(Creditcoin::claim_legacy_wallet(Origin::signed(claimer.clone()), pub_key));
+		// assert events in reversed order
+		let mut all_events = <frame_system::Pallet<Test>>::events();
+		let event = all_events.pop().expect("Expected at least one EventRecord to be found").event;
+		assert!(matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::LegacyWalletClaimed(..))
 		));
+		assert_eq!(frame_system::pallet::Account::<Test>::get(&claimer).data.free, 1000000);
 	});
 }
 
 #[test]
-fn register_deal_order_accepts_ed25519() {
+fn add_authority_errors_for_non_root() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let (owners_key_pair, _) = sp_core::ed25519::Pair::generate();
-		let o_pubkey = owners_key_pair.public();
-		let o_signer: MultiSigner = o_pubkey.into();
-		let owners_account = o_signer.clone().into_account();
+		let acct: AccountId = AccountId::new([0; 32]);
 
-		let test_info = {
-			let (b_key_pair, _) = sp_core::ecdsa::Pair::generate();
-			let b_pubkey = b_key_pair.public();
-			let message = get_register_address_message(owners_account.clone());
-			let ownership_proof = b_key_pair.sign(message.as_slice());
-			TestInfo {
-				borrower: RegisteredAddress::from_pubkey_distinct_owner(
-					owners_account,
-					Blockchain::RINKEBY,
-					b_pubkey,
-					ownership_proof,
-				),
-				..TestInfo::new_defaults()
-			}
-		};
+		assert_noop!(Creditcoin::add_authority(Origin::signed(acct.clone()), acct), BadOrigin);
+	});
+}
 
-		let message = test_info.get_register_deal_msg();
-		let compliance_proof = owners_key_pair.sign(&message);
+#[test]
+fn add_authority_should_fail_when_authority_already_exists() {
+	ExtBuilder::default().build_and_execute(|| {
+		let root = RawOrigin::Root;
+		let acct: AccountId = AccountId::new([0; 32]);
 
-		assert_ok!(Creditcoin::register_deal_order(
-			Origin::signed(test_info.lender.account_id),
-			test_info.lender.address_id,
-			test_info.borrower.address_id,
-			test_info.loan_terms,
-			test_info.expiration_block,
-			test_info.ask_guid,
-			test_info.bid_guid,
-			o_signer,
-			compliance_proof.into(),
+		assert_ok!(Creditcoin::add_authority(
+			crate::mock::RuntimeOrigin::from(root.clone()),
+			acct.clone(),
 		));
+
+		// try again
+		assert_noop!(
+			Creditcoin::add_authority(crate::mock::RuntimeOrigin::from(root), acct,),
+			TestError::AlreadyAuthority,
+		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_currency_unregistered() {
+fn register_deal_order_should_error_when_not_signed() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
-
-		let mut test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::new_defaults()
-		};
-		test_info.loan_terms.currency = ethless_currency("0xabab".hex_to_address()).to_id::<Test>();
+		let test_info = TestInfo::new_defaults();
 
+		let (key_pair, _) = sp_core::ecdsa::Pair::generate();
 		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		let signature = key_pair.sign(&message);
 
 		assert_noop!(
 			Creditcoin::register_deal_order(
-				Origin::signed(test_info.lender.account_id),
+				Origin::none(),
 				test_info.lender.address_id,
 				test_info.borrower.address_id,
 				test_info.loan_terms,
 				test_info.expiration_block,
 				test_info.ask_guid,
 				test_info.bid_guid,
-				pub_key.into(),
-				compliance_proof.into(),
+				key_pair.public().into(),
+				signature.into(),
 			),
-			TestError::CurrencyNotRegistered
+			BadOrigin
 		);
 	});
 }
 
 #[test]
-fn register_deal_order_should_error_when_currency_blockchain_mismatch() {
+fn register_deal_order_should_error_when_signature_is_invalid() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
-		let pub_key = key_pair.public();
-
-		let test_info = TestInfo {
-			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
-			..TestInfo::with_currency(match ethless_currency("0xaaaa".hex_to_address()) {
-				Currency::Evm(typ, _) => Currency::Evm(typ, EvmInfo::ETHEREUM),
-			})
-		};
+		let test_info = TestInfo::new_defaults();
 
+		let (key_pair, _) = sp_core::ecdsa::Pair::generate();
+		let (wrong_key, _) = sp_core::ecdsa::Pair::generate();
 		let message = test_info.get_register_deal_msg();
-		let compliance_proof = key_pair.sign(&message);
+		let signature = key_pair.sign(&message);
 
 		assert_noop!(
 			Creditcoin::register_deal_order(
@@ -2065,155 +2136,634 @@ fn register_deal_order_should_error_when_currency_blockchain_mismatch() {
 				test_info.expiration_block,
 				test_info.ask_guid,
 				test_info.bid_guid,
-				pub_key.into(),
-				compliance_proof.into(),
+				wrong_key.public().into(),
+				signature.into(),
 			),
-			TestError::AddressBlockchainMismatch
+			TestError::InvalidSignature
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_not_signed() {
+fn register_deal_order_should_error_when_borrower_address_doesnt_match_signature() {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		let (key_pair, _) = sp_core::ecdsa::Pair::generate();
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(Origin::none(), deal_order_id, transfer_id,),
-			BadOrigin
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id.clone(),
+				test_info.lender.address_id.clone(), // <-- bogus address
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				key_pair.public().into(),
+				compliance_proof.into(),
+			),
+			TestError::NotAddressOwner
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_borrower_address_is_not_registered() {
+fn register_deal_order_should_error_when_lender_address_doesnt_match_sender() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
-
-		// simulate deal with an address that isn't registered
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				let blockchain = Blockchain::RINKEBY;
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(
+				key_pair.public(),
+				Blockchain::RINKEBY,
+				ownership_proof,
+			),
+			..TestInfo::new_defaults()
+		};
 
-				deal_order_storage.as_mut().unwrap().borrower_address_id =
-					AddressId::new::<Test>(&blockchain, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
-			},
-		);
+		let lender = RegisteredAddress::new("lender2", Blockchain::RINKEBY);
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				key_pair.public().into(),
+				compliance_proof.into(),
 			),
-			TestError::NonExistentAddress
+			TestError::NotAddressOwner
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_not_signed_by_borrower() {
+fn register_deal_order_should_error_when_lender_and_borrower_are_on_different_chains() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
+
+		let test_info = TestInfo {
+			lender: RegisteredAddress::new("lender2", Blockchain::ETHEREUM),
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				// bogus signature --------v
+			Creditcoin::register_deal_order(
 				Origin::signed(test_info.lender.account_id),
-				deal_order_id,
-				transfer_id,
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				pub_key.into(),
+				compliance_proof.into(),
 			),
-			TestError::NotBorrower
+			TestError::AddressBlockchainMismatch
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_deal_timestamp_is_in_the_future() {
+fn register_deal_order_should_error_when_ask_order_id_exists() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
 
-		// simulate deal with a timestamp in the future
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().timestamp = Creditcoin::timestamp() + 99999;
-			},
-		);
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+		// create AskOrder which will use-up the default ID
+		test_info.create_ask_order();
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				pub_key.into(),
+				compliance_proof.into(),
 			),
-			TestError::MalformedDealOrder
+			TestError::DuplicateId
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_deal_order_has_already_been_repaid() {
+fn register_deal_order_should_error_when_bid_order_id_exists() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
 
-		// simulate DealOrder which has been repaid
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().repayment_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"4444"));
-			},
-		);
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+
+		// create BidOrder which will use-up the default ID
+		test_info.create_bid_order();
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				key_pair.public().into(),
+				compliance_proof.into(),
 			),
-			TestError::DealOrderAlreadyClosed
+			TestError::DuplicateId
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_deal_isnt_locked() {
+fn register_deal_order_should_error_when_offer_id_exists() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
 
-		// simulate deal which is not locked
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().lock = None;
-			},
-		);
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+
+		// create Offer w/o creating AskOrder & BidOrder to avoid
+		// erroring out when checking for their existence
+		let ask_order_id = AskOrderId::new::<Test>(test_info.expiration_block, &test_info.ask_guid);
+		let bid_order_id = BidOrderId::new::<Test>(test_info.expiration_block, &test_info.bid_guid);
+		let offer_id =
+			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
+		let current_block = Creditcoin::block_number();
+		let offer = Offer {
+			ask_id: ask_order_id,
+			bid_id: bid_order_id,
+			block: current_block,
+			expiration_block: test_info.expiration_block,
+			lender: test_info.lender.account_id.clone(),
+		};
+		// insert this offer into storage which will use-up the ID
+		// register_deal_order() will reconstruct the same ID later
+		crate::Offers::<Test>::insert_id(offer_id, offer);
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				pub_key.into(),
+				compliance_proof.into(),
 			),
-			TestError::DealOrderMustBeLocked
+			TestError::DuplicateOffer
+		);
+	});
+}
+
+#[test]
+fn register_deal_order_should_error_when_deal_order_id_exists() {
+	ExtBuilder::default().build_and_execute(|| {
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
+
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
+
+		// create DealOrder w/o creating AskOrder, BidOrder & Offer to avoid
+		// erroring out when checking for their existence
+		let ask_order_id = AskOrderId::new::<Test>(test_info.expiration_block, &test_info.ask_guid);
+		let bid_order_id = BidOrderId::new::<Test>(test_info.expiration_block, &test_info.bid_guid);
+		let offer_id =
+			OfferId::new::<Test>(test_info.expiration_block, &ask_order_id, &bid_order_id);
+		let deal_order_id = DealOrderId::new::<Test>(test_info.expiration_block, &offer_id);
+
+		let deal_order = DealOrder {
+			offer_id,
+			lender_address_id: test_info.lender.address_id.clone(),
+			borrower_address_id: test_info.borrower.address_id.clone(),
+			terms: test_info.loan_terms.clone(),
+			expiration_block: test_info.expiration_block,
+			timestamp: Creditcoin::timestamp(),
+			borrower: test_info.borrower.account_id,
+			block: Some(Creditcoin::block_number()),
+			funding_transfer_id: None,
+			lock: None,
+			repayment_transfer_id: None,
+			repaid_amount: ExternalAmount::zero(),
+			repayments: Default::default(),
+			accrual: None,
+		};
+
+		// insert this DealOrder into storage which will use-up the ID
+		// register_deal_order() will reconstruct the same ID later
+		crate::DealOrders::<Test>::insert_id(deal_order_id, deal_order);
+
+		assert_noop!(
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				pub_key.into(),
+				compliance_proof.into(),
+			),
+			TestError::DuplicateDealOrder
+		);
+	});
+}
+
+#[test]
+fn register_deal_order_should_succeed() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
+
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
+
+		assert_ok!(Creditcoin::register_deal_order(
+			Origin::signed(test_info.lender.account_id),
+			test_info.lender.address_id,
+			test_info.borrower.address_id,
+			test_info.loan_terms,
+			test_info.expiration_block,
+			test_info.ask_guid,
+			test_info.bid_guid,
+			pub_key.into(),
+			compliance_proof.into(),
+		));
+
+		// assert events in reversed order
+		let mut all_events = <frame_system::Pallet<Test>>::events();
+		let event4 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
+		assert!(matches!(
+			event4,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderAdded(..))
+		));
+
+		let event3 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
+		assert!(matches!(
+			event3,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::OfferAdded(..))
+		));
+
+		let event2 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
+		assert!(matches!(
+			event2,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::BidOrderAdded(..))
+		));
+
+		let event1 = all_events.pop().expect("Expected at least one EventRecord to be found").event;
+		assert!(matches!(
+			event1,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::AskOrderAdded(..))
+		));
+	});
+}
+
+#[test]
+fn register_deal_order_accepts_sr25519() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let (owners_key_pair, _) = sp_core::sr25519::Pair::generate();
+		let o_pubkey = owners_key_pair.public();
+		let o_signer: MultiSigner = o_pubkey.into();
+		let owners_account = o_signer.into_account();
+
+		let test_info = {
+			let (b_key_pair, _) = sp_core::ecdsa::Pair::generate();
+			let b_pubkey = b_key_pair.public();
+			let message = get_register_address_message(owners_account.clone());
+			let ownership_proof = b_key_pair.sign(message.as_slice());
+			TestInfo {
+				borrower: RegisteredAddress::from_pubkey_distinct_owner(
+					owners_account,
+					Blockchain::RINKEBY,
+					b_pubkey,
+					ownership_proof,
+				),
+				..TestInfo::new_defaults()
+			}
+		};
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = owners_key_pair.sign(&message);
+
+		assert_ok!(Creditcoin::register_deal_order(
+			Origin::signed(test_info.lender.account_id),
+			test_info.lender.address_id,
+			test_info.borrower.address_id,
+			test_info.loan_terms,
+			test_info.expiration_block,
+			test_info.ask_guid,
+			test_info.bid_guid,
+			o_pubkey.into(),
+			compliance_proof.into(),
+		));
+	});
+}
+
+#[test]
+fn register_deal_order_accepts_ed25519() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let (owners_key_pair, _) = sp_core::ed25519::Pair::generate();
+		let o_pubkey = owners_key_pair.public();
+		let o_signer: MultiSigner = o_pubkey.into();
+		let owners_account = o_signer.clone().into_account();
+
+		let test_info = {
+			let (b_key_pair, _) = sp_core::ecdsa::Pair::generate();
+			let b_pubkey = b_key_pair.public();
+			let message = get_register_address_message(owners_account.clone());
+			let ownership_proof = b_key_pair.sign(message.as_slice());
+			TestInfo {
+				borrower: RegisteredAddress::from_pubkey_distinct_owner(
+					owners_account,
+					Blockchain::RINKEBY,
+					b_pubkey,
+					ownership_proof,
+				),
+				..TestInfo::new_defaults()
+			}
+		};
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = owners_key_pair.sign(&message);
+
+		assert_ok!(Creditcoin::register_deal_order(
+			Origin::signed(test_info.lender.account_id),
+			test_info.lender.address_id,
+			test_info.borrower.address_id,
+			test_info.loan_terms,
+			test_info.expiration_block,
+			test_info.ask_guid,
+			test_info.bid_guid,
+			o_signer,
+			compliance_proof.into(),
+		));
+	});
+}
+
+#[test]
+fn register_deal_order_should_error_when_currency_unregistered() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
+
+		let mut test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::new_defaults()
+		};
+		test_info.loan_terms.currency = ethless_currency("0xabab".hex_to_address()).to_id::<Test>();
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
+
+		assert_noop!(
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				pub_key.into(),
+				compliance_proof.into(),
+			),
+			TestError::CurrencyNotRegistered
+		);
+	});
+}
+
+#[test]
+fn register_deal_order_should_error_when_currency_blockchain_mismatch() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let (_, _, ownership_proof, key_pair) = generate_address_with_proof("borrower2");
+		let pub_key = key_pair.public();
+
+		let test_info = TestInfo {
+			borrower: RegisteredAddress::from_pubkey(pub_key, Blockchain::RINKEBY, ownership_proof),
+			..TestInfo::with_currency(match ethless_currency("0xaaaa".hex_to_address()) {
+				Currency::Evm(typ, _) => Currency::Evm(typ, EvmInfo::ETHEREUM),
+			})
+		};
+
+		let message = test_info.get_register_deal_msg();
+		let compliance_proof = key_pair.sign(&message);
+
+		assert_noop!(
+			Creditcoin::register_deal_order(
+				Origin::signed(test_info.lender.account_id),
+				test_info.lender.address_id,
+				test_info.borrower.address_id,
+				test_info.loan_terms,
+				test_info.expiration_block,
+				test_info.ask_guid,
+				test_info.bid_guid,
+				pub_key.into(),
+				compliance_proof.into(),
+			),
+			TestError::AddressBlockchainMismatch
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_not_signed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		assert_noop!(
+			Creditcoin::close_deal_order(Origin::none(), deal_order_id, transfer_id,),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_borrower_address_is_not_registered() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		// simulate deal with an address that isn't registered
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				let blockchain = Blockchain::RINKEBY;
+
+				deal_order_storage.as_mut().unwrap().borrower_address_id =
+					AddressId::new::<Test>(&blockchain, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]);
+			},
+		);
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::NonExistentAddress
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_not_signed_by_borrower() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				// bogus signature --------v
+				Origin::signed(test_info.lender.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::NotBorrower
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_deal_timestamp_is_in_the_future() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		// simulate deal with a timestamp in the future
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().timestamp = Creditcoin::timestamp() + 99999;
+			},
+		);
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::MalformedDealOrder
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_deal_order_has_already_been_repaid() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		// simulate DealOrder which has been repaid
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().repayment_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"4444"));
+			},
+		);
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::DealOrderAlreadyClosed
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_deal_isnt_locked() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"12345678");
+
+		// simulate deal which is not locked
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock = None;
+			},
+		);
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::DealOrderMustBeLocked
 		);
 	});
 }
@@ -2224,998 +2774,3226 @@ fn close_deal_order_should_error_when_transfer_order_id_doesnt_match_deal_order_
 		let test_info = TestInfo::new_defaults();
 		let (deal_order_id, _) = test_info.create_deal_order();
 
-		// lock DealOrder
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().lock =
-					Some(test_info.borrower.account_id.clone());
-			},
+		// lock DealOrder
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock =
+					Some(test_info.borrower.account_id.clone());
+			},
+		);
+		// this is a deal_order from another person
+		let second_test_info = TestInfo {
+			lender: RegisteredAddress::new("lender2", Blockchain::RINKEBY),
+			borrower: RegisteredAddress::new("borrower2", Blockchain::RINKEBY),
+			blockchain: Blockchain::RINKEBY,
+			loan_terms: LoanTerms {
+				amount: 2_000_000u64.into(),
+				interest_rate: Default::default(),
+				term_length: Duration::from_millis(1_000_000),
+				currency: CurrencyId::new::<Test>(&Currency::default()),
+			},
+			ask_guid: "second-ask-guid".into_bounded(),
+			bid_guid: "second-bid-guid".into_bounded(),
+			expiration_block: 3_333,
+			currency: Currency::default(),
+		};
+
+		let (bogus_deal_order_id, _) = second_test_info.create_deal_order();
+
+		let (transfer_id, _) =
+			second_test_info.create_repayment_transfer(&bogus_deal_order_id, 33u64);
+
+		// Person1 tries closing the deal by using the transfer made by Person2
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::TransferDealOrderMismatch
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_transfer_block_is_greater_than_current_block() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// lock DealOrder
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock =
+					Some(test_info.borrower.account_id.clone());
+			},
+		);
+
+		let (transfer_id, _) =
+			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
+
+		// modify transfer in order to cause transfer mismatch
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let mut ts = transfer_storage.as_mut().unwrap();
+			// b/c amount above is 0
+			ts.amount = deal_order.terms.amount;
+			ts.block = Creditcoin::block_number() + 1;
+		});
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::MalformedTransfer
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_transfer_sighash_doesnt_match_borrower() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::default();
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// lock DealOrder
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock =
+					Some(test_info.borrower.account_id.clone());
+			},
+		);
+
+		let (transfer_id, _) =
+			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
+
+		// modify transfer in order to cause transfer mismatch
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let mut ts = transfer_storage.as_mut().unwrap();
+			ts.account_id = AccountId::new([44; 32]);
+		});
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::TransferAccountMismatch
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_transfer_has_already_been_processed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::default();
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// lock DealOrder
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock =
+					Some(test_info.borrower.account_id.clone());
+			},
+		);
+
+		let (transfer_id, _) =
+			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
+
+		// modify transfer in order to cause transfer mismatch
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let mut ts = transfer_storage.as_mut().unwrap();
+			// b/c amount above is 0
+			ts.is_processed = true;
+		});
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::TransferAlreadyProcessed
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_succeed() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
+		let currency = ethless_currency(contract);
+
+		let test_info = TestInfo::with_currency(currency);
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// lock DealOrder
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock =
+					Some(test_info.borrower.account_id.clone());
+			},
+		);
+
+		//  insert as exemption to bypass transfer verification
+		let tx_hash = "0".into_bounded();
+
+		assert_ok!(Creditcoin::register_transfer_internal(
+			test_info.borrower.account_id.clone(),
+			test_info.borrower.address_id.clone(),
+			test_info.lender.address_id.clone(),
+			EvmTransferKind::Ethless.into(),
+			33u64.into(),
+			deal_order_id.clone(),
+			tx_hash,
+			&test_info.loan_terms.currency
+		));
+
+		let (transfer_id, _) =
+			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount + 1u64);
+
+		// modify transfer to make sure we have transfered enough funds
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let mut ts = transfer_storage.as_mut().unwrap();
+
+			ts.amount = deal_order.terms.amount + 1u64;
+		});
+
+		// a late repayment still closes the deal order
+		crate::DefaultedDealOrders::<Test>::insert_id(deal_order_id.clone(), 1);
+
+		assert_ok!(Creditcoin::close_deal_order(
+			Origin::signed(test_info.borrower.account_id),
+			deal_order_id.clone(),
+			transfer_id.clone(),
+		));
+
+		// assert field values were updated in storage
+		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(saved_deal_order.repayment_transfer_id, Some(transfer_id.clone()));
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_none());
+
+		let saved_transfer = crate::Pallet::<Test>::transfers(&transfer_id).unwrap();
+		assert!(saved_transfer.is_processed);
+
+		// assert events in reversed order
+		let mut all_events = <frame_system::Pallet<Test>>::events();
+		let event2 = all_events.pop().expect("Second EventRecord").event;
+		assert_matches!(
+			event2,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::TransferProcessed(id)) =>{
+				assert_eq!(id,transfer_id);
+			}
+		);
+
+		let event1 = all_events.pop().expect("First EventRecord").event;
+		assert_matches!(
+			event1,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderClosed(id)) =>{
+				assert_eq!(id,deal_order_id);
+			}
+		);
+	});
+}
+
+#[test]
+fn close_deal_order_should_error_when_transfer_amount_does_not_cover_interest() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::default();
+		let test_info = TestInfo {
+			loan_terms: LoanTerms {
+				interest_rate: InterestRate {
+					rate_per_period: 1,
+					decimals: 1,
+					period: Duration::from_millis(100_000),
+					interest_type: InterestType::Simple,
+				},
+				..test_info.loan_terms
+			},
+			..test_info
+		};
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// lock DealOrder
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().lock =
+					Some(test_info.borrower.account_id.clone());
+			},
+		);
+
+		let (transfer_id, _) =
+			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
+
+		// repaid one and a half periods after the deal started, so two periods of interest are due
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let ts = transfer_storage.as_mut().unwrap();
+			ts.timestamp = Some(deal_order.timestamp + 150_000);
+		});
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id.clone(),
+				transfer_id.clone(),
+			),
+			TestError::TransferAmountInsufficient
+		);
+
+		let amount_due = deal_order.terms.amount + deal_order.terms.amount / 5u64;
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			let ts = transfer_storage.as_mut().unwrap();
+			ts.amount = amount_due;
+		});
+
+		assert_ok!(Creditcoin::close_deal_order(
+			Origin::signed(test_info.borrower.account_id),
+			deal_order_id,
+			transfer_id,
+		));
+	});
+}
+
+#[test]
+fn repay_installment_should_error_when_deal_order_is_not_funded() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		let (transfer_id, _) = test_info.mock_transfer(
+			&test_info.borrower,
+			&test_info.lender,
+			deal_order.terms.amount,
+			&deal_order_id,
+			"0xaa01",
+		);
+
+		assert_noop!(
+			Creditcoin::repay_installment(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::DealNotFunded
+		);
+	});
+}
+
+#[test]
+fn repay_installment_should_track_repayments_until_deal_order_is_closed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// simulate deal transfer
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+			},
+		);
+
+		let installment = deal_order.terms.amount / 4u64;
+		let mut repayments = Vec::new();
+		for tx in ["0xaa01", "0xaa02"] {
+			let (transfer_id, _) = test_info.mock_transfer(
+				&test_info.borrower,
+				&test_info.lender,
+				installment,
+				&deal_order_id,
+				tx,
+			);
+			assert_ok!(Creditcoin::repay_installment(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id.clone(),
+				transfer_id.clone(),
+			));
+			assert!(Creditcoin::transfers(&transfer_id).unwrap().is_processed);
+			repayments.push(transfer_id);
+		}
+
+		let outstanding = deal_order.terms.amount - installment * 2u64;
+
+		let mut all_events = <frame_system::Pallet<Test>>::events();
+		all_events.pop().expect("TransferProcessed EventRecord");
+		let event = all_events.pop().expect("InstallmentRepaid EventRecord").event;
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::InstallmentRepaid(id, transfer_id, amount)) => {
+				assert_eq!(id, deal_order_id);
+				assert_eq!(transfer_id, repayments[1]);
+				assert_eq!(amount, outstanding);
+			}
+		);
+
+		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(saved_deal_order.repaid_amount, installment * 2u64);
+		assert_eq!(saved_deal_order.repayments.into_inner(), repayments);
+
+		assert_ok!(Creditcoin::lock_deal_order(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deal_order_id.clone()
+		));
+
+		let (transfer_id, _) = test_info.mock_transfer(
+			&test_info.borrower,
+			&test_info.lender,
+			outstanding - 1u64,
+			&deal_order_id,
+			"0xaa03",
+		);
+
+		assert_noop!(
+			Creditcoin::close_deal_order(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id.clone(),
+				transfer_id.clone(),
+			),
+			TestError::TransferAmountInsufficient
+		);
+
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			transfer_storage.as_mut().unwrap().amount = outstanding;
+		});
+
+		assert_ok!(Creditcoin::close_deal_order(
+			Origin::signed(test_info.borrower.account_id),
+			deal_order_id.clone(),
+			transfer_id.clone(),
+		));
+
+		repayments.push(transfer_id);
+		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(saved_deal_order.repaid_amount, deal_order.terms.amount);
+		assert_eq!(saved_deal_order.repayments.into_inner(), repayments);
+	});
+}
+
+#[test]
+fn repay_installment_should_accrue_interest_on_outstanding_principal() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::default();
+		let test_info = TestInfo {
+			loan_terms: LoanTerms {
+				interest_rate: InterestRate {
+					rate_per_period: 1,
+					decimals: 1,
+					period: Duration::from_millis(100_000),
+					interest_type: InterestType::Simple,
+				},
+				..test_info.loan_terms
+			},
+			..test_info
+		};
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// simulate deal transfer
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().funding_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+			},
+		);
+
+		let amount = deal_order.terms.amount;
+		let (transfer_id, _) = test_info.mock_transfer(
+			&test_info.borrower,
+			&test_info.lender,
+			amount / 2u64,
+			&deal_order_id,
+			"0xaa01",
+		);
+		// repaid during the first period, the installment pays its interest and then principal
+		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
+			transfer_storage.as_mut().unwrap().timestamp = Some(deal_order.timestamp + 50_000);
+		});
+		assert_ok!(Creditcoin::repay_installment(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deal_order_id.clone(),
+			transfer_id,
+		));
+
+		let principal = amount - (amount / 2u64 - amount / 10u64);
+		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		let amount_due =
+			Creditcoin::deal_order_amount_due(&saved_deal_order, deal_order.timestamp + 150_000)
+				.unwrap();
+
+		// the second period only accrues interest on what is left of the principal
+		assert_eq!(amount_due.principal, principal);
+		assert_eq!(amount_due.interest, principal / 10u64);
+	});
+}
+
+fn funded_deal_with_new_lender(
+	test_info: &TestInfo,
+) -> (TestDealOrderId, RegisteredAddress, sp_core::ecdsa::Pair) {
+	let (deal_order_id, _) = test_info.create_deal_order();
+
+	// simulate deal transfer
+	crate::DealOrders::<Test>::mutate(
+		deal_order_id.expiration(),
+		deal_order_id.hash(),
+		|deal_order_storage| {
+			deal_order_storage.as_mut().unwrap().funding_transfer_id =
+				Some(TransferId::new::<Test>(&test_info.blockchain, b"12345678"));
+		},
+	);
+
+	let (who, address, ownership_proof, key_pair) = generate_address_with_proof("new_lender");
+	let address_id = AddressId::new::<Test>(&test_info.blockchain, &address);
+	assert_ok!(Creditcoin::register_address(
+		Origin::signed(who.clone()),
+		test_info.blockchain.clone(),
+		address,
+		ownership_proof
+	));
+
+	(deal_order_id, RegisteredAddress { address_id, account_id: who }, key_pair)
+}
+
+fn get_assign_deal_msg(
+	deal_order_id: &TestDealOrderId,
+	lender_address_id: &AddressId<H256>,
+	new_lender_address_id: &AddressId<H256>,
+	assignments: u32,
+) -> Vec<u8> {
+	deal_order_id
+		.encode()
+		.into_iter()
+		.chain(lender_address_id.encode())
+		.chain(new_lender_address_id.encode())
+		.chain(assignments.encode())
+		.collect::<Vec<u8>>()
+}
+
+#[test]
+fn assign_deal_order_should_error_for_non_lender() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, new_lender, key_pair) = funded_deal_with_new_lender(&test_info);
+
+		let message = get_assign_deal_msg(
+			&deal_order_id,
+			&test_info.lender.address_id,
+			&new_lender.address_id,
+			0,
+		);
+
+		assert_noop!(
+			Creditcoin::assign_deal_order(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id,
+				new_lender.address_id,
+				key_pair.public().into(),
+				key_pair.sign(&message).into(),
+			),
+			TestError::NotLender
+		);
+	});
+}
+
+#[test]
+fn assign_deal_order_should_error_when_deal_order_is_locked() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, new_lender, key_pair) = funded_deal_with_new_lender(&test_info);
+
+		assert_ok!(Creditcoin::lock_deal_order(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deal_order_id.clone()
+		));
+
+		let message = get_assign_deal_msg(
+			&deal_order_id,
+			&test_info.lender.address_id,
+			&new_lender.address_id,
+			0,
+		);
+
+		assert_noop!(
+			Creditcoin::assign_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				new_lender.address_id,
+				key_pair.public().into(),
+				key_pair.sign(&message).into(),
+			),
+			TestError::DealOrderLocked
+		);
+	});
+}
+
+#[test]
+fn assign_deal_order_should_error_when_signature_is_invalid() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, new_lender, key_pair) = funded_deal_with_new_lender(&test_info);
+
+		let (wrong_key, _) = sp_core::ecdsa::Pair::generate();
+		let message = get_assign_deal_msg(
+			&deal_order_id,
+			&test_info.lender.address_id,
+			&new_lender.address_id,
+			0,
+		);
+
+		assert_noop!(
+			Creditcoin::assign_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				new_lender.address_id,
+				key_pair.public().into(),
+				wrong_key.sign(&message).into(),
+			),
+			TestError::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn assign_deal_order_should_succeed() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, new_lender, key_pair) = funded_deal_with_new_lender(&test_info);
+
+		let message = get_assign_deal_msg(
+			&deal_order_id,
+			&test_info.lender.address_id,
+			&new_lender.address_id,
+			0,
+		);
+
+		assert_ok!(Creditcoin::assign_deal_order(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id.clone(),
+			new_lender.address_id.clone(),
+			key_pair.public().into(),
+			key_pair.sign(&message).into(),
+		));
+
+		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(saved_deal_order.lender_address_id, new_lender.address_id);
+
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("EventRecord").event;
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderAssigned(id, address_id)) => {
+				assert_eq!(id, deal_order_id);
+				assert_eq!(address_id, new_lender.address_id);
+			}
+		);
+
+		// repayments to the previous lender no longer count towards the deal order
+		let (transfer_id, _) = test_info.mock_transfer(
+			&test_info.borrower,
+			&test_info.lender,
+			saved_deal_order.terms.amount,
+			&deal_order_id,
+			"0xaa01",
+		);
+
+		assert_noop!(
+			Creditcoin::repay_installment(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id,
+				transfer_id,
+			),
+			TestError::TransferRecipientMismatch
+		);
+	});
+}
+
+#[test]
+fn assign_deal_order_should_reject_replayed_signature() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, new_lender, new_lender_pair) = funded_deal_with_new_lender(&test_info);
+		let (_, _, _, lender_pair) = generate_address_with_proof("lender");
+
+		let message = get_assign_deal_msg(
+			&deal_order_id,
+			&test_info.lender.address_id,
+			&new_lender.address_id,
+			0,
+		);
+		let signature = new_lender_pair.sign(&message);
+
+		assert_ok!(Creditcoin::assign_deal_order(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id.clone(),
+			new_lender.address_id.clone(),
+			new_lender_pair.public().into(),
+			signature.clone().into(),
+		));
+
+		let message = get_assign_deal_msg(
+			&deal_order_id,
+			&new_lender.address_id,
+			&test_info.lender.address_id,
+			1,
+		);
+		assert_ok!(Creditcoin::assign_deal_order(
+			Origin::signed(new_lender.account_id.clone()),
+			deal_order_id.clone(),
+			test_info.lender.address_id.clone(),
+			lender_pair.public().into(),
+			lender_pair.sign(&message).into(),
+		));
+		assert_eq!(
+			Creditcoin::deal_order_assignments(deal_order_id.expiration(), deal_order_id.hash()),
+			2
+		);
+
+		assert_noop!(
+			Creditcoin::assign_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				new_lender.address_id,
+				new_lender_pair.public().into(),
+				signature.into(),
+			),
+			TestError::InvalidSignature
+		);
+	});
+}
+
+fn funded_deal_order(test_info: &TestInfo) -> TestDealOrderId {
+	let (deal_order_id, _) = test_info.create_deal_order();
+	let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+
+	assert_ok!(Creditcoin::fund_deal_order(
+		Origin::signed(test_info.lender.account_id.clone()),
+		deal_order_id.clone(),
+		transfer_id
+	));
+
+	deal_order_id
+}
+
+#[test]
+fn on_idle_should_mark_unpaid_deal_order_as_defaulted() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		let maturity = Creditcoin::deal_order_maturity(&deal_order);
+
+		// the maturity window of the deal order has not fully elapsed yet
+		Timestamp::set_timestamp(maturity);
+		Creditcoin::on_idle(1, Weight::from_ref_time(u64::MAX));
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_none());
+
+		System::set_block_number(2);
+		Timestamp::set_timestamp(maturity + MATURITY_BUCKET_MILLIS);
+		Creditcoin::on_idle(2, Weight::from_ref_time(u64::MAX));
+		assert_eq!(
+			Creditcoin::defaulted_deal_orders(deal_order_id.expiration(), deal_order_id.hash()),
+			Some(2)
+		);
+
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("EventRecord").event;
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderDefaulted(id)) => {
+				assert_eq!(id, deal_order_id);
+			}
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_not_mark_closed_deal_order_as_defaulted() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		assert_ok!(Creditcoin::exempt(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id.clone()
+		));
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		Timestamp::set_timestamp(
+			Creditcoin::deal_order_maturity(&deal_order) + MATURITY_BUCKET_MILLIS,
+		);
+		Creditcoin::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_none());
+		assert!(crate::DealOrderMaturities::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn exempt_should_clear_default() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		Timestamp::set_timestamp(
+			Creditcoin::deal_order_maturity(&deal_order) + MATURITY_BUCKET_MILLIS,
+		);
+		Creditcoin::on_idle(1, Weight::from_ref_time(u64::MAX));
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_some());
+
+		assert_ok!(Creditcoin::exempt(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id.clone()
+		));
+
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_none());
+	});
+}
+
+#[test]
+fn on_idle_should_not_spend_default_checks_on_empty_maturity_windows() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		// the deal order matures in the first window, two more deal orders in the sixth one
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert!(Creditcoin::deal_order_maturity(&deal_order) < MATURITY_BUCKET_MILLIS);
+		for byte in 1..=2 {
+			let other_deal_order_id =
+				DealOrderId::with_expiration_hash::<Test>(1, H256::repeat_byte(byte));
+			crate::DealOrderMaturities::<Test>::insert(5, &other_deal_order_id, ());
+		}
+
+		Timestamp::set_timestamp(6 * MATURITY_BUCKET_MILLIS);
+		let budget = super::weights::WeightInfo::<Test>::on_idle(2) + Weight::from_ref_time(6);
+		let used = Creditcoin::on_idle(1, budget);
+
+		assert!(used.ref_time() <= budget.ref_time());
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_some());
+		assert_eq!(crate::DealOrderMaturities::<Test>::iter().count(), 1);
+		assert_eq!(crate::NextMaturityBucket::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn exempt_should_error_when_not_signed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		assert_noop!(Creditcoin::exempt(Origin::none(), deal_order_id), BadOrigin);
+	});
+}
+
+#[test]
+fn exempt_should_error_when_deal_order_has_already_been_repaid() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		// simulate DealOrder which has been repaid
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order_storage| {
+				deal_order_storage.as_mut().unwrap().repayment_transfer_id =
+					Some(TransferId::new::<Test>(&test_info.blockchain, b"4444"));
+			},
+		);
+
+		assert_noop!(
+			Creditcoin::exempt(Origin::signed(test_info.lender.account_id), deal_order_id),
+			TestError::DealOrderAlreadyClosed
+		);
+	});
+}
+
+#[test]
+fn exempt_should_error_for_non_lender() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		assert_noop!(
+			Creditcoin::exempt(Origin::signed(test_info.borrower.account_id), deal_order_id),
+			TestError::NotLender
+		);
+	});
+}
+
+#[test]
+fn exempt_should_succeed() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		assert_ok!(Creditcoin::exempt(
+			Origin::signed(test_info.lender.account_id),
+			deal_order_id.clone()
+		));
+
+		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"0");
+
+		// assert field values were updated in storage
+		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(saved_deal_order.repayment_transfer_id, Some(transfer_id));
+
+		// assert events in reversed order
+		let mut all_events = <frame_system::Pallet<Test>>::events();
+		let event = all_events.pop().expect("Expected at least one EventRecord to be found").event;
+		assert_eq!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::LoanExempted(deal_order_id))
+		);
+	});
+}
+
+#[test]
+fn credit_history_should_track_funded_and_exempted_loans() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let borrower = test_info.borrower.account_id.clone();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let history = Creditcoin::credit_history(&borrower);
+		assert_eq!(history.record, CreditRecord { loans_taken: 1, ..Default::default() });
+		assert_eq!(
+			history.volume,
+			vec![(test_info.loan_terms.currency.clone(), test_info.loan_terms.amount)]
+		);
+
+		assert_ok!(Creditcoin::exempt(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id
+		));
+
+		assert_eq!(
+			Creditcoin::credit_records(&borrower),
+			CreditRecord { loans_taken: 1, exempted: 1, ..Default::default() }
+		);
+	});
+}
+
+#[test]
+fn credit_history_should_track_defaulted_loans() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		Timestamp::set_timestamp(
+			Creditcoin::deal_order_maturity(&deal_order) + MATURITY_BUCKET_MILLIS,
+		);
+		Creditcoin::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		assert_eq!(
+			Creditcoin::credit_records(&test_info.borrower.account_id),
+			CreditRecord { loans_taken: 1, defaulted: 1, ..Default::default() }
+		);
+	});
+}
+
+#[test]
+fn record_repayment_should_distinguish_late_repayments() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		let maturity = Creditcoin::deal_order_maturity(&deal_order);
+		Creditcoin::record_repayment(&deal_order, maturity);
+		Creditcoin::record_repayment(&deal_order, maturity + 1);
+
+		assert_eq!(
+			Creditcoin::credit_records(&test_info.borrower.account_id),
+			CreditRecord {
+				loans_taken: 1,
+				repaid_on_time: 1,
+				repaid_late: 1,
+				..Default::default()
+			}
+		);
+	});
+}
+
+fn get_renegotiation_msg(
+	deal_order_id: &TestDealOrderId,
+	amendment_count: u32,
+	term_length: &Duration,
+	interest_rate: &InterestRate,
+) -> Vec<u8> {
+	deal_order_id
+		.encode()
+		.into_iter()
+		.chain(amendment_count.encode())
+		.chain(term_length.encode())
+		.chain(interest_rate.encode())
+		.collect::<Vec<u8>>()
+}
+
+fn extended_terms(test_info: &TestInfo) -> (Duration, InterestRate) {
+	let term_length = Duration::from_millis(
+		test_info.loan_terms.term_length.as_millis() as u64 + 10 * MATURITY_BUCKET_MILLIS,
+	);
+	let interest_rate = InterestRate { rate_per_period: 5, ..Default::default() };
+	(term_length, interest_rate)
+}
+
+#[test]
+fn renegotiate_deal_order_should_succeed() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+		let old_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		let old_maturity = Creditcoin::deal_order_maturity(&old_deal_order);
+
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (_, _, _, borrower_key) = generate_address_with_proof("borrower");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+
+		assert_ok!(Creditcoin::renegotiate_deal_order(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id.clone(),
+			term_length,
+			interest_rate.clone(),
+			borrower_key.public().into(),
+			borrower_key.sign(&message).into(),
+		));
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		assert_eq!(deal_order.terms.term_length, term_length);
+		assert_eq!(deal_order.terms.interest_rate, interest_rate);
+		assert_eq!(deal_order.terms.amount, old_deal_order.terms.amount);
+
+		let amendments =
+			Creditcoin::deal_order_amendments(deal_order_id.expiration(), deal_order_id.hash());
+		assert_eq!(amendments.len(), 1);
+		assert_eq!(amendments[0].term_length, old_deal_order.terms.term_length);
+		assert_eq!(amendments[0].interest_rate, old_deal_order.terms.interest_rate);
+		assert_eq!(amendments[0].block, 1);
+
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("EventRecord").event;
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderRenegotiated(id, terms)) => {
+				assert_eq!(id, deal_order_id);
+				assert_eq!(terms, deal_order.terms);
+			}
+		);
+
+		// the deal order is no longer checked for default at its original maturity
+		Timestamp::set_timestamp(old_maturity + MATURITY_BUCKET_MILLIS);
+		Creditcoin::on_idle(1, Weight::from_ref_time(u64::MAX));
+		assert!(Creditcoin::defaulted_deal_orders(
+			deal_order_id.expiration(),
+			deal_order_id.hash()
+		)
+		.is_none());
+		assert!(crate::DealOrderMaturities::<Test>::iter().next().is_some());
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_accept_lender_signature_from_borrower() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (_, _, _, lender_key) = generate_address_with_proof("lender");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+
+		assert_ok!(Creditcoin::renegotiate_deal_order(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deal_order_id.clone(),
+			term_length,
+			interest_rate.clone(),
+			lender_key.public().into(),
+			lender_key.sign(&message).into(),
+		));
+
+		// a signature cannot be replayed once the amendment has been recorded
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				deal_order_id,
+				term_length,
+				interest_rate,
+				lender_key.public().into(),
+				lender_key.sign(&message).into(),
+			),
+			TestError::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_error_for_non_participant() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (outsider, _, _, outsider_key) = generate_address_with_proof("outsider");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(outsider),
+				deal_order_id.clone(),
+				term_length,
+				interest_rate.clone(),
+				outsider_key.public().into(),
+				outsider_key.sign(&message).into(),
+			),
+			TestError::NotDealParticipant
+		);
+
+		// the counterparty's signature must come from the other party of the deal order
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				term_length,
+				interest_rate,
+				outsider_key.public().into(),
+				outsider_key.sign(&message).into(),
+			),
+			TestError::NotDealParticipant
+		);
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_error_when_signature_is_invalid() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (_, _, _, borrower_key) = generate_address_with_proof("borrower");
+		let message = get_renegotiation_msg(
+			&deal_order_id,
+			0,
+			&term_length,
+			&test_info.loan_terms.interest_rate,
+		);
+
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				term_length,
+				interest_rate,
+				borrower_key.public().into(),
+				borrower_key.sign(&message).into(),
+			),
+			TestError::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_error_when_term_length_is_shortened() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let term_length =
+			Duration::from_millis(test_info.loan_terms.term_length.as_millis() as u64 - 1);
+		let interest_rate = test_info.loan_terms.interest_rate.clone();
+		let (_, _, _, borrower_key) = generate_address_with_proof("borrower");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				term_length,
+				interest_rate,
+				borrower_key.public().into(),
+				borrower_key.sign(&message).into(),
+			),
+			TestError::TermLengthShortened
+		);
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_error_when_deal_order_is_not_funded() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (_, _, _, borrower_key) = generate_address_with_proof("borrower");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				term_length,
+				interest_rate,
+				borrower_key.public().into(),
+				borrower_key.sign(&message).into(),
+			),
+			TestError::DealNotFunded
+		);
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_error_when_deal_order_has_defaulted() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		Timestamp::set_timestamp(
+			Creditcoin::deal_order_maturity(&deal_order) + MATURITY_BUCKET_MILLIS,
+		);
+		Creditcoin::on_idle(1, Weight::from_ref_time(u64::MAX));
+
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (_, _, _, borrower_key) = generate_address_with_proof("borrower");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+
+		assert_noop!(
+			Creditcoin::renegotiate_deal_order(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deal_order_id,
+				term_length,
+				interest_rate,
+				borrower_key.public().into(),
+				borrower_key.sign(&message).into(),
+			),
+			TestError::DealOrderDefaulted
+		);
+	});
+}
+
+#[test]
+fn renegotiate_deal_order_should_apply_new_rate_from_renegotiation() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let deal_order_id = funded_deal_order(&test_info);
+
+		// 10% per period until the renegotiation
+		crate::DealOrders::<Test>::mutate(
+			deal_order_id.expiration(),
+			deal_order_id.hash(),
+			|deal_order| {
+				deal_order.as_mut().unwrap().terms.interest_rate =
+					InterestRate { rate_per_period: 1, ..Default::default() };
+			},
+		);
+		let deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
+		let period = deal_order.terms.interest_rate.period.as_millis() as u64;
+		Timestamp::set_timestamp(deal_order.timestamp + 2 * period);
+
+		// 50% per period afterwards
+		let (term_length, interest_rate) = extended_terms(&test_info);
+		let (_, _, _, borrower_key) = generate_address_with_proof("borrower");
+		let message = get_renegotiation_msg(&deal_order_id, 0, &term_length, &interest_rate);
+		assert_ok!(Creditcoin::renegotiate_deal_order(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deal_order_id.clone(),
+			term_length,
+			interest_rate,
+			borrower_key.public().into(),
+			borrower_key.sign(&message).into(),
+		));
+
+		let amount_due =
+			Creditcoin::amount_due(&deal_order_id, deal_order.timestamp + 3 * period).unwrap();
+		assert_eq!(amount_due.principal, test_info.loan_terms.amount);
+		assert_eq!(amount_due.interest, ExternalAmount::from(2_000_000u64 + 5_000_000u64));
+	});
+}
+
+#[test]
+fn verify_transfer_should_error_when_not_signed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let (transfer_id, transfer) = test_info.create_funding_transfer(&deal_order_id);
+		let deadline = Test::unverified_transfer_deadline();
+		assert_noop!(
+			Creditcoin::persist_task_output(
+				Origin::none(),
+				deadline,
+				(transfer_id, transfer).into()
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn verify_transfer_should_error_when_signer_not_authorized() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let (transfer_id, transfer) = test_info.create_funding_transfer(&deal_order_id);
+		let deadline = Test::unverified_transfer_deadline();
+		assert_noop!(
+			Creditcoin::persist_task_output(
+				Origin::signed(test_info.lender.account_id),
+				deadline,
+				(transfer_id, transfer).into(),
+			),
+			TestError::InsufficientAuthority,
+		);
+	});
+}
+
+#[test]
+fn verify_transfer_should_error_when_transfer_has_already_been_registered() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+
+		// authorize lender
+		let root = RawOrigin::Root;
+		assert_ok!(Creditcoin::add_authority(
+			crate::mock::RuntimeOrigin::from(root),
+			test_info.lender.account_id.clone(),
+		));
+
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let (transfer_id, transfer) = test_info.create_funding_transfer(&deal_order_id);
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_noop!(
+			Creditcoin::persist_task_output(
+				Origin::signed(test_info.lender.account_id),
+				deadline,
+				(transfer_id, transfer).into(),
+			),
+			non_paying_error(TestError::TransferAlreadyRegistered),
+		);
+	});
+}
+
+#[test]
+fn verify_transfer_should_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let test_info = TestInfo::new_defaults();
+
+		// authorize lender
+		let root = RawOrigin::Root;
+		assert_ok!(Creditcoin::add_authority(
+			crate::mock::RuntimeOrigin::from(root),
+			test_info.lender.account_id.clone(),
+		));
+
+		let (deal_order_id, deal_order) = test_info.create_deal_order();
+
+		// create a transfer but don't add it into storage
+		let tx = "0xafafaf".hex_to_address();
+		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+		let transfer = Transfer {
+			blockchain: test_info.blockchain.clone(),
+			kind: TransferKind::Evm(EvmTransferKind::Ethless),
+			from: test_info.lender.address_id.clone(),
+			to: test_info.borrower.address_id.clone(),
+			deal_order_id,
+			amount: deal_order.terms.amount,
+			tx_id: tx,
+			block: System::block_number(),
+			is_processed: false,
+			account_id: test_info.lender.account_id.clone(),
+			timestamp: None,
+		};
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(test_info.lender.account_id),
+			deadline,
+			(transfer_id.clone(), transfer.clone()).into(),
+		));
+
+		let mut all_events = <frame_system::Pallet<Test>>::events();
+
+		// assert events in reversed order
+		let last_event = all_events.pop().expect("At least one EventRecord").event;
+		assert_matches!(
+			last_event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::TransferVerified(id)) => {
+				assert_eq!(transfer_id, id)
+			}
+		);
+
+		assert_eq!(Transfers::<Test>::get(&transfer_id), Some(transfer));
+	});
+}
+
+fn set_up_quorum_test(test_info: &TestInfo) -> TestTransfer {
+	let root = RawOrigin::Root;
+	for authority in [&test_info.lender.account_id, &test_info.borrower.account_id] {
+		assert_ok!(Creditcoin::add_authority(Origin::from(root.clone()), authority.clone()));
+	}
+	assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(root), 2));
+
+	let (deal_order_id, deal_order) = test_info.create_deal_order();
+	let tx = "0xafafaf".hex_to_address();
+	let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+	let transfer = Transfer {
+		blockchain: test_info.blockchain.clone(),
+		kind: TransferKind::Evm(EvmTransferKind::Ethless),
+		from: test_info.lender.address_id.clone(),
+		to: test_info.borrower.address_id.clone(),
+		deal_order_id,
+		amount: deal_order.terms.amount,
+		tx_id: tx,
+		block: System::block_number(),
+		is_processed: false,
+		account_id: test_info.lender.account_id.clone(),
+		timestamp: None,
+	};
+	(transfer_id, transfer)
+}
+
+fn creditcoin_events() -> Vec<crate::Event<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			crate::mock::RuntimeEvent::Creditcoin(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn verify_transfer_with_quorum_should_wait_for_threshold() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, transfer) = set_up_quorum_test(&test_info);
+		let task_id = crate::TaskId::from(transfer_id.clone());
+		let deadline = Test::unverified_transfer_deadline();
+		let output = crate::TaskOutput::from((transfer_id.clone(), transfer.clone()));
+		let vote = <Test as frame_system::Config>::Hashing::hash_of(&output);
+
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deadline,
+			output.clone(),
+		));
+		assert_eq!(Transfers::<Test>::get(&transfer_id), None);
+		assert_eq!(
+			creditcoin_events().pop(),
+			Some(crate::Event::TaskOutputVoted(
+				task_id.clone(),
+				test_info.lender.account_id.clone(),
+				vote
+			))
+		);
+
+		assert_noop!(
+			Creditcoin::persist_task_output(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deadline,
+				output.clone(),
+			),
+			non_paying_error(TestError::AlreadyVoted),
+		);
+
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deadline,
+			output,
+		));
+		assert_eq!(Transfers::<Test>::get(&transfer_id), Some(transfer));
+		assert_eq!(crate::TaskOutputVotes::<Test>::iter_prefix((deadline,)).count(), 2);
+		assert_eq!(Creditcoin::task_outcomes(deadline, &task_id), Some(vote));
+
+		let mut events = creditcoin_events();
+		assert_eq!(events.pop(), Some(crate::Event::TransferVerified(transfer_id)));
+		assert_eq!(events.pop(), Some(crate::Event::TaskOutputQuorumReached(task_id, vote)));
+	});
+}
+
+#[test]
+fn verify_transfer_with_quorum_should_report_disagreements() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, transfer) = set_up_quorum_test(&test_info);
+		let task_id = crate::TaskId::from(transfer_id.clone());
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deadline,
+			(transfer_id.clone(), transfer.clone()).into(),
+		));
+
+		let bogus = Transfer { amount: transfer.amount + 1, ..transfer };
+		let bogus_output = crate::TaskOutput::from((transfer_id.clone(), bogus));
+		let bogus_vote = <Test as frame_system::Config>::Hashing::hash_of(&bogus_output);
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deadline,
+			bogus_output,
+		));
+		assert_eq!(Transfers::<Test>::get(&transfer_id), None);
+
+		let borrower = test_info.borrower.account_id;
+		let mut events = creditcoin_events();
+		assert_eq!(
+			events.pop(),
+			Some(crate::Event::TaskOutputVoted(task_id.clone(), borrower.clone(), bogus_vote))
+		);
+		assert_eq!(
+			events.pop(),
+			Some(crate::Event::TaskOutputDisputed(task_id, borrower, bogus_vote))
+		);
+	});
+}
+
+#[test]
+fn task_output_votes_should_be_cleared_after_retention() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, transfer) = set_up_quorum_test(&test_info);
+		let task_id = crate::TaskId::from(transfer_id.clone());
+		let deadline = Test::unverified_transfer_deadline();
+
+		for authority in [&test_info.lender.account_id, &test_info.borrower.account_id] {
+			assert_ok!(Creditcoin::persist_task_output(
+				Origin::signed(authority.clone()),
+				deadline,
+				(transfer_id.clone(), transfer.clone()).into(),
+			));
+		}
+
+		Creditcoin::on_initialize(deadline);
+		assert_eq!(crate::TaskOutputVotes::<Test>::iter_prefix((deadline,)).count(), 2);
+		assert!(Creditcoin::task_outcomes(deadline, &task_id).is_some());
+
+		let retention: BlockNumber = <Test as Config>::TaskStatusRetention::get();
+		Creditcoin::on_initialize(deadline + retention);
+		assert_eq!(crate::TaskOutputVotes::<Test>::iter_prefix((deadline,)).count(), 0);
+		assert_eq!(Creditcoin::task_outcomes(deadline, &task_id), None);
+	});
+}
+
+#[test]
+fn fail_task_should_fail_once_quorum_is_reached() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, _) = set_up_quorum_test(&test_info);
+		let third = AccountId::new([3; 32]);
+		<Test as Config>::TaskScheduler::insert_authority(&third);
+		let task_id = crate::TaskId::from(transfer_id);
+		let deadline = Test::unverified_transfer_deadline();
+		let cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
+
+		for authority in [&test_info.lender.account_id, &test_info.borrower.account_id] {
+			assert_ok!(Creditcoin::fail_task(
+				Origin::signed(authority.clone()),
+				deadline,
+				task_id.clone(),
+				cause
+			));
+		}
+
+		assert_noop!(
+			Creditcoin::fail_task(Origin::signed(third), deadline, task_id, cause),
+			non_paying_error(TestError::TaskAlreadyDecided),
+		);
+	});
+}
+
+#[test]
+fn fail_task_with_quorum_should_wait_for_threshold() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, _) = set_up_quorum_test(&test_info);
+		let task_id = crate::TaskId::from(transfer_id.clone());
+		let deadline = Test::unverified_transfer_deadline();
+		let cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
+		let vote = <Test as frame_system::Config>::Hashing::hash_of(&(&task_id, cause));
+
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deadline,
+			task_id.clone(),
+			cause
+		));
+		assert_eq!(Creditcoin::task_statuses(&task_id), None);
+		assert_eq!(
+			creditcoin_events().pop(),
+			Some(crate::Event::TaskOutputVoted(
+				task_id.clone(),
+				test_info.lender.account_id.clone(),
+				vote
+			))
+		);
+
+		assert_noop!(
+			Creditcoin::fail_task(
+				Origin::signed(test_info.lender.account_id.clone()),
+				deadline,
+				task_id.clone(),
+				cause
+			),
+			non_paying_error(TestError::AlreadyVoted),
+		);
+
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deadline,
+			task_id.clone(),
+			cause
+		));
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Failed(cause)));
+
+		let mut events = creditcoin_events();
+		assert_eq!(
+			events.pop(),
+			Some(crate::Event::TransferFailedVerification(transfer_id, cause))
+		);
+		assert_eq!(events.pop(), Some(crate::Event::TaskOutputQuorumReached(task_id, vote)));
+	});
+}
+
+#[test]
+fn fail_task_with_quorum_should_dispute_persisted_output() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, transfer) = set_up_quorum_test(&test_info);
+		let task_id = crate::TaskId::from(transfer_id.clone());
+		let deadline = Test::unverified_transfer_deadline();
+		let cause = crate::ocw::errors::VerificationFailureCause::IncorrectAmount;
+
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(test_info.lender.account_id.clone()),
+			deadline,
+			(transfer_id.clone(), transfer).into(),
+		));
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(test_info.borrower.account_id.clone()),
+			deadline,
+			task_id.clone(),
+			cause
+		));
+
+		assert_eq!(Transfers::<Test>::get(&transfer_id), None);
+		assert_eq!(Creditcoin::task_statuses(&task_id), None);
+		let vote = <Test as frame_system::Config>::Hashing::hash_of(&(&task_id, cause));
+		let borrower = test_info.borrower.account_id;
+		let mut events = creditcoin_events();
+		events.pop();
+		assert_eq!(events.pop(), Some(crate::Event::TaskOutputDisputed(task_id, borrower, vote)));
+	});
+}
+
+#[test]
+fn fail_transfer_should_work() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let test_info = TestInfo::new_defaults();
+
+		let root = RawOrigin::Root;
+		assert_ok!(Creditcoin::add_authority(
+			crate::mock::RuntimeOrigin::from(root),
+			test_info.lender.account_id.clone(),
+		));
+
+		let _ = test_info.create_deal_order();
+
+		let tx = "0xafafaf".hex_to_address();
+		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+
+		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(test_info.lender.account_id),
+			deadline,
+			transfer_id.clone().into(),
+			failure_cause
+		));
+
+		let mut all_events = System::events();
+
+		assert_matches!(
+			all_events.pop().unwrap().event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::<Test>::TransferFailedVerification(id, cause)) => {
+				assert_eq!(id, transfer_id);
+				assert_eq!(cause, failure_cause);
+			}
+		);
+	})
+}
+
+#[test]
+fn fail_transfer_should_error_when_not_signed() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let test_info = TestInfo::new_defaults();
+
+		let _ = test_info.create_deal_order();
+
+		let tx = "0xafafaf".hex_to_address();
+		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+
+		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_noop!(
+			Creditcoin::fail_task(Origin::none(), deadline, transfer_id.into(), failure_cause),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn fail_transfer_should_error_when_not_authority() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let test_info = TestInfo::new_defaults();
+
+		let _ = test_info.create_deal_order();
+
+		let tx = "0xafafaf".hex_to_address();
+		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+
+		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_noop!(
+			Creditcoin::fail_task(
+				Origin::signed(test_info.lender.account_id),
+				deadline,
+				transfer_id.into(),
+				failure_cause
+			),
+			TestError::InsufficientAuthority
+		);
+	})
+}
+
+#[test]
+fn fail_transfer_should_error_when_transfer_registered() {
+	ExtBuilder::default().build_and_execute(|| {
+		System::set_block_number(1);
+
+		let test_info = TestInfo::new_defaults();
+
+		let (deal_order_id, _) = test_info.create_deal_order();
+
+		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
+
+		let root = RawOrigin::Root;
+		assert_ok!(Creditcoin::add_authority(
+			crate::mock::RuntimeOrigin::from(root),
+			test_info.lender.account_id.clone(),
+		));
+
+		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_noop!(
+			Creditcoin::fail_task(
+				Origin::signed(test_info.lender.account_id),
+				deadline,
+				transfer_id.into(),
+				failure_cause
+			),
+			TestError::TransferAlreadyRegistered
 		);
-		// this is a deal_order from another person
-		let second_test_info = TestInfo {
-			lender: RegisteredAddress::new("lender2", Blockchain::RINKEBY),
-			borrower: RegisteredAddress::new("borrower2", Blockchain::RINKEBY),
-			blockchain: Blockchain::RINKEBY,
-			loan_terms: LoanTerms {
-				amount: 2_000_000u64.into(),
-				interest_rate: Default::default(),
-				term_length: Duration::from_millis(1_000_000),
-				currency: CurrencyId::new::<Test>(&Currency::default()),
-			},
-			ask_guid: "second-ask-guid".into_bounded(),
-			bid_guid: "second-bid-guid".into_bounded(),
-			expiration_block: 3_333,
-			currency: Currency::default(),
-		};
+	})
+}
 
-		let (bogus_deal_order_id, _) = second_test_info.create_deal_order();
+#[test]
+fn on_initialize_removes_expired_deals_without_transfers() {
+	ExtBuilder::default().build_offchain_and_execute(|| {
+		System::set_block_number(1);
+		let mut expected_deal_orders = Vec::new();
+
+		let now = System::block_number();
+		for expiration_block in now..=20 {
+			let seed1 = format!("{:02}0", expiration_block.clone());
+			let seed2 = format!("{:02}1", expiration_block.clone());
+
+			let test_info = TestInfo {
+				lender: RegisteredAddress::new(&seed1, Blockchain::RINKEBY),
+				borrower: RegisteredAddress::new(&seed2, Blockchain::RINKEBY),
+				blockchain: Blockchain::RINKEBY,
+				loan_terms: LoanTerms {
+					amount: 2_000_000u64.into(),
+					interest_rate: Default::default(),
+					term_length: Duration::from_millis(1_000_000),
+					currency: CurrencyId::new::<Test>(&Currency::default()),
+				},
+				ask_guid: format!("{:?}-ask-guid", expiration_block.clone())
+					.as_bytes()
+					.into_bounded(),
+				bid_guid: format!("{:?}-bid-guid", expiration_block.clone())
+					.as_bytes()
+					.into_bounded(),
+				expiration_block,
+				currency: Currency::default(),
+			};
+
+			let (offer_id, _) = test_info.create_offer();
+			assert_ok!(Creditcoin::add_deal_order(
+				Origin::signed(test_info.borrower.account_id.clone()),
+				offer_id.clone(),
+				expiration_block,
+			));
+			let deal_order_id = DealOrderId::new::<Test>(expiration_block, &offer_id);
+			let deal_order =
+				Creditcoin::deal_orders(deal_order_id.expiration(), deal_order_id.hash()).unwrap();
+
+			// fund only deal orders which expire at even blocks
+			if expiration_block % 2 == 0 {
+				let tx = format!("0xfafafa{:02}", expiration_block.clone());
+				assert_ok!(Creditcoin::register_funding_transfer(
+					Origin::signed(test_info.lender.account_id.clone()),
+					EvmTransferKind::Ethless.into(),
+					deal_order_id.clone(),
+					tx.hex_to_address()
+				));
+				let (transfer_id, _) = test_info.mock_transfer(
+					&test_info.lender,
+					&test_info.borrower,
+					deal_order.terms.amount,
+					&deal_order_id,
+					tx,
+				);
+
+				// attach transfer to deal order
+				assert_ok!(Creditcoin::fund_deal_order(
+					Origin::signed(test_info.lender.account_id.clone()),
+					deal_order_id.clone(),
+					transfer_id.clone()
+				));
+				// it's funded so it should be kept
+				expected_deal_orders.push(deal_order_id.clone());
+			} else if expiration_block > 15 {
+				// still hasn't expired so should be kept regardless
+				expected_deal_orders.push(deal_order_id.clone());
+			}
+		}
 
-		let (transfer_id, _) =
-			second_test_info.create_repayment_transfer(&bogus_deal_order_id, 33u64);
+		// advance blocks, will perform housekeeping
+		roll_to(15);
 
-		// Person1 tries closing the deal by using the transfer made by Person2
-		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
-			),
-			TestError::TransferDealOrderMismatch
-		);
+		for expected_order_id in expected_deal_orders.iter() {
+			let _order = DealOrders::<Test>::try_get_id(&expected_order_id).unwrap();
+		}
 	});
 }
 
-#[test]
-fn close_deal_order_should_error_when_transfer_block_is_greater_than_current_block() {
+enum TransferVersion {
+	Legacy,
+	New,
+}
+
+fn register_funding_transfer_should_error_when_not_signed(version: TransferVersion) {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		// lock DealOrder
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().lock =
-					Some(test_info.borrower.account_id.clone());
-			},
-		);
-
-		let (transfer_id, _) =
-			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
+		let (deal_order_id, _) = test_info.create_deal_order();
+		let tx = "0xabcabcabca";
 
-		// modify transfer in order to cause transfer mismatch
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			let mut ts = transfer_storage.as_mut().unwrap();
-			// b/c amount above is 0
-			ts.amount = deal_order.terms.amount;
-			ts.block = Creditcoin::block_number() + 1;
-		});
+		let tx = tx.hex_to_address();
+		let origin = Origin::none();
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
-			),
-			TestError::MalformedTransfer
+			match version {
+				TransferVersion::New => Creditcoin::register_funding_transfer(
+					origin,
+					EvmTransferKind::Ethless.into(),
+					deal_order_id,
+					tx,
+				),
+				TransferVersion::Legacy => Creditcoin::register_funding_transfer_legacy(
+					origin,
+					LegacyTransferKind::Native,
+					deal_order_id,
+					tx,
+				),
+			},
+			BadOrigin
 		);
 	});
 }
 
 #[test]
-fn close_deal_order_should_error_when_transfer_sighash_doesnt_match_borrower() {
-	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::default();
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
+fn register_funding_transfer_new_should_error_when_not_signed() {
+	register_funding_transfer_should_error_when_not_signed(TransferVersion::New);
+}
 
-		// lock DealOrder
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().lock =
-					Some(test_info.borrower.account_id.clone());
-			},
-		);
+#[test]
+fn register_funding_transfer_legacy_should_error_when_not_signed() {
+	register_funding_transfer_should_error_when_not_signed(TransferVersion::Legacy);
+}
 
-		let (transfer_id, _) =
-			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
+fn register_funding_transfer_should_error_when_not_deal_order_not_found(version: TransferVersion) {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (_, deal_order) = test_info.create_deal_order();
+		let DealOrder { offer_id, .. } = deal_order;
+		// expiration_block set to 0
+		let deal_order_id = DealOrderId::new::<Test>(0, &offer_id);
 
-		// modify transfer in order to cause transfer mismatch
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			let mut ts = transfer_storage.as_mut().unwrap();
-			ts.account_id = AccountId::new([44; 32]);
-		});
+		let tx = "0xabcabcabca".hex_to_address();
+		let origin = Origin::signed(test_info.lender.account_id);
 
 		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
-			),
-			TestError::TransferAccountMismatch
+			match version {
+				TransferVersion::Legacy => Creditcoin::register_funding_transfer_legacy(
+					origin,
+					LegacyTransferKind::Native,
+					deal_order_id,
+					tx
+				),
+				TransferVersion::New => Creditcoin::register_funding_transfer(
+					origin,
+					EvmTransferKind::Ethless.into(),
+					deal_order_id,
+					tx
+				),
+			},
+			TestError::NonExistentDealOrder
 		);
-	});
+	})
 }
 
 #[test]
-fn close_deal_order_should_error_when_transfer_has_already_been_processed() {
-	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::default();
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		// lock DealOrder
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().lock =
-					Some(test_info.borrower.account_id.clone());
-			},
-		);
-
-		let (transfer_id, _) =
-			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount);
-
-		// modify transfer in order to cause transfer mismatch
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			let mut ts = transfer_storage.as_mut().unwrap();
-			// b/c amount above is 0
-			ts.is_processed = true;
-		});
+fn register_funding_transfer_legacy_should_error_when_not_deal_order_not_found() {
+	register_funding_transfer_should_error_when_not_deal_order_not_found(TransferVersion::Legacy);
+}
 
-		assert_noop!(
-			Creditcoin::close_deal_order(
-				Origin::signed(test_info.borrower.account_id),
-				deal_order_id,
-				transfer_id,
-			),
-			TestError::TransferAlreadyProcessed
-		);
-	});
+#[test]
+fn register_funding_transfer_new_should_error_when_not_deal_order_not_found() {
+	register_funding_transfer_should_error_when_not_deal_order_not_found(TransferVersion::New);
 }
 
 #[test]
-fn close_deal_order_should_succeed() {
+fn register_funding_transfer_should_accept_erc20_transfers_of_erc20_currency() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-		let contract = "0x0ad1439a0e0bfdcd49939f9722866651a4aa9b3c".hex_to_address();
-		let currency = ethless_currency(contract);
-
-		let test_info = TestInfo::with_currency(currency);
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
-
-		// lock DealOrder
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().lock =
-					Some(test_info.borrower.account_id.clone());
-			},
+		let currency = Currency::Evm(
+			EvmCurrencyType::SmartContract(
+				"0xaaaa".hex_to_address(),
+				[EvmTransferKind::Erc20].into_bounded(),
+			),
+			EvmInfo::RINKEBY,
 		);
+		let test_info = TestInfo::with_currency(currency);
+		let (deal_order_id, _) = test_info.create_deal_order();
 
-		//  insert as exemption to bypass transfer verification
-		let tx_hash = "0".into_bounded();
-
-		assert_ok!(Creditcoin::register_transfer_internal(
-			test_info.borrower.account_id.clone(),
-			test_info.borrower.address_id.clone(),
-			test_info.lender.address_id.clone(),
-			EvmTransferKind::Ethless.into(),
-			33u64.into(),
-			deal_order_id.clone(),
-			tx_hash,
-			&test_info.loan_terms.currency
-		));
-
-		let (transfer_id, _) =
-			test_info.create_repayment_transfer(&deal_order_id, deal_order.terms.amount + 1u64);
-
-		// modify transfer to make sure we have transfered enough funds
-		crate::Transfers::<Test>::mutate(&transfer_id, |transfer_storage| {
-			let mut ts = transfer_storage.as_mut().unwrap();
-
-			ts.amount = deal_order.terms.amount + 1u64;
-		});
-
-		assert_ok!(Creditcoin::close_deal_order(
-			Origin::signed(test_info.borrower.account_id),
-			deal_order_id.clone(),
-			transfer_id.clone(),
+		assert_ok!(Creditcoin::register_funding_transfer(
+			Origin::signed(test_info.lender.account_id.clone()),
+			EvmTransferKind::Erc20.into(),
+			deal_order_id,
+			"0xabcabcabca".hex_to_address()
 		));
-
-		// assert field values were updated in storage
-		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
-		assert_eq!(saved_deal_order.repayment_transfer_id, Some(transfer_id.clone()));
-
-		let saved_transfer = crate::Pallet::<Test>::transfers(&transfer_id).unwrap();
-		assert!(saved_transfer.is_processed);
-
-		// assert events in reversed order
-		let mut all_events = <frame_system::Pallet<Test>>::events();
-		let event2 = all_events.pop().expect("Second EventRecord").event;
-		assert_matches!(
-			event2,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::TransferProcessed(id)) =>{
-				assert_eq!(id,transfer_id);
-			}
-		);
-
-		let event1 = all_events.pop().expect("First EventRecord").event;
-		assert_matches!(
-			event1,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::DealOrderClosed(id)) =>{
-				assert_eq!(id,deal_order_id);
-			}
-		);
 	});
 }
 
 #[test]
-fn exempt_should_error_when_not_signed() {
+fn register_funding_transfer_should_accept_native_transfers_of_native_currency() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
+		let currency = Currency::Evm(EvmCurrencyType::Native, EvmInfo::RINKEBY);
+		let test_info = TestInfo::with_currency(currency);
 		let (deal_order_id, _) = test_info.create_deal_order();
+		let origin = Origin::signed(test_info.lender.account_id.clone());
 
-		assert_noop!(Creditcoin::exempt(Origin::none(), deal_order_id), BadOrigin);
+		assert_noop!(
+			Creditcoin::register_funding_transfer(
+				origin.clone(),
+				EvmTransferKind::Ethless.into(),
+				deal_order_id.clone(),
+				"0xabcabcabca".hex_to_address()
+			),
+			TestError::UnsupportedTransferKind
+		);
+
+		assert_ok!(Creditcoin::register_funding_transfer(
+			origin,
+			EvmTransferKind::Native.into(),
+			deal_order_id,
+			"0xabcabcabca".hex_to_address()
+		));
 	});
 }
 
 #[test]
-fn exempt_should_error_when_deal_order_has_already_been_repaid() {
+fn register_funding_transfer_legacy_should_error_when_currency_present() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
+		let test_info = TestInfo::with_currency(ethless_currency("0xaaaa".hex_to_address()));
 		let (deal_order_id, _) = test_info.create_deal_order();
-
-		// simulate DealOrder which has been repaid
-		crate::DealOrders::<Test>::mutate(
-			deal_order_id.expiration(),
-			deal_order_id.hash(),
-			|deal_order_storage| {
-				deal_order_storage.as_mut().unwrap().repayment_transfer_id =
-					Some(TransferId::new::<Test>(&test_info.blockchain, b"4444"));
-			},
-		);
-
+		let tx = "0xdeadbeef".hex_to_address();
+		let origin = Origin::signed(test_info.lender.account_id);
 		assert_noop!(
-			Creditcoin::exempt(Origin::signed(test_info.lender.account_id), deal_order_id),
-			TestError::DealOrderAlreadyClosed
+			Creditcoin::register_funding_transfer_legacy(
+				origin,
+				LegacyTransferKind::Native,
+				deal_order_id,
+				tx
+			),
+			TestError::DeprecatedExtrinsic
 		);
 	});
 }
 
-#[test]
-fn exempt_should_error_for_non_lender() {
+fn register_repayment_transfer_should_error_when_not_signed(version: TransferVersion) {
 	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
 		let (deal_order_id, _) = test_info.create_deal_order();
+		let tx = "0xabcabcabca".hex_to_address();
+		let origin = Origin::none();
+		let amount = 21u64.into();
 
 		assert_noop!(
-			Creditcoin::exempt(Origin::signed(test_info.borrower.account_id), deal_order_id),
-			TestError::NotLender
+			match version {
+				TransferVersion::Legacy => Creditcoin::register_repayment_transfer_legacy(
+					origin,
+					LegacyTransferKind::Native,
+					amount,
+					deal_order_id,
+					tx,
+				),
+				TransferVersion::New => Creditcoin::register_repayment_transfer(
+					origin,
+					EvmTransferKind::Ethless.into(),
+					amount,
+					deal_order_id,
+					tx,
+				),
+			},
+			BadOrigin
 		);
-	});
+	})
 }
 
 #[test]
-fn exempt_should_succeed() {
-	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
+fn register_repayment_transfer_legacy_should_error_when_not_signed() {
+	register_repayment_transfer_should_error_when_not_signed(TransferVersion::Legacy);
+}
+
+#[test]
+fn register_repayment_transfer_new_should_error_when_not_signed() {
+	register_repayment_transfer_should_error_when_not_signed(TransferVersion::New);
+}
 
+fn register_repayment_transfer_should_error_when_not_deal_order_not_found(
+	version: TransferVersion,
+) {
+	ExtBuilder::default().build_and_execute(|| {
 		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
+		let (_, deal_order) = test_info.create_deal_order();
+		let DealOrder { offer_id, .. } = deal_order;
+		// expiration_block set to 0
+		let deal_order_id = DealOrderId::new::<Test>(0, &offer_id);
 
-		assert_ok!(Creditcoin::exempt(
-			Origin::signed(test_info.lender.account_id),
-			deal_order_id.clone()
-		));
+		let amount = 21u64.into();
+		let origin = Origin::signed(test_info.borrower.account_id);
+		let tx = "0xabcabcabca".hex_to_address();
 
-		let transfer_id = TransferId::new::<Test>(&test_info.blockchain, b"0");
+		assert_noop!(
+			match version {
+				TransferVersion::Legacy => Creditcoin::register_repayment_transfer_legacy(
+					origin,
+					LegacyTransferKind::Native,
+					amount,
+					deal_order_id,
+					tx,
+				),
+				TransferVersion::New => Creditcoin::register_repayment_transfer(
+					origin,
+					EvmTransferKind::Ethless.into(),
+					amount,
+					deal_order_id,
+					tx,
+				),
+			},
+			TestError::NonExistentDealOrder
+		);
+	})
+}
 
-		// assert field values were updated in storage
-		let saved_deal_order = DealOrders::<Test>::try_get_id(&deal_order_id).unwrap();
-		assert_eq!(saved_deal_order.repayment_transfer_id, Some(transfer_id));
+#[test]
+fn register_repayment_transfer_legacy_should_error_when_not_deal_order_not_found() {
+	register_repayment_transfer_should_error_when_not_deal_order_not_found(TransferVersion::Legacy);
+}
 
-		// assert events in reversed order
-		let mut all_events = <frame_system::Pallet<Test>>::events();
-		let event = all_events.pop().expect("Expected at least one EventRecord to be found").event;
-		assert_eq!(
-			event,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::LoanExempted(deal_order_id))
-		);
-	});
+#[test]
+fn register_repayment_transfer_new_should_error_when_not_deal_order_not_found() {
+	register_repayment_transfer_should_error_when_not_deal_order_not_found(TransferVersion::New);
 }
 
 #[test]
-fn verify_transfer_should_error_when_not_signed() {
+fn register_repayment_transfer_legacy_should_error_when_currency_present() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
+		let test_info = TestInfo::with_currency(ethless_currency("0xaaaa".hex_to_address()));
 		let (deal_order_id, _) = test_info.create_deal_order();
-		let (transfer_id, transfer) = test_info.create_funding_transfer(&deal_order_id);
-		let deadline = Test::unverified_transfer_deadline();
+		let amount = 21u64.into();
+		let origin = Origin::signed(test_info.borrower.account_id);
+		let tx = "0xabcabcabca".hex_to_address();
+
 		assert_noop!(
-			Creditcoin::persist_task_output(
-				Origin::none(),
-				deadline,
-				(transfer_id, transfer).into()
+			Creditcoin::register_repayment_transfer_legacy(
+				origin,
+				LegacyTransferKind::Native,
+				amount,
+				deal_order_id,
+				tx,
 			),
-			BadOrigin
+			TestError::DeprecatedExtrinsic
 		);
 	});
 }
 
 #[test]
-fn verify_transfer_should_error_when_signer_not_authorized() {
+fn register_currency_should_error_when_not_sudo() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let (transfer_id, transfer) = test_info.create_funding_transfer(&deal_order_id);
-		let deadline = Test::unverified_transfer_deadline();
+		let test_info = TestInfo::default();
+
 		assert_noop!(
-			Creditcoin::persist_task_output(
+			Creditcoin::register_currency(
 				Origin::signed(test_info.lender.account_id),
-				deadline,
-				(transfer_id, transfer).into(),
+				Currency::default(),
 			),
-			TestError::InsufficientAuthority,
+			BadOrigin
 		);
 	});
 }
 
 #[test]
-fn verify_transfer_should_error_when_transfer_has_already_been_registered() {
+fn register_currency_should_work() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
+		System::set_block_number(1);
+		let currency = Currency::default();
 
-		// authorize lender
-		let root = RawOrigin::Root;
-		assert_ok!(Creditcoin::add_authority(
-			crate::mock::RuntimeOrigin::from(root),
-			test_info.lender.account_id.clone(),
-		));
+		assert_ok!(Creditcoin::register_currency(Origin::root(), currency.clone()));
 
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let (transfer_id, transfer) = test_info.create_funding_transfer(&deal_order_id);
-		let deadline = Test::unverified_transfer_deadline();
+		let id = CurrencyId::new::<Test>(&currency);
+		assert_eq!(crate::Currencies::<Test>::get(&id).as_ref(), Some(&currency));
+
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("an event").event;
+
+		assert_matches!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::<Test>::CurrencyRegistered(registered_id, registered_currency)) => {
+				assert_eq!(registered_id, id);
+				assert_eq!(registered_currency, currency);
+			}
+		);
+	})
+}
+
+#[test]
+fn register_currency_should_error_when_currency_already_registered() {
+	ExtBuilder::default().build_and_execute(|| {
+		let currency = Currency::default();
+
+		assert_ok!(Creditcoin::register_currency(Origin::root(), currency.clone()));
 
 		assert_noop!(
-			Creditcoin::persist_task_output(
-				Origin::signed(test_info.lender.account_id),
-				deadline,
-				(transfer_id, transfer).into(),
-			),
-			non_paying_error(TestError::TransferAlreadyRegistered),
+			Creditcoin::register_currency(Origin::root(), currency),
+			TestError::CurrencyAlreadyRegistered
 		);
-	});
+	})
 }
 
-#[test]
-fn verify_transfer_should_work() {
-	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
+#[test]
+fn exercise_weightinfo_functions() {
+	let result = super::weights::WeightInfo::<Test>::register_address();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::claim_legacy_wallet();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::add_ask_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::add_bid_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::add_offer();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::add_deal_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::cancel_ask_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::cancel_bid_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::cancel_offer();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::add_authority();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::persist_transfer();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::fail_transfer();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::fund_deal_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::lock_deal_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::register_funding_transfer();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::register_repayment_transfer();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::repay_installment();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::close_deal_order();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::assign_deal_order();
+	assert!(result.ref_time() > 0);
 
-		let test_info = TestInfo::new_defaults();
+	let result = super::weights::WeightInfo::<Test>::exempt();
+	assert!(result.ref_time() > 0);
 
-		// authorize lender
-		let root = RawOrigin::Root;
-		assert_ok!(Creditcoin::add_authority(
-			crate::mock::RuntimeOrigin::from(root),
-			test_info.lender.account_id.clone(),
-		));
+	let result = super::weights::WeightInfo::<Test>::register_deal_order();
+	assert!(result.ref_time() > 0);
 
-		let (deal_order_id, deal_order) = test_info.create_deal_order();
+	let result = super::weights::WeightInfo::<Test>::request_collect_coins();
+	assert!(result.ref_time() > 0);
 
-		// create a transfer but don't add it into storage
-		let tx = "0xafafaf".hex_to_address();
-		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
-		let transfer = Transfer {
-			blockchain: test_info.blockchain.clone(),
-			kind: TransferKind::Evm(EvmTransferKind::Ethless),
-			from: test_info.lender.address_id.clone(),
-			to: test_info.borrower.address_id.clone(),
-			deal_order_id,
-			amount: deal_order.terms.amount,
-			tx_id: tx,
-			block: System::block_number(),
-			is_processed: false,
-			account_id: test_info.lender.account_id.clone(),
-			timestamp: None,
-		};
-		let deadline = Test::unverified_transfer_deadline();
+	let result = super::weights::WeightInfo::<Test>::fail_collect_coins();
+	assert!(result.ref_time() > 0);
 
-		assert_ok!(Creditcoin::persist_task_output(
-			Origin::signed(test_info.lender.account_id),
-			deadline,
-			(transfer_id.clone(), transfer.clone()).into(),
-		));
+	let result = super::weights::WeightInfo::<Test>::persist_collect_coins();
+	assert!(result.ref_time() > 0);
 
-		let mut all_events = <frame_system::Pallet<Test>>::events();
+	let result = super::weights::WeightInfo::<Test>::remove_authority();
+	assert!(result.ref_time() > 0);
 
-		// assert events in reversed order
-		let last_event = all_events.pop().expect("At least one EventRecord").event;
-		assert_matches!(
-			last_event,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::TransferVerified(id)) => {
-				assert_eq!(transfer_id, id)
-			}
-		);
+	let result = super::weights::WeightInfo::<Test>::set_collect_coins_contract();
+	assert!(result.ref_time() > 0);
 
-		assert_eq!(Transfers::<Test>::get(&transfer_id), Some(transfer));
-	});
-}
+	let result = super::weights::WeightInfo::<Test>::register_currency();
+	assert!(result.ref_time() > 0);
 
-#[test]
-fn fail_transfer_should_work() {
-	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
+	let result = super::weights::WeightInfo::<Test>::on_idle(1);
+	assert!(result.ref_time() > 0);
 
-		let test_info = TestInfo::new_defaults();
+	let result = super::weights::WeightInfo::<Test>::match_orders(1);
+	assert!(result.ref_time() > 0);
 
-		let root = RawOrigin::Root;
-		assert_ok!(Creditcoin::add_authority(
-			crate::mock::RuntimeOrigin::from(root),
-			test_info.lender.account_id.clone(),
-		));
+	let result = super::weights::WeightInfo::<Test>::set_order_matching();
+	assert!(result.ref_time() > 0);
 
-		let _ = test_info.create_deal_order();
+	let result = super::weights::WeightInfo::<Test>::renegotiate_deal_order();
+	assert!(result.ref_time() > 0);
 
-		let tx = "0xafafaf".hex_to_address();
-		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+	let result = super::weights::WeightInfo::<Test>::set_confirmation_policy();
+	assert!(result.ref_time() > 0);
 
-		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
-		let deadline = Test::unverified_transfer_deadline();
+	let result = super::weights::WeightInfo::<Test>::set_quorum_threshold();
+	assert!(result.ref_time() > 0);
 
-		assert_ok!(Creditcoin::fail_task(
-			Origin::signed(test_info.lender.account_id),
-			deadline,
-			transfer_id.clone().into(),
-			failure_cause
-		));
+	let result = super::weights::WeightInfo::<Test>::set_authority_bond();
+	assert!(result.ref_time() > 0);
 
-		let mut all_events = System::events();
+	let result = super::weights::WeightInfo::<Test>::slash_authority();
+	assert!(result.ref_time() > 0);
 
-		assert_matches!(
-			all_events.pop().unwrap().event,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::<Test>::TransferFailedVerification(id, cause)) => {
-				assert_eq!(id, transfer_id);
-				assert_eq!(cause, failure_cause);
-			}
-		);
-	})
+	let result = super::weights::WeightInfo::<Test>::set_task_fee();
+	assert!(result.ref_time() > 0);
+
+	let result = super::weights::WeightInfo::<Test>::bond_authority();
+	assert!(result.ref_time() > 0);
 }
 
 #[test]
-fn fail_transfer_should_error_when_not_signed() {
+fn remove_authority_errors_for_non_root() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let test_info = TestInfo::new_defaults();
-
-		let _ = test_info.create_deal_order();
+		let acct: AccountId = AccountId::new([0; 32]);
 
-		let tx = "0xafafaf".hex_to_address();
-		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+		assert_noop!(Creditcoin::remove_authority(Origin::signed(acct.clone()), acct), BadOrigin);
+	});
+}
 
-		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
-		let deadline = Test::unverified_transfer_deadline();
+#[test]
+fn remove_authority_should_fail_when_authority_does_not_exist() {
+	ExtBuilder::default().build_and_execute(|| {
+		let root = RawOrigin::Root;
+		let acct: AccountId = AccountId::new([0; 32]);
 
 		assert_noop!(
-			Creditcoin::fail_task(Origin::none(), deadline, transfer_id.into(), failure_cause),
-			BadOrigin
+			Creditcoin::remove_authority(crate::mock::RuntimeOrigin::from(root), acct),
+			crate::Error::<Test>::NotAnAuthority,
 		);
-	})
+	});
 }
 
 #[test]
-fn fail_transfer_should_error_when_not_authority() {
+fn add_and_remove_authority_works_for_root() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let test_info = TestInfo::new_defaults();
+		let root = RawOrigin::Root;
+		let account: AccountId = AccountId::new([0; 32]);
 
-		let _ = test_info.create_deal_order();
+		assert!(!<Test as Config>::TaskScheduler::is_authority(&account));
+		<Test as Config>::TaskScheduler::insert_authority(&account);
+		assert!(<Test as Config>::TaskScheduler::is_authority(&account));
 
-		let tx = "0xafafaf".hex_to_address();
-		let transfer_id = TransferId::new::<Test>(&Blockchain::RINKEBY, &tx);
+		assert_ok!(Creditcoin::remove_authority(Origin::from(root), account.clone()));
+		assert!(!<Test as Config>::TaskScheduler::is_authority(&account));
+	});
+}
 
-		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
-		let deadline = Test::unverified_transfer_deadline();
+#[test]
+fn set_confirmation_policy_errors_for_non_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		let acct: AccountId = AccountId::new([0; 32]);
 
 		assert_noop!(
-			Creditcoin::fail_task(
-				Origin::signed(test_info.lender.account_id),
-				deadline,
-				transfer_id.into(),
-				failure_cause
+			Creditcoin::set_confirmation_policy(
+				Origin::signed(acct),
+				Blockchain::ETHEREUM,
+				Some(ConfirmationPolicy::Finalized)
 			),
-			TestError::InsufficientAuthority
+			BadOrigin
 		);
-	})
+	});
 }
 
 #[test]
-fn fail_transfer_should_error_when_transfer_registered() {
+fn set_confirmation_policy_should_fail_when_unsupported_by_blockchain() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-
-		let test_info = TestInfo::new_defaults();
-
-		let (deal_order_id, _) = test_info.create_deal_order();
-
-		let (transfer_id, _) = test_info.create_funding_transfer(&deal_order_id);
-
-		let root = RawOrigin::Root;
-		assert_ok!(Creditcoin::add_authority(
-			crate::mock::RuntimeOrigin::from(root),
-			test_info.lender.account_id.clone(),
-		));
-
-		let failure_cause = crate::ocw::errors::VerificationFailureCause::TaskFailed;
-		let deadline = Test::unverified_transfer_deadline();
-
 		assert_noop!(
-			Creditcoin::fail_task(
-				Origin::signed(test_info.lender.account_id),
-				deadline,
-				transfer_id.into(),
-				failure_cause
+			Creditcoin::set_confirmation_policy(
+				Origin::from(RawOrigin::Root),
+				Blockchain::BITCOIN,
+				Some(ConfirmationPolicy::Finalized)
 			),
-			TestError::TransferAlreadyRegistered
+			crate::Error::<Test>::UnsupportedConfirmationPolicy
 		);
-	})
+	});
 }
 
 #[test]
-fn on_initialize_removes_expired_deals_without_transfers() {
-	ExtBuilder::default().build_offchain_and_execute(|| {
-		System::set_block_number(1);
-		let mut expected_deal_orders = Vec::new();
+fn set_confirmation_policy_should_fail_for_zero_blocks() {
+	ExtBuilder::default().build_and_execute(|| {
+		for blockchain in [Blockchain::ETHEREUM, Blockchain::BITCOIN] {
+			assert_noop!(
+				Creditcoin::set_confirmation_policy(
+					Origin::from(RawOrigin::Root),
+					blockchain,
+					Some(ConfirmationPolicy::Blocks(0))
+				),
+				crate::Error::<Test>::UnsupportedConfirmationPolicy
+			);
+		}
+	});
+}
 
-		let now = System::block_number();
-		for expiration_block in now..=20 {
-			let seed1 = format!("{:02}0", expiration_block.clone());
-			let seed2 = format!("{:02}1", expiration_block.clone());
+#[test]
+fn set_confirmation_policy_works_for_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
+		let policy = ConfirmationPolicy::Blocks(3);
 
-			let test_info = TestInfo {
-				lender: RegisteredAddress::new(&seed1, Blockchain::RINKEBY),
-				borrower: RegisteredAddress::new(&seed2, Blockchain::RINKEBY),
-				blockchain: Blockchain::RINKEBY,
-				loan_terms: LoanTerms {
-					amount: 2_000_000u64.into(),
-					interest_rate: Default::default(),
-					term_length: Duration::from_millis(1_000_000),
-					currency: CurrencyId::new::<Test>(&Currency::default()),
-				},
-				ask_guid: format!("{:?}-ask-guid", expiration_block.clone())
-					.as_bytes()
-					.into_bounded(),
-				bid_guid: format!("{:?}-bid-guid", expiration_block.clone())
-					.as_bytes()
-					.into_bounded(),
-				expiration_block,
-				currency: Currency::default(),
-			};
+		assert_ok!(Creditcoin::set_confirmation_policy(
+			Origin::from(RawOrigin::Root),
+			Blockchain::BITCOIN,
+			Some(policy)
+		));
+		assert_eq!(Creditcoin::confirmation_policy(Blockchain::BITCOIN), Some(policy));
+		let event = <frame_system::Pallet<Test>>::events().pop().expect("expected an event").event;
+		assert_eq!(
+			event,
+			crate::mock::RuntimeEvent::Creditcoin(crate::Event::ConfirmationPolicySet(
+				Blockchain::BITCOIN,
+				Some(policy)
+			))
+		);
 
-			let (offer_id, _) = test_info.create_offer();
-			assert_ok!(Creditcoin::add_deal_order(
-				Origin::signed(test_info.borrower.account_id.clone()),
-				offer_id.clone(),
-				expiration_block,
-			));
-			let deal_order_id = DealOrderId::new::<Test>(expiration_block, &offer_id);
-			let deal_order =
-				Creditcoin::deal_orders(deal_order_id.expiration(), deal_order_id.hash()).unwrap();
+		assert_ok!(Creditcoin::set_confirmation_policy(
+			Origin::from(RawOrigin::Root),
+			Blockchain::BITCOIN,
+			None
+		));
+		assert_eq!(Creditcoin::confirmation_policy(Blockchain::BITCOIN), None);
+	});
+}
 
-			// fund only deal orders which expire at even blocks
-			if expiration_block % 2 == 0 {
-				let tx = format!("0xfafafa{:02}", expiration_block.clone());
-				assert_ok!(Creditcoin::register_funding_transfer(
-					Origin::signed(test_info.lender.account_id.clone()),
-					EvmTransferKind::Ethless.into(),
-					deal_order_id.clone(),
-					tx.hex_to_address()
-				));
-				let (transfer_id, _) = test_info.mock_transfer(
-					&test_info.lender,
-					&test_info.borrower,
-					deal_order.terms.amount,
-					&deal_order_id,
-					tx,
-				);
+#[test]
+fn set_quorum_threshold_errors_for_non_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		let acct: AccountId = AccountId::new([0; 32]);
 
-				// attach transfer to deal order
-				assert_ok!(Creditcoin::fund_deal_order(
-					Origin::signed(test_info.lender.account_id.clone()),
-					deal_order_id.clone(),
-					transfer_id.clone()
-				));
-				// it's funded so it should be kept
-				expected_deal_orders.push(deal_order_id.clone());
-			} else if expiration_block > 15 {
-				// still hasn't expired so should be kept regardless
-				expected_deal_orders.push(deal_order_id.clone());
-			}
-		}
+		assert_noop!(Creditcoin::set_quorum_threshold(Origin::signed(acct), 2), BadOrigin);
+	});
+}
 
-		// advance blocks, will perform housekeeping
-		roll_to(15);
+#[test]
+fn set_quorum_threshold_works_for_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
 
-		for expected_order_id in expected_deal_orders.iter() {
-			let _order = DealOrders::<Test>::try_get_id(&expected_order_id).unwrap();
+		for seed in 1..=3 {
+			<Test as Config>::TaskScheduler::insert_authority(&AccountId::new([seed; 32]));
 		}
+
+		assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(RawOrigin::Root), 3));
+		assert_eq!(Creditcoin::quorum_threshold(), 3);
+		assert_eq!(creditcoin_events().pop(), Some(crate::Event::QuorumThresholdSet(3)));
 	});
 }
 
-enum TransferVersion {
-	Legacy,
-	New,
+#[test]
+fn set_quorum_threshold_should_fail_above_authority_count() {
+	ExtBuilder::default().build_and_execute(|| {
+		for seed in 1..=2 {
+			<Test as Config>::TaskScheduler::insert_authority(&AccountId::new([seed; 32]));
+		}
+
+		assert_noop!(
+			Creditcoin::set_quorum_threshold(Origin::from(RawOrigin::Root), 3),
+			TestError::QuorumThresholdTooHigh
+		);
+	});
 }
 
-fn register_funding_transfer_should_error_when_not_signed(version: TransferVersion) {
+#[test]
+fn remove_authority_should_fail_when_quorum_would_be_unreachable() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let tx = "0xabcabcabca";
-
-		let tx = tx.hex_to_address();
-		let origin = Origin::none();
+		let account = set_up_bonded_authority();
+		<Test as Config>::TaskScheduler::insert_authority(&AccountId::new([1; 32]));
+		assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(RawOrigin::Root), 2));
 
 		assert_noop!(
-			match version {
-				TransferVersion::New => Creditcoin::register_funding_transfer(
-					origin,
-					EvmTransferKind::Ethless.into(),
-					deal_order_id,
-					tx,
-				),
-				TransferVersion::Legacy => Creditcoin::register_funding_transfer_legacy(
-					origin,
-					LegacyTransferKind::Native,
-					deal_order_id,
-					tx,
-				),
-			},
-			BadOrigin
+			Creditcoin::remove_authority(Origin::from(RawOrigin::Root), account),
+			TestError::QuorumThresholdTooHigh
 		);
 	});
 }
 
+const AUTHORITY_BOND: u128 = 1_000;
+
+fn set_up_bonded_authority() -> AccountId {
+	let account: AccountId = AccountId::new([0; 32]);
+	<Balances as CurrencyTrait<AccountId>>::make_free_balance_be(&account, 5 * AUTHORITY_BOND);
+	assert_ok!(Creditcoin::set_authority_bond(Origin::from(RawOrigin::Root), AUTHORITY_BOND));
+	assert_ok!(Creditcoin::add_authority(Origin::from(RawOrigin::Root), account.clone()));
+	account
+}
+
 #[test]
-fn register_funding_transfer_new_should_error_when_not_signed() {
-	register_funding_transfer_should_error_when_not_signed(TransferVersion::New);
+fn add_authority_should_reserve_bond() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account = set_up_bonded_authority();
+
+		assert!(<Test as Config>::TaskScheduler::is_authority(&account));
+		assert_eq!(Balances::reserved_balance(&account), AUTHORITY_BOND);
+		assert_eq!(Creditcoin::authority_bonds(&account), Some(AUTHORITY_BOND));
+	});
 }
 
 #[test]
-fn register_funding_transfer_legacy_should_error_when_not_signed() {
-	register_funding_transfer_should_error_when_not_signed(TransferVersion::Legacy);
+fn add_authority_should_fail_when_bond_cannot_be_reserved() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account: AccountId = AccountId::new([0; 32]);
+		assert_ok!(Creditcoin::set_authority_bond(Origin::from(RawOrigin::Root), AUTHORITY_BOND));
+
+		assert_noop!(
+			Creditcoin::add_authority(Origin::from(RawOrigin::Root), account),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
 }
 
-fn register_funding_transfer_should_error_when_not_deal_order_not_found(version: TransferVersion) {
+#[test]
+fn remove_authority_should_unreserve_bond() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (_, deal_order) = test_info.create_deal_order();
-		let DealOrder { offer_id, .. } = deal_order;
-		// expiration_block set to 0
-		let deal_order_id = DealOrderId::new::<Test>(0, &offer_id);
+		let account = set_up_bonded_authority();
 
-		let tx = "0xabcabcabca".hex_to_address();
-		let origin = Origin::signed(test_info.lender.account_id);
+		assert_ok!(Creditcoin::remove_authority(Origin::from(RawOrigin::Root), account.clone()));
+		assert_eq!(Balances::reserved_balance(&account), 0);
+		assert_eq!(Balances::free_balance(&account), 5 * AUTHORITY_BOND);
+		assert_eq!(Creditcoin::authority_bonds(&account), None);
+	});
+}
+
+#[test]
+fn set_authority_bond_errors_for_non_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		let acct: AccountId = AccountId::new([0; 32]);
 
 		assert_noop!(
-			match version {
-				TransferVersion::Legacy => Creditcoin::register_funding_transfer_legacy(
-					origin,
-					LegacyTransferKind::Native,
-					deal_order_id,
-					tx
-				),
-				TransferVersion::New => Creditcoin::register_funding_transfer(
-					origin,
-					EvmTransferKind::Ethless.into(),
-					deal_order_id,
-					tx
-				),
-			},
-			TestError::NonExistentDealOrder
+			Creditcoin::set_authority_bond(Origin::signed(acct), AUTHORITY_BOND),
+			BadOrigin
 		);
-	})
+	});
+}
+
+/// Records a vote by `who` on a task which the other authorities reached a different outcome on.
+fn set_up_contradicted_vote(who: &AccountId) -> (BlockNumber, crate::TaskId<H256>, H256) {
+	let deadline = 10;
+	let task_id = crate::TaskId::from(TransferId::new::<Test>(&Blockchain::RINKEBY, b"0x00"));
+	let vote = H256::repeat_byte(1);
+	crate::TaskOutputVotes::<Test>::insert((deadline, &task_id, who), vote);
+	crate::TaskOutcomes::<Test>::insert(deadline, &task_id, H256::repeat_byte(2));
+	(deadline, task_id, vote)
 }
 
 #[test]
-fn register_funding_transfer_legacy_should_error_when_not_deal_order_not_found() {
-	register_funding_transfer_should_error_when_not_deal_order_not_found(TransferVersion::Legacy);
+fn slash_authority_errors_for_non_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account = set_up_bonded_authority();
+		let (deadline, task_id, vote) = set_up_contradicted_vote(&account);
+
+		assert_noop!(
+			Creditcoin::slash_authority(
+				Origin::signed(account.clone()),
+				account,
+				deadline,
+				task_id,
+				vote
+			),
+			BadOrigin
+		);
+	});
 }
 
 #[test]
-fn register_funding_transfer_new_should_error_when_not_deal_order_not_found() {
-	register_funding_transfer_should_error_when_not_deal_order_not_found(TransferVersion::New);
+fn slash_authority_should_fail_when_authority_does_not_exist() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account: AccountId = AccountId::new([0; 32]);
+		let (deadline, task_id, vote) = set_up_contradicted_vote(&account);
+
+		assert_noop!(
+			Creditcoin::slash_authority(
+				Origin::from(RawOrigin::Root),
+				account,
+				deadline,
+				task_id,
+				vote
+			),
+			TestError::NotAnAuthority
+		);
+	});
 }
 
 #[test]
-fn register_funding_transfer_legacy_should_error_when_currency_present() {
+fn slash_authority_should_fail_without_recorded_vote() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::with_currency(ethless_currency("0xaaaa".hex_to_address()));
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let tx = "0xdeadbeef".hex_to_address();
-		let origin = Origin::signed(test_info.lender.account_id);
+		let account = set_up_bonded_authority();
+		let (deadline, task_id, vote) = set_up_contradicted_vote(&account);
+		let root = RawOrigin::Root;
+
 		assert_noop!(
-			Creditcoin::register_funding_transfer_legacy(
-				origin,
-				LegacyTransferKind::Native,
-				deal_order_id,
-				tx
+			Creditcoin::slash_authority(
+				Origin::from(root.clone()),
+				account.clone(),
+				deadline,
+				task_id.clone(),
+				H256::repeat_byte(3)
 			),
-			TestError::DeprecatedExtrinsic
+			TestError::VoteNotRecorded
+		);
+		assert_noop!(
+			Creditcoin::slash_authority(Origin::from(root), account, deadline + 1, task_id, vote),
+			TestError::VoteNotRecorded
 		);
 	});
 }
 
-fn register_repayment_transfer_should_error_when_not_signed(version: TransferVersion) {
+#[test]
+fn slash_authority_should_fail_when_vote_is_not_contradicted() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::new_defaults();
-		let (deal_order_id, _) = test_info.create_deal_order();
-		let tx = "0xabcabcabca".hex_to_address();
-		let origin = Origin::none();
-		let amount = 21u64.into();
+		let account = set_up_bonded_authority();
+		let (deadline, task_id, vote) = set_up_contradicted_vote(&account);
+		let root = RawOrigin::Root;
 
+		crate::TaskOutcomes::<Test>::insert(deadline, &task_id, vote);
 		assert_noop!(
-			match version {
-				TransferVersion::Legacy => Creditcoin::register_repayment_transfer_legacy(
-					origin,
-					LegacyTransferKind::Native,
-					amount,
-					deal_order_id,
-					tx,
-				),
-				TransferVersion::New => Creditcoin::register_repayment_transfer(
-					origin,
-					EvmTransferKind::Ethless.into(),
-					amount,
-					deal_order_id,
-					tx,
-				),
-			},
-			BadOrigin
+			Creditcoin::slash_authority(
+				Origin::from(root.clone()),
+				account.clone(),
+				deadline,
+				task_id.clone(),
+				vote
+			),
+			TestError::VoteNotContradicted
 		);
-	})
+
+		// the vote is still waiting for the quorum its task requires
+		crate::TaskOutcomes::<Test>::remove(deadline, &task_id);
+		<Test as Config>::TaskScheduler::insert_authority(&AccountId::new([1; 32]));
+		<Test as Config>::TaskScheduler::insert_authority(&AccountId::new([2; 32]));
+		assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(root.clone()), 2));
+		assert_noop!(
+			Creditcoin::slash_authority(Origin::from(root), account, deadline, task_id, vote),
+			TestError::VoteNotContradicted
+		);
+	});
 }
 
 #[test]
-fn register_repayment_transfer_legacy_should_error_when_not_signed() {
-	register_repayment_transfer_should_error_when_not_signed(TransferVersion::Legacy);
+fn slash_authority_should_fail_when_quorum_would_be_unreachable() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account = set_up_bonded_authority();
+		<Test as Config>::TaskScheduler::insert_authority(&AccountId::new([1; 32]));
+		assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(RawOrigin::Root), 2));
+		let (deadline, task_id, vote) = set_up_contradicted_vote(&account);
+
+		assert_noop!(
+			Creditcoin::slash_authority(
+				Origin::from(RawOrigin::Root),
+				account,
+				deadline,
+				task_id,
+				vote
+			),
+			TestError::QuorumThresholdTooHigh
+		);
+	});
 }
 
 #[test]
-fn register_repayment_transfer_new_should_error_when_not_signed() {
-	register_repayment_transfer_should_error_when_not_signed(TransferVersion::New);
+fn slash_authority_should_burn_bond_and_remove_authority() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
+		let account = set_up_bonded_authority();
+		let (deadline, task_id, vote) = set_up_contradicted_vote(&account);
+		let issuance = Balances::total_issuance();
+
+		assert_ok!(Creditcoin::slash_authority(
+			Origin::from(RawOrigin::Root),
+			account.clone(),
+			deadline,
+			task_id.clone(),
+			vote
+		));
+
+		assert!(!<Test as Config>::TaskScheduler::is_authority(&account));
+		assert_eq!(Balances::reserved_balance(&account), 0);
+		assert_eq!(Balances::free_balance(&account), 4 * AUTHORITY_BOND);
+		assert_eq!(Balances::total_issuance(), issuance - AUTHORITY_BOND);
+		assert_eq!(Creditcoin::authority_bonds(&account), None);
+		assert_eq!(
+			creditcoin_events().pop(),
+			Some(crate::Event::AuthoritySlashed(account, task_id, AUTHORITY_BOND))
+		);
+	});
 }
 
-fn register_repayment_transfer_should_error_when_not_deal_order_not_found(
-	version: TransferVersion,
-) {
+#[test]
+fn slash_authority_should_work_for_vote_acted_on_without_quorum() {
 	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
 		let test_info = TestInfo::new_defaults();
-		let (_, deal_order) = test_info.create_deal_order();
-		let DealOrder { offer_id, .. } = deal_order;
-		// expiration_block set to 0
-		let deal_order_id = DealOrderId::new::<Test>(0, &offer_id);
+		let account = set_up_bonded_authority();
+		let (transfer_id, _) = set_up_task_fee_test(&test_info, &[]);
+		let deadline = Test::unverified_transfer_deadline();
+		let task_id = crate::TaskId::from(transfer_id);
+		assert_eq!(Creditcoin::quorum_threshold(), 0);
 
-		let amount = 21u64.into();
-		let origin = Origin::signed(test_info.borrower.account_id);
-		let tx = "0xabcabcabca".hex_to_address();
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(account.clone()),
+			deadline,
+			task_id.clone(),
+			crate::ocw::errors::VerificationFailureCause::TaskFailed
+		));
+		let vote = crate::TaskOutputVotes::<Test>::get((deadline, &task_id, &account))
+			.expect("the vote is recorded");
+		assert_eq!(Creditcoin::task_outcomes(deadline, &task_id), None);
+
+		assert_ok!(Creditcoin::slash_authority(
+			Origin::from(RawOrigin::Root),
+			account.clone(),
+			deadline,
+			task_id,
+			vote
+		));
+
+		assert!(!<Test as Config>::TaskScheduler::is_authority(&account));
+		assert_eq!(Balances::reserved_balance(&account), 0);
+	});
+}
+
+/// Adds an authority without a bond, as at genesis, then requires a bond.
+fn set_up_unbonded_authority() -> AccountId {
+	let account: AccountId = AccountId::new([0; 32]);
+	<Balances as CurrencyTrait<AccountId>>::make_free_balance_be(&account, 5 * AUTHORITY_BOND);
+	assert_ok!(Creditcoin::add_authority(Origin::from(RawOrigin::Root), account.clone()));
+	assert_ok!(Creditcoin::set_authority_bond(Origin::from(RawOrigin::Root), AUTHORITY_BOND));
+	account
+}
+
+#[test]
+fn fail_task_should_fail_for_unbonded_authority() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account = set_up_unbonded_authority();
+		let task_id = crate::TaskId::from(TransferId::new::<Test>(&Blockchain::RINKEBY, b"0x00"));
 
 		assert_noop!(
-			match version {
-				TransferVersion::Legacy => Creditcoin::register_repayment_transfer_legacy(
-					origin,
-					LegacyTransferKind::Native,
-					amount,
-					deal_order_id,
-					tx,
-				),
-				TransferVersion::New => Creditcoin::register_repayment_transfer(
-					origin,
-					EvmTransferKind::Ethless.into(),
-					amount,
-					deal_order_id,
-					tx,
-				),
-			},
-			TestError::NonExistentDealOrder
+			Creditcoin::fail_task(
+				Origin::signed(account),
+				Test::unverified_transfer_deadline(),
+				task_id,
+				crate::ocw::errors::VerificationFailureCause::TaskFailed
+			),
+			TestError::AuthorityNotBonded
 		);
-	})
+	});
+}
+
+#[test]
+fn bond_authority_should_reserve_bond() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
+		let account = set_up_unbonded_authority();
+
+		assert_ok!(Creditcoin::bond_authority(Origin::signed(account.clone())));
+		assert_eq!(Balances::reserved_balance(&account), AUTHORITY_BOND);
+		assert_eq!(Creditcoin::authority_bonds(&account), Some(AUTHORITY_BOND));
+		assert_eq!(
+			creditcoin_events().pop(),
+			Some(crate::Event::AuthorityBonded(account.clone(), AUTHORITY_BOND))
+		);
+
+		assert_noop!(
+			Creditcoin::bond_authority(Origin::signed(account)),
+			TestError::AuthorityAlreadyBonded
+		);
+	});
 }
 
 #[test]
-fn register_repayment_transfer_legacy_should_error_when_not_deal_order_not_found() {
-	register_repayment_transfer_should_error_when_not_deal_order_not_found(TransferVersion::Legacy);
+fn bond_authority_should_fail_when_not_an_authority() {
+	ExtBuilder::default().build_and_execute(|| {
+		let account: AccountId = AccountId::new([0; 32]);
+
+		assert_noop!(
+			Creditcoin::bond_authority(Origin::signed(account)),
+			TestError::NotAnAuthority
+		);
+	});
+}
+
+const TASK_FEE: u128 = 1_001;
+
+/// Sets a task fee, funds the lender to pay it and `authorities` to receive rewards, then has the
+/// lender register a funding transfer.
+fn set_up_task_fee_test(test_info: &TestInfo, authorities: &[AccountId]) -> TestTransfer {
+	assert_ok!(Creditcoin::set_task_fee(Origin::from(RawOrigin::Root), TASK_FEE));
+	<Balances as CurrencyTrait<AccountId>>::make_free_balance_be(
+		&test_info.lender.account_id,
+		10 * TASK_FEE,
+	);
+	for authority in authorities {
+		<Balances as CurrencyTrait<AccountId>>::make_free_balance_be(authority, 10 * TASK_FEE);
+		assert_ok!(Creditcoin::add_authority(Origin::from(RawOrigin::Root), authority.clone()));
+	}
+
+	let (deal_order_id, deal_order) = test_info.create_deal_order();
+	let tx = "0xfafafa";
+	assert_ok!(Creditcoin::register_funding_transfer(
+		Origin::signed(test_info.lender.account_id.clone()),
+		TransferKind::Evm(EvmTransferKind::Ethless),
+		deal_order_id.clone(),
+		tx.hex_to_address()
+	));
+	test_info.make_transfer(
+		&test_info.lender,
+		&test_info.borrower,
+		deal_order.terms.amount,
+		&deal_order_id,
+		tx,
+		None::<TransferKind>,
+	)
 }
 
 #[test]
-fn register_repayment_transfer_new_should_error_when_not_deal_order_not_found() {
-	register_repayment_transfer_should_error_when_not_deal_order_not_found(TransferVersion::New);
+fn register_funding_transfer_should_reserve_task_fee() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, _) = set_up_task_fee_test(&test_info, &[]);
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_eq!(Balances::reserved_balance(&test_info.lender.account_id), TASK_FEE);
+		assert_eq!(
+			crate::TaskFees::<Test>::get(deadline, crate::TaskId::from(transfer_id)),
+			Some((test_info.lender.account_id, TASK_FEE))
+		);
+	});
 }
 
 #[test]
-fn register_repayment_transfer_legacy_should_error_when_currency_present() {
+fn register_funding_transfer_should_fail_when_task_fee_cannot_be_reserved() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::with_currency(ethless_currency("0xaaaa".hex_to_address()));
+		let test_info = TestInfo::new_defaults();
 		let (deal_order_id, _) = test_info.create_deal_order();
-		let amount = 21u64.into();
-		let origin = Origin::signed(test_info.borrower.account_id);
-		let tx = "0xabcabcabca".hex_to_address();
+		assert_ok!(Creditcoin::set_task_fee(Origin::from(RawOrigin::Root), TASK_FEE));
 
 		assert_noop!(
-			Creditcoin::register_repayment_transfer_legacy(
-				origin,
-				LegacyTransferKind::Native,
-				amount,
+			Creditcoin::register_funding_transfer(
+				Origin::signed(test_info.lender.account_id.clone()),
+				TransferKind::Evm(EvmTransferKind::Ethless),
 				deal_order_id,
-				tx,
+				"0xfafafa".hex_to_address()
 			),
-			TestError::DeprecatedExtrinsic
+			pallet_balances::Error::<Test>::InsufficientBalance
 		);
 	});
 }
 
 #[test]
-fn register_currency_should_error_when_not_sudo() {
+fn persist_task_output_should_reward_authority() {
 	ExtBuilder::default().build_and_execute(|| {
-		let test_info = TestInfo::default();
+		roll_to(1);
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, transfer) = set_up_task_fee_test(&test_info, &[authority.clone()]);
+		let deadline = Test::unverified_transfer_deadline();
 
-		assert_noop!(
-			Creditcoin::register_currency(
-				Origin::signed(test_info.lender.account_id),
-				Currency::default(),
-			),
-			BadOrigin
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(authority.clone()),
+			deadline,
+			(transfer_id.clone(), transfer).into(),
+		));
+
+		let lender = &test_info.lender.account_id;
+		assert_eq!(Balances::reserved_balance(lender), 0);
+		assert_eq!(Balances::free_balance(lender), 9 * TASK_FEE);
+		assert_eq!(Balances::free_balance(&authority), 11 * TASK_FEE);
+		assert_eq!(Creditcoin::era_rewards(0u64, &authority), TASK_FEE);
+		assert_eq!(Creditcoin::era_reward_totals(0u64), TASK_FEE);
+		assert_eq!(
+			creditcoin_events().pop(),
+			Some(crate::Event::AuthorityRewarded(authority, transfer_id.into(), TASK_FEE))
 		);
 	});
 }
 
 #[test]
-fn register_currency_should_work() {
+fn persist_task_output_with_quorum_should_split_reward() {
 	ExtBuilder::default().build_and_execute(|| {
-		System::set_block_number(1);
-		let currency = Currency::default();
+		roll_to(1);
+		let test_info = TestInfo::new_defaults();
+		let first = AccountId::new([1; 32]);
+		let second = AccountId::new([2; 32]);
+		let (transfer_id, transfer) =
+			set_up_task_fee_test(&test_info, &[first.clone(), second.clone()]);
+		assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(RawOrigin::Root), 2));
+		let deadline = Test::unverified_transfer_deadline();
 
-		assert_ok!(Creditcoin::register_currency(Origin::root(), currency.clone()));
+		for authority in [&first, &second] {
+			assert_ok!(Creditcoin::persist_task_output(
+				Origin::signed(authority.clone()),
+				deadline,
+				(transfer_id.clone(), transfer.clone()).into(),
+			));
+		}
 
-		let id = CurrencyId::new::<Test>(&currency);
-		assert_eq!(crate::Currencies::<Test>::get(&id).as_ref(), Some(&currency));
+		assert_eq!(Balances::reserved_balance(&test_info.lender.account_id), 0);
+		assert_eq!(Balances::free_balance(&second), 10 * TASK_FEE + TASK_FEE / 2 + 1);
+		assert_eq!(Balances::free_balance(&first), 10 * TASK_FEE + TASK_FEE / 2);
+		assert_eq!(Creditcoin::era_reward_totals(0u64), TASK_FEE);
+	});
+}
 
-		let event = <frame_system::Pallet<Test>>::events().pop().expect("an event").event;
+#[test]
+fn fail_task_should_refund_task_fee() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, _) = set_up_task_fee_test(&test_info, &[authority.clone()]);
+		let deadline = Test::unverified_transfer_deadline();
 
-		assert_matches!(
-			event,
-			crate::mock::RuntimeEvent::Creditcoin(crate::Event::<Test>::CurrencyRegistered(registered_id, registered_currency)) => {
-				assert_eq!(registered_id, id);
-				assert_eq!(registered_currency, currency);
-			}
-		);
-	})
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(authority.clone()),
+			deadline,
+			transfer_id.clone().into(),
+			crate::ocw::errors::VerificationFailureCause::TaskFailed
+		));
+
+		let lender = &test_info.lender.account_id;
+		assert_eq!(Balances::reserved_balance(lender), 0);
+		assert_eq!(Balances::free_balance(lender), 10 * TASK_FEE);
+		assert_eq!(Balances::free_balance(&authority), 10 * TASK_FEE);
+		assert_eq!(crate::TaskFees::<Test>::get(deadline, crate::TaskId::from(transfer_id)), None);
+	});
 }
 
 #[test]
-fn register_currency_should_error_when_currency_already_registered() {
+fn task_fee_should_be_refunded_when_task_expires() {
 	ExtBuilder::default().build_and_execute(|| {
-		let currency = Currency::default();
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, _) = set_up_task_fee_test(&test_info, &[]);
+		let deadline = Test::unverified_transfer_deadline();
 
-		assert_ok!(Creditcoin::register_currency(Origin::root(), currency.clone()));
+		Creditcoin::on_initialize(deadline);
 
-		assert_noop!(
-			Creditcoin::register_currency(Origin::root(), currency),
-			TestError::CurrencyAlreadyRegistered
-		);
-	})
+		let lender = &test_info.lender.account_id;
+		assert_eq!(Balances::reserved_balance(lender), 0);
+		assert_eq!(Balances::free_balance(lender), 10 * TASK_FEE);
+		assert_eq!(crate::TaskFees::<Test>::get(deadline, crate::TaskId::from(transfer_id)), None);
+	});
 }
 
 #[test]
-fn exercise_weightinfo_functions() {
-	let result = super::weights::WeightInfo::<Test>::register_address();
-	assert!(result.ref_time() > 0);
+fn on_initialize_should_report_ended_reward_era() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
+		crate::EraRewardTotals::<Test>::insert(0u64, TASK_FEE);
+		let era_length: BlockNumber = <Test as Config>::RewardEraLength::get();
 
-	let result = super::weights::WeightInfo::<Test>::claim_legacy_wallet();
-	assert!(result.ref_time() > 0);
+		Creditcoin::on_initialize(era_length - 1);
+		assert!(!creditcoin_events().contains(&crate::Event::RewardEraEnded(0, TASK_FEE)));
 
-	let result = super::weights::WeightInfo::<Test>::add_ask_order();
-	assert!(result.ref_time() > 0);
+		Creditcoin::on_initialize(era_length);
+		assert!(creditcoin_events().contains(&crate::Event::RewardEraEnded(0, TASK_FEE)));
+		assert_eq!(Creditcoin::reward_era(era_length), 1);
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::add_bid_order();
-	assert!(result.ref_time() > 0);
+#[test]
+fn on_initialize_should_prune_rewards_after_retention() {
+	ExtBuilder::default().build_and_execute(|| {
+		roll_to(1);
+		let authority = AccountId::new([1; 32]);
+		crate::EraRewards::<Test>::insert(0u64, &authority, TASK_FEE);
+		crate::EraRewardTotals::<Test>::insert(0u64, TASK_FEE);
+		let era_length: BlockNumber = <Test as Config>::RewardEraLength::get();
+		let retention: BlockNumber = <Test as Config>::RewardEraRetention::get();
+
+		Creditcoin::on_initialize(retention * era_length);
+		assert_eq!(Creditcoin::era_rewards(0u64, &authority), TASK_FEE);
+		assert_eq!(Creditcoin::era_reward_totals(0u64), TASK_FEE);
+
+		Creditcoin::on_initialize((retention + 1) * era_length);
+		assert_eq!(Creditcoin::era_rewards(0u64, &authority), 0);
+		assert_eq!(Creditcoin::era_reward_totals(0u64), 0);
+		assert!(!crate::EraRewardTotals::<Test>::contains_key(0u64));
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::add_offer();
-	assert!(result.ref_time() > 0);
+#[test]
+fn set_task_fee_errors_for_non_root() {
+	ExtBuilder::default().build_and_execute(|| {
+		let acct: AccountId = AccountId::new([0; 32]);
 
-	let result = super::weights::WeightInfo::<Test>::add_deal_order();
-	assert!(result.ref_time() > 0);
+		assert_noop!(Creditcoin::set_task_fee(Origin::signed(acct), TASK_FEE), BadOrigin);
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::add_authority();
-	assert!(result.ref_time() > 0);
+fn set_up_task_status_test(test_info: &TestInfo, authority: &AccountId) -> TestTransfer {
+	assert_ok!(Creditcoin::add_authority(Origin::from(RawOrigin::Root), authority.clone()));
+
+	let (deal_order_id, deal_order) = test_info.create_deal_order();
+	let tx = "0xfafafa";
+	assert_ok!(Creditcoin::register_funding_transfer(
+		Origin::signed(test_info.lender.account_id.clone()),
+		TransferKind::Evm(EvmTransferKind::Ethless),
+		deal_order_id.clone(),
+		tx.hex_to_address()
+	));
+	test_info.make_transfer(
+		&test_info.lender,
+		&test_info.borrower,
+		deal_order.terms.amount,
+		&deal_order_id,
+		tx,
+		None::<TransferKind>,
+	)
+}
 
-	let result = super::weights::WeightInfo::<Test>::persist_transfer();
-	assert!(result.ref_time() > 0);
+#[test]
+fn register_funding_transfer_should_track_pending_task() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, _) = set_up_task_status_test(&test_info, &AccountId::new([1; 32]));
+		let deadline = Test::unverified_transfer_deadline();
 
-	let result = super::weights::WeightInfo::<Test>::fail_transfer();
-	assert!(result.ref_time() > 0);
+		assert_eq!(
+			Creditcoin::task_statuses(crate::TaskId::from(transfer_id)),
+			Some(crate::TaskStatus::Pending(deadline))
+		);
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::fund_deal_order();
-	assert!(result.ref_time() > 0);
+#[test]
+fn persist_task_output_should_mark_task_completed() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, transfer) = set_up_task_status_test(&test_info, &authority);
+		let deadline = Test::unverified_transfer_deadline();
 
-	let result = super::weights::WeightInfo::<Test>::lock_deal_order();
-	assert!(result.ref_time() > 0);
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(authority),
+			deadline,
+			(transfer_id.clone(), transfer).into(),
+		));
 
-	let result = super::weights::WeightInfo::<Test>::register_funding_transfer();
-	assert!(result.ref_time() > 0);
+		let task_id = crate::TaskId::from(transfer_id);
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Completed));
 
-	let result = super::weights::WeightInfo::<Test>::register_repayment_transfer();
-	assert!(result.ref_time() > 0);
+		// the status outlives the scheduler's cleanup of the task
+		roll_to(deadline);
+		Creditcoin::on_initialize(deadline);
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Completed));
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::close_deal_order();
-	assert!(result.ref_time() > 0);
+#[test]
+fn fail_task_should_record_failure_cause() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, _) = set_up_task_status_test(&test_info, &authority);
+		let deadline = Test::unverified_transfer_deadline();
+		let cause = crate::ocw::errors::VerificationFailureCause::IncorrectAmount;
 
-	let result = super::weights::WeightInfo::<Test>::exempt();
-	assert!(result.ref_time() > 0);
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(authority),
+			deadline,
+			transfer_id.clone().into(),
+			cause
+		));
 
-	let result = super::weights::WeightInfo::<Test>::register_deal_order();
-	assert!(result.ref_time() > 0);
+		assert_eq!(
+			Creditcoin::task_statuses(crate::TaskId::from(transfer_id)),
+			Some(crate::TaskStatus::Failed(cause))
+		);
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::request_collect_coins();
-	assert!(result.ref_time() > 0);
+#[test]
+fn exhausted_task_retries_should_fail_task_with_quorum() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let other = AccountId::new([2; 32]);
+		let (transfer_id, _) = set_up_task_status_test(&test_info, &authority);
+		assert_ok!(Creditcoin::add_authority(Origin::from(RawOrigin::Root), other.clone()));
+		assert_ok!(Creditcoin::set_quorum_threshold(Origin::from(RawOrigin::Root), 2));
+		let deadline = Test::unverified_transfer_deadline();
+		let cause = crate::ocw::errors::VerificationFailureCause::RetriesExhausted;
 
-	let result = super::weights::WeightInfo::<Test>::fail_collect_coins();
-	assert!(result.ref_time() > 0);
+		let task = TaskScheduler::pending_tasks(deadline, *transfer_id.as_ref()).unwrap();
+		let call = ForwardTask::<Test>::retries_exhausted(&task, deadline).unwrap();
+		assert_eq!(
+			call,
+			RuntimeCall::Creditcoin(crate::Call::fail_task {
+				deadline,
+				task_id: transfer_id.clone().into(),
+				cause
+			})
+		);
 
-	let result = super::weights::WeightInfo::<Test>::persist_collect_coins();
-	assert!(result.ref_time() > 0);
+		assert_ok!(call.clone().dispatch(Origin::signed(authority)));
+		assert_ok!(call.dispatch(Origin::signed(other)));
 
-	let result = super::weights::WeightInfo::<Test>::remove_authority();
-	assert!(result.ref_time() > 0);
+		assert_eq!(
+			Creditcoin::task_statuses(crate::TaskId::from(transfer_id)),
+			Some(crate::TaskStatus::Failed(cause))
+		);
+	});
+}
 
-	let result = super::weights::WeightInfo::<Test>::set_collect_coins_contract();
-	assert!(result.ref_time() > 0);
+#[test]
+fn exhausted_task_retries_should_fail_task_with_default_threshold() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, _) = set_up_task_status_test(&test_info, &authority);
+		let deadline = Test::unverified_transfer_deadline();
+		let cause = crate::ocw::errors::VerificationFailureCause::RetriesExhausted;
+		assert_eq!(Creditcoin::quorum_threshold(), 0);
 
-	let result = super::weights::WeightInfo::<Test>::register_currency();
-	assert!(result.ref_time() > 0);
+		let task = TaskScheduler::pending_tasks(deadline, *transfer_id.as_ref()).unwrap();
+		let call = ForwardTask::<Test>::retries_exhausted(&task, deadline).unwrap();
+		assert_eq!(
+			call,
+			RuntimeCall::Creditcoin(crate::Call::fail_task {
+				deadline,
+				task_id: transfer_id.clone().into(),
+				cause
+			})
+		);
+
+		assert_ok!(call.dispatch(Origin::signed(authority)));
+
+		assert_eq!(
+			Creditcoin::task_statuses(crate::TaskId::from(transfer_id)),
+			Some(crate::TaskStatus::Failed(cause))
+		);
+	});
 }
 
 #[test]
-fn remove_authority_errors_for_non_root() {
+fn task_status_should_expire_at_deadline_and_be_pruned_after_retention() {
 	ExtBuilder::default().build_and_execute(|| {
-		let acct: AccountId = AccountId::new([0; 32]);
+		let test_info = TestInfo::new_defaults();
+		let (transfer_id, _) = set_up_task_status_test(&test_info, &AccountId::new([1; 32]));
+		let deadline = Test::unverified_transfer_deadline();
+		let task_id = crate::TaskId::from(transfer_id);
 
-		assert_noop!(Creditcoin::remove_authority(Origin::signed(acct.clone()), acct), BadOrigin);
+		roll_to(deadline);
+		Creditcoin::on_initialize(deadline);
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Expired));
+
+		let pruned_at = deadline + <Test as Config>::TaskStatusRetention::get();
+		roll_to(pruned_at - 1);
+		Creditcoin::on_initialize(pruned_at - 1);
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Expired));
+
+		roll_to(pruned_at);
+		Creditcoin::on_initialize(pruned_at);
+		assert_eq!(Creditcoin::task_statuses(&task_id), None);
 	});
 }
 
 #[test]
-fn remove_authority_should_fail_when_authority_does_not_exist() {
+fn task_status_should_not_be_expired_by_earlier_registration() {
 	ExtBuilder::default().build_and_execute(|| {
-		let root = RawOrigin::Root;
-		let acct: AccountId = AccountId::new([0; 32]);
+		roll_to(1);
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, transfer) = set_up_task_status_test(&test_info, &authority);
+		let task_id = crate::TaskId::from(transfer_id);
 
-		assert_noop!(
-			Creditcoin::remove_authority(crate::mock::RuntimeOrigin::from(root), acct),
-			crate::Error::<Test>::NotAnAuthority,
-		);
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(authority),
+			Test::unverified_transfer_deadline(),
+			task_id.clone(),
+			crate::ocw::errors::VerificationFailureCause::TaskFailed
+		));
+		let pruned_at = 1 + <Test as Config>::TaskStatusRetention::get();
+
+		roll_to(pruned_at - 3);
+		assert_ok!(Creditcoin::register_funding_transfer(
+			Origin::signed(test_info.lender.account_id.clone()),
+			TransferKind::Evm(EvmTransferKind::Ethless),
+			transfer.deal_order_id,
+			transfer.tx_id
+		));
+		let deadline = Test::unverified_transfer_deadline();
+		assert!(deadline > pruned_at);
+		assert_eq!(crate::TaskStatusUpdates::<Test>::get(pruned_at, &task_id), None);
+
+		roll_to(pruned_at);
+		Creditcoin::on_initialize(pruned_at);
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Pending(deadline)));
+
+		roll_to(deadline);
+		Creditcoin::on_initialize(deadline);
+		assert_eq!(Creditcoin::task_statuses(&task_id), Some(crate::TaskStatus::Expired));
 	});
 }
 
 #[test]
-fn add_and_remove_authority_works_for_root() {
+fn persist_task_output_should_complete_scheduled_task() {
 	ExtBuilder::default().build_and_execute(|| {
-		let root = RawOrigin::Root;
-		let account: AccountId = AccountId::new([0; 32]);
+		roll_to(1);
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, transfer) = set_up_task_status_test(&test_info, &authority);
+		let deadline = Test::unverified_transfer_deadline();
+		assert!(Test::is_scheduled(&deadline, transfer_id.as_ref()));
 
-		assert!(!<Test as Config>::TaskScheduler::is_authority(&account));
-		<Test as Config>::TaskScheduler::insert_authority(&account);
-		assert!(<Test as Config>::TaskScheduler::is_authority(&account));
+		assert_ok!(Creditcoin::persist_task_output(
+			Origin::signed(authority),
+			deadline,
+			(transfer_id.clone(), transfer).into(),
+		));
 
-		assert_ok!(Creditcoin::remove_authority(Origin::from(root), account.clone()));
-		assert!(!<Test as Config>::TaskScheduler::is_authority(&account));
+		assert!(!Test::is_scheduled(&deadline, transfer_id.as_ref()));
+		assert!(System::events().into_iter().any(|record| record.event
+			== RuntimeEvent::TaskScheduler(pallet_offchain_task_scheduler::Event::TaskCompleted(
+				*transfer_id.as_ref(),
+				deadline
+			))));
+	});
+}
+
+#[test]
+fn fail_task_should_complete_scheduled_task() {
+	ExtBuilder::default().build_and_execute(|| {
+		let test_info = TestInfo::new_defaults();
+		let authority = AccountId::new([1; 32]);
+		let (transfer_id, _) = set_up_task_status_test(&test_info, &authority);
+		let deadline = Test::unverified_transfer_deadline();
+
+		assert_ok!(Creditcoin::fail_task(
+			Origin::signed(authority),
+			deadline,
+			transfer_id.clone().into(),
+			crate::ocw::errors::VerificationFailureCause::TaskFailed
+		));
+
+		assert!(!Test::is_scheduled(&deadline, transfer_id.as_ref()));
 	});
 }
@@ -1,9 +1,11 @@
 mod collect_coins;
+mod credit_history;
 pub mod loan_terms;
 pub mod platform;
 mod transfer;
 
 pub use collect_coins::*;
+pub use credit_history::*;
 pub use loan_terms::*;
 pub use platform::*;
 pub use transfer::*;
@@ -37,6 +39,13 @@ type ExternalTxIdLen = ConstU32<256>;
 pub type ExternalTxId = BoundedVec<u8, ExternalTxIdLen>;
 type OtherChainLen = ConstU32<256>;
 pub type OtherChain = BoundedVec<u8, OtherChainLen>;
+type MaxRepayments = ConstU32<32>;
+pub type Repayments<Hash> = BoundedVec<TransferId<Hash>, MaxRepayments>;
+type MaxQueuedOrders = ConstU32<1024>;
+pub type QueuedOrders<BlockNum, Hash> = BoundedVec<OrderId<BlockNum, Hash>, MaxQueuedOrders>;
+type MaxAmendments = ConstU32<16>;
+pub type Amendments<BlockNum, Moment> =
+	BoundedVec<DealOrderAmendment<BlockNum, Moment>, MaxAmendments>;
 type OtherTransferKindLen = ConstU32<256>;
 pub type OtherTransferKind = BoundedVec<u8, OtherTransferKindLen>;
 
@@ -107,6 +116,30 @@ pub struct DealOrder<AccountId, BlockNum, Hash, Moment> {
 	pub repayment_transfer_id: Option<TransferId<Hash>>,
 	pub lock: Option<AccountId>,
 	pub borrower: AccountId,
+	pub repaid_amount: ExternalAmount,
+	pub repayments: Repayments<Hash>,
+	pub accrual: Option<Accrual<Moment>>,
+}
+
+/// The interest accrued on a deal order as of its last installment or renegotiation. Interest
+/// keeps accruing on the outstanding `principal` from then on, counting the interest periods
+/// started since `since`. `None` on a deal order means nothing has been repaid or renegotiated
+/// since it was funded.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Accrual<Moment> {
+	pub principal: ExternalAmount,
+	pub interest: ExternalAmount,
+	pub since: Moment,
+	pub periods: u128,
+}
+
+/// The terms a deal order had before it was renegotiated, and when they were replaced.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct DealOrderAmendment<BlockNum, Moment> {
+	pub term_length: Duration,
+	pub interest_rate: InterestRate,
+	pub block: BlockNum,
+	pub timestamp: Moment,
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -130,8 +163,16 @@ pub struct AskOrderId<BlockNum, Hash>(BlockNum, Hash);
 pub struct BidOrderId<BlockNum, Hash>(BlockNum, Hash);
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct DealOrderId<BlockNum, Hash>(BlockNum, Hash);
 
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum OrderId<BlockNum, Hash> {
+	Ask(AskOrderId<BlockNum, Hash>),
+	Bid(BidOrderId<BlockNum, Hash>),
+}
+
 #[cfg(test)]
 impl<B: Default, H: Default> DealOrderId<B, H> {
 	pub fn dummy() -> Self {
@@ -410,6 +451,8 @@ impl<AccountId, BlockNum, Hash, Moment> From<UnverifiedCollectedCoins>
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub enum TaskId<Hash> {
 	VerifyTransfer(TransferId<Hash>),
 	CollectCoins(CollectedCoinsId<Hash>),
@@ -427,6 +470,27 @@ impl<Hash> From<CollectedCoinsId<Hash>> for TaskId<Hash> {
 	}
 }
 
+/// The id the task is scheduled under.
+impl<Hash> AsRef<Hash> for TaskId<Hash> {
+	fn as_ref(&self) -> &Hash {
+		match self {
+			TaskId::VerifyTransfer(id) => id.as_ref(),
+			TaskId::CollectCoins(id) => id.as_ref(),
+		}
+	}
+}
+
+impl<AccountId, Balance, BlockNum, Hash: Clone, Moment>
+	From<&TaskOutput<AccountId, Balance, BlockNum, Hash, Moment>> for TaskId<Hash>
+{
+	fn from(output: &TaskOutput<AccountId, Balance, BlockNum, Hash, Moment>) -> Self {
+		match output {
+			TaskOutput::VerifyTransfer(id, _) => TaskId::VerifyTransfer(id.clone()),
+			TaskOutput::CollectCoins(id, _) => TaskId::CollectCoins(id.clone()),
+		}
+	}
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum TaskOutput<AccountId, Balance, BlockNum, Hash, Moment> {
 	VerifyTransfer(TransferId<Hash>, Transfer<AccountId, BlockNum, Hash, Moment>),
@@ -453,6 +517,21 @@ impl<AccountId, Balance, BlockNum, Hash, Moment>
 	}
 }
 
+/// The state of a verification task, kept for a while after the task leaves the scheduler.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum TaskStatus<BlockNum> {
+	/// The task is waiting to be verified and expires at the given deadline.
+	Pending(BlockNum),
+	/// The task's output was verified and persisted.
+	Completed,
+	/// The task failed verification for the given cause.
+	Failed(VerificationFailureCause),
+	/// The task reached its deadline without being completed or failed.
+	Expired,
+}
+
 #[cfg(test)]
 pub(crate) mod test {
 	use crate::{
@@ -615,6 +694,8 @@ pub(crate) mod test {
 	ask_order: AskOrder<AccountId, BlockNum, Hash> : TestInfo::new_defaults().create_ask_order().1,
 	bid_order: BidOrder<AccountId, BlockNum, Hash> : TestInfo::new_defaults().create_bid_order().1,
 	deal_order: DealOrder<AccountId, BlockNum, Hash, Moment> : TestInfo::new_defaults().create_deal_order().1,
+	accrual: Accrual<Moment> : Accrual { principal: 100.into(), interest: 1.into(), since: 0, periods: 1 },
+	deal_order_amendment: DealOrderAmendment<BlockNum, Moment> : DealOrderAmendment { term_length: Duration::from_millis(100), interest_rate: InterestRate::default(), block: 1, timestamp: 0 },
 	address_id: AddressId<Hash> : AddressId::new::<mock::Test>(&Blockchain::RINKEBY, b"0"),
 	ask_order_id: AskOrderId<BlockNum, Hash> : TestInfo::new_defaults().create_ask_order().0,
 	bid_order_id: BidOrderId<BlockNum, Hash> : TestInfo::new_defaults().create_bid_order().0,
@@ -628,11 +709,17 @@ pub(crate) mod test {
 	task_output: TaskOutput<AccountId, Balance, BlockNum, Hash, Moment> : TaskOutput::<AccountId, Balance, BlockNum, Hash, Moment>::from(
 		create_funding_transfer()
 	),
+	task_status: TaskStatus<BlockNum> : TaskStatus::Failed(ocw::VerificationFailureCause::TaskFailed),
+	// from types/credit_history.rs
+	credit_record: CreditRecord : CreditRecord { loans_taken: 1, ..Default::default() },
+	credit_history: CreditHistory<Hash> : CreditHistory { record: CreditRecord::default(), volume: vec![(CurrencyId::new::<mock::Test>(&Currency::default()), 1u64.into())] },
+
 	// from types/loan_terms.rs
 	duration: Duration : Duration::from_millis(100),
 	interest_type: InterestType : InterestType::Simple,
 	interest_rate: InterestRate : InterestRate::default(),
 	loan_terms: LoanTerms<Hash> : TestInfo::new_defaults().loan_terms,
+	loan_term_ranges: LoanTermRanges<Hash> : LoanTermRanges::from(TestInfo::new_defaults().loan_terms),
 	ask_terms: AskTerms<Hash> : AskTerms::try_from(TestInfo::new_defaults().loan_terms).unwrap(),
 	bid_terms: BidTerms<Hash> : BidTerms::try_from(TestInfo::new_defaults().loan_terms).unwrap(),
 
@@ -640,13 +727,16 @@ pub(crate) mod test {
 	evm_chain_id: EvmChainId : EvmChainId::from(44),
 	evm_info: EvmInfo : EvmInfo { chain_id: 0.into() },
 	blockchain: Blockchain : Blockchain::Evm(EvmInfo { chain_id: 0.into() }),
+	bitcoin_network: BitcoinNetwork : BitcoinNetwork::Testnet,
 	evm_transfer_kind: EvmTransferKind : EvmTransferKind::Erc20,
 	evm_currency_type: EvmCurrencyType : match Currency::default() {
 		Currency::Evm(currency_type, _) => currency_type,
+		Currency::Bitcoin(_) => unreachable!(),
 	},
 	currency: Currency : Currency::default(),
 	transfer_kind: TransferKind : TransferKind::Evm(EvmTransferKind::Erc20),
 	currency_id: CurrencyId<Hash> : CurrencyId::new::<mock::Test>(&Currency::default()),
+	confirmation_policy: ConfirmationPolicy : ConfirmationPolicy::Blocks(12),
 	}
 
 	#[test]
@@ -721,4 +811,12 @@ pub(crate) mod test {
 		let new_value = value.clone();
 		format!("{new_value:?}");
 	}
+
+	#[test]
+	#[allow(clippy::clone_on_copy)]
+	fn exercise_invalid_term_ranges_error_clone_and_runtime_debug() {
+		let value = InvalidTermRangesError::EmptyRange;
+		let new_value = value.clone();
+		format!("{new_value:?}");
+	}
 }
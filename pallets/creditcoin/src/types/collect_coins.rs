@@ -26,6 +26,7 @@ impl UnverifiedCollectedCoins {
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct CollectedCoinsId<Hash>(Hash);
 
 impl<H> CollectedCoinsId<H> {
@@ -55,7 +56,13 @@ impl<H> From<H> for CollectedCoinsId<H> {
 	}
 }
 
-use crate::ocw::errors::SchedulerError;
+impl<H> AsRef<H> for CollectedCoinsId<H> {
+	fn as_ref(&self) -> &H {
+		&self.0
+	}
+}
+
+use crate::ocw::errors::{OffchainError, SchedulerError};
 use crate::ocw::tasks::OffchainVerification;
 use crate::ocw::VerificationFailureCause;
 use crate::ocw::VerificationResult;
@@ -80,7 +87,7 @@ where
 	<UnverifiedCollectedCoins as OffchainVerification<T>>::Output: Into<T::Balance>,
 {
 	type Call = crate::pallet::Call<T>;
-	type EvaluationError = VerificationFailureCause;
+	type EvaluationError = OffchainError;
 	type SchedulerError = SchedulerError;
 	fn to_id(&self) -> T::Hash {
 		CollectedCoinsId::inner_hash::<T::Hashing>(&self.contract.chain, self.tx_id.as_slice())
@@ -106,13 +113,18 @@ where
 				let id = CollectedCoinsId::from(*id);
 				Ok(Self::Call::fail_task { deadline, task_id: id.into(), cause })
 			},
-			Err(InvalidTask(e)) => Err(TaskError::Evaluation(e)),
+			Err(e @ (InvalidTask(_) | RpcError(_))) => Err(TaskError::Evaluation(e)),
 			Err(NoRpcUrl(e)) => Err(TaskError::Scheduler(e.into())),
-			Err(RpcError(e)) => Err(TaskError::Scheduler(e.into())),
 			Err(IncorrectChainId) => Err(TaskError::Scheduler(SchedulerError::IncorrectChainId)),
 		}
 	}
 
+	fn retries_exhausted(&self, deadline: T::BlockNumber, id: &T::Hash) -> Option<Self::Call> {
+		let id = CollectedCoinsId::from(*id);
+		let cause = VerificationFailureCause::RetriesExhausted;
+		Some(Self::Call::fail_task { deadline, task_id: id.into(), cause })
+	}
+
 	fn is_persisted(id: &T::Hash) -> bool {
 		let id = CollectedCoinsId::from(*id);
 		crate::pallet::CollectedCoins::<T>::contains_key(id)
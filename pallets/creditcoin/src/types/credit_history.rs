@@ -0,0 +1,24 @@
+use super::*;
+
+/// Counts of a borrower's loans by outcome.
+#[derive(
+	Clone, Copy, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CreditRecord {
+	pub loans_taken: u32,
+	pub repaid_on_time: u32,
+	pub repaid_late: u32,
+	pub defaulted: u32,
+	pub exempted: u32,
+}
+
+/// A borrower's credit record along with the principal they have borrowed in each currency.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct CreditHistory<Hash> {
+	pub record: CreditRecord,
+	pub volume: Vec<(CurrencyId<Hash>, ExternalAmount)>,
+}
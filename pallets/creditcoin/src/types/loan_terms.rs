@@ -1,4 +1,4 @@
-use core::ops::Deref;
+use core::{cmp::Ordering, ops::Deref};
 
 use crate::CurrencyId;
 
@@ -10,7 +10,19 @@ use sp_std::convert::TryFrom;
 
 pub type RatePerPeriod = u64;
 pub type Decimals = u64;
-#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	Eq,
+	PartialEq,
+	PartialOrd,
+	Ord,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+)]
 pub struct Duration {
 	secs: u64,
 	nanos: u32,
@@ -18,6 +30,7 @@ pub struct Duration {
 
 const MILLIS_PER_SEC: u64 = 1_000;
 const NANOS_PER_MILLI: u32 = 1_000_000;
+const COMPOUNDING_PRECISION: usize = 18;
 
 impl Duration {
 	pub const fn new(secs: u64, nanos: u32) -> Self {
@@ -33,6 +46,10 @@ impl Duration {
 	pub const fn is_zero(&self) -> bool {
 		self.secs == 0 && self.nanos == 0
 	}
+
+	pub const fn as_millis(&self) -> u128 {
+		self.secs as u128 * MILLIS_PER_SEC as u128 + (self.nanos / NANOS_PER_MILLI) as u128
+	}
 }
 
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -49,6 +66,130 @@ pub struct InterestRate {
 	pub interest_type: InterestType,
 }
 
+/// The period and interest type of a rate. Only rates of the same class can be compared.
+pub type RateClass = (Duration, InterestType);
+
+/// A rate scaled to [`RATE_KEY_DECIMALS`] decimals and encoded big-endian, so that keys compare
+/// in the same order as the rates of a class they were made from.
+pub type RateKey = [u8; 32];
+
+const RATE_KEY_DECIMALS: Decimals = 38;
+
+#[derive(Clone, Copy, RuntimeDebug)]
+pub struct InterestOverflowError;
+
+impl<T: crate::Config> From<InterestOverflowError> for crate::Error<T> {
+	fn from(_: InterestOverflowError) -> Self {
+		Self::InterestOverflow
+	}
+}
+
+impl InterestRate {
+	/// Compares the rate charged per period with that of `other`. Rates are only comparable
+	/// when they share the same period and interest type.
+	pub fn compare(&self, other: &InterestRate) -> Option<Ordering> {
+		if self.period != other.period || self.interest_type != other.interest_type {
+			return None;
+		}
+		let ten = ExternalAmount::from(10u64);
+		let scale = |rate: RatePerPeriod, decimals: Decimals| {
+			ten.checked_pow(decimals.into())?.checked_mul(rate.into())
+		};
+		let ours = scale(self.rate_per_period, other.decimals)?;
+		let theirs = scale(other.rate_per_period, self.decimals)?;
+		Some(ours.cmp(&theirs))
+	}
+
+	pub fn class(&self) -> RateClass {
+		(self.period, self.interest_type)
+	}
+
+	/// The rate per period as a key which sorts like the rate among rates of the same class.
+	/// Rates with more than [`RATE_KEY_DECIMALS`] decimals are rounded down, so a key that is
+	/// greater than another always belongs to a greater rate.
+	pub fn sort_key(&self) -> RateKey {
+		let ten = ExternalAmount::from(10u64);
+		let rate = ExternalAmount::from(self.rate_per_period);
+		let scaled = if self.decimals <= RATE_KEY_DECIMALS {
+			// a u64 rate times 10^38 fits comfortably in 256 bits
+			rate * ten.pow((RATE_KEY_DECIMALS - self.decimals).into())
+		} else {
+			ten.checked_pow((self.decimals - RATE_KEY_DECIMALS).into())
+				.map_or_else(ExternalAmount::zero, |divisor| rate / divisor)
+		};
+
+		let mut key = RateKey::default();
+		scaled.to_big_endian(&mut key);
+		key
+	}
+
+	/// The number of interest periods started within `elapsed`.
+	/// A period that has only partially elapsed accrues interest as if it were complete.
+	pub fn periods_in(&self, elapsed: &Duration) -> u128 {
+		let period = self.period.as_millis();
+		if period == 0 {
+			return 0;
+		}
+		let elapsed = elapsed.as_millis();
+		elapsed / period + u128::from(elapsed % period != 0)
+	}
+
+	/// The interest accrued on `principal` once `elapsed` time has passed.
+	/// The rate applied on each period is `rate_per_period / 10^decimals`.
+	pub fn accrued_interest(
+		&self,
+		principal: ExternalAmount,
+		elapsed: &Duration,
+	) -> Result<ExternalAmount, InterestOverflowError> {
+		self.interest_for_periods(principal, self.periods_in(elapsed))
+	}
+
+	/// The interest accrued on `principal` over `periods` interest periods.
+	pub fn interest_for_periods(
+		&self,
+		principal: ExternalAmount,
+		periods: u128,
+	) -> Result<ExternalAmount, InterestOverflowError> {
+		if periods == 0 || self.rate_per_period == 0 || principal.is_zero() {
+			return Ok(ExternalAmount::zero());
+		}
+
+		let one = ExternalAmount::from(10u64)
+			.checked_pow(self.decimals.into())
+			.ok_or(InterestOverflowError)?;
+		let rate = ExternalAmount::from(self.rate_per_period);
+
+		let interest = match self.interest_type {
+			InterestType::Simple => principal
+				.checked_mul(rate)
+				.and_then(|x| x.checked_mul(periods.into()))
+				.map(|x| x / one),
+			InterestType::Compound => {
+				// (1 + rate)^periods in fixed point, with at least 18 digits of precision
+				let unit = one.max(ExternalAmount::exp10(COMPOUNDING_PRECISION));
+				let mut factor = unit;
+				let mut base = rate
+					.checked_mul(unit / one)
+					.and_then(|rate| rate.checked_add(unit))
+					.ok_or(InterestOverflowError)?;
+				let mut exp = periods;
+				while exp > 0 {
+					if exp & 1 == 1 {
+						factor = factor.checked_mul(base).ok_or(InterestOverflowError)? / unit;
+					}
+					exp >>= 1;
+					if exp > 0 {
+						base = base.checked_mul(base).ok_or(InterestOverflowError)? / unit;
+					}
+				}
+				principal.checked_mul(factor).map(|x| x / unit - principal)
+			},
+		};
+
+		interest.ok_or(InterestOverflowError)
+	}
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub struct LoanTerms<Hash> {
 	pub amount: ExternalAmount,
@@ -57,11 +198,54 @@ pub struct LoanTerms<Hash> {
 	pub currency: CurrencyId<Hash>,
 }
 
+impl<Hash> LoanTerms<Hash> {
+	/// The principal and the interest accrued once `elapsed` time has passed since funding.
+	pub fn amount_due(&self, elapsed: &Duration) -> Result<AmountDue, InterestOverflowError> {
+		let interest = self.interest_rate.accrued_interest(self.amount, elapsed)?;
+		let total = self.amount.checked_add(interest).ok_or(InterestOverflowError)?;
+		Ok(AmountDue { principal: self.amount, interest, total })
+	}
+}
+
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AmountDue {
+	pub principal: ExternalAmount,
+	pub interest: ExternalAmount,
+	pub total: ExternalAmount,
+}
+
+/// The range of terms a lender or borrower is willing to accept.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct LoanTermRanges<Hash> {
+	pub min_amount: ExternalAmount,
+	pub max_amount: ExternalAmount,
+	/// The lowest rate a lender accepts, or the highest rate a borrower accepts.
+	pub interest_rate: InterestRate,
+	pub min_term_length: Duration,
+	pub max_term_length: Duration,
+	pub currency: CurrencyId<Hash>,
+}
+
+impl<Hash> From<LoanTerms<Hash>> for LoanTermRanges<Hash> {
+	fn from(terms: LoanTerms<Hash>) -> Self {
+		Self {
+			min_amount: terms.amount,
+			max_amount: terms.amount,
+			interest_rate: terms.interest_rate,
+			min_term_length: terms.term_length,
+			max_term_length: terms.term_length,
+			currency: terms.currency,
+		}
+	}
+}
+
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct AskTerms<Hash>(LoanTerms<Hash>);
+pub struct AskTerms<Hash>(LoanTermRanges<Hash>);
 
 impl<Hash> Deref for AskTerms<Hash> {
-	type Target = LoanTerms<Hash>;
+	type Target = LoanTermRanges<Hash>;
 
 	fn deref(&self) -> &Self::Target {
 		&self.0
@@ -77,6 +261,47 @@ impl<T: crate::Config> From<InvalidTermLengthError> for crate::Error<T> {
 	}
 }
 
+#[derive(Clone, Copy, RuntimeDebug)]
+pub enum InvalidTermRangesError {
+	TermLength,
+	EmptyRange,
+}
+
+impl From<InvalidTermLengthError> for InvalidTermRangesError {
+	fn from(_: InvalidTermLengthError) -> Self {
+		Self::TermLength
+	}
+}
+
+impl<T: crate::Config> From<InvalidTermRangesError> for crate::Error<T> {
+	fn from(err: InvalidTermRangesError) -> Self {
+		match err {
+			InvalidTermRangesError::TermLength => Self::InvalidTermLength,
+			InvalidTermRangesError::EmptyRange => Self::InvalidTermRange,
+		}
+	}
+}
+
+impl<Hash> LoanTermRanges<Hash> {
+	fn validate(self) -> Result<Self, InvalidTermRangesError> {
+		if self.min_term_length.is_zero() {
+			return Err(InvalidTermRangesError::TermLength);
+		}
+		if self.min_amount > self.max_amount || self.min_term_length > self.max_term_length {
+			return Err(InvalidTermRangesError::EmptyRange);
+		}
+
+		Ok(self)
+	}
+}
+
+impl<Hash> TryFrom<LoanTermRanges<Hash>> for AskTerms<Hash> {
+	type Error = InvalidTermRangesError;
+	fn try_from(terms: LoanTermRanges<Hash>) -> Result<Self, Self::Error> {
+		terms.validate().map(Self)
+	}
+}
+
 impl<Hash> TryFrom<LoanTerms<Hash>> for AskTerms<Hash> {
 	type Error = InvalidTermLengthError;
 	fn try_from(terms: LoanTerms<Hash>) -> Result<Self, Self::Error> {
@@ -84,33 +309,64 @@ impl<Hash> TryFrom<LoanTerms<Hash>> for AskTerms<Hash> {
 			return Err(InvalidTermLengthError);
 		}
 
-		Ok(Self(terms))
+		Ok(Self(terms.into()))
 	}
 }
 
-impl<Hash> AskTerms<Hash> {
+impl<Hash: PartialEq> AskTerms<Hash> {
+	/// The largest amount and longest term length both sides accept, if the ranges overlap
+	/// and the borrower accepts the lender's rate.
+	fn overlap(&self, bid_terms: &BidTerms<Hash>) -> Option<(ExternalAmount, Duration)> {
+		if self.currency != bid_terms.currency {
+			return None;
+		}
+		if self.interest_rate.compare(&bid_terms.interest_rate)? == Ordering::Greater {
+			return None;
+		}
+
+		let amount = self.max_amount.min(bid_terms.max_amount);
+		let term_length = self.max_term_length.min(bid_terms.max_term_length);
+		let acceptable = amount >= self.min_amount.max(bid_terms.min_amount)
+			&& term_length >= self.min_term_length.max(bid_terms.min_term_length);
+
+		acceptable.then_some((amount, term_length))
+	}
+
 	pub fn match_with(&self, bid_terms: &BidTerms<Hash>) -> bool {
-		self.amount == bid_terms.amount
-			&& self.interest_rate == bid_terms.interest_rate
-			&& self.term_length == bid_terms.term_length
+		self.overlap(bid_terms).is_some()
 	}
 
+	/// The terms a deal between the two orders is made on: the largest amount and longest
+	/// term length both sides accept, at the lender's rate.
 	pub fn agreed_terms(&self, bid_terms: BidTerms<Hash>) -> Option<LoanTerms<Hash>> {
-		self.match_with(&bid_terms).then_some(bid_terms.0)
+		let (amount, term_length) = self.overlap(&bid_terms)?;
+		Some(LoanTerms {
+			amount,
+			interest_rate: self.interest_rate.clone(),
+			term_length,
+			currency: bid_terms.0.currency,
+		})
 	}
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
-pub struct BidTerms<Hash>(LoanTerms<Hash>);
+pub struct BidTerms<Hash>(LoanTermRanges<Hash>);
 
 impl<Hash> Deref for BidTerms<Hash> {
-	type Target = LoanTerms<Hash>;
+	type Target = LoanTermRanges<Hash>;
 
 	fn deref(&self) -> &Self::Target {
 		&self.0
 	}
 }
 
+impl<Hash> TryFrom<LoanTermRanges<Hash>> for BidTerms<Hash> {
+	type Error = InvalidTermRangesError;
+	fn try_from(terms: LoanTermRanges<Hash>) -> Result<Self, Self::Error> {
+		terms.validate().map(Self)
+	}
+}
+
 impl<Hash> TryFrom<LoanTerms<Hash>> for BidTerms<Hash> {
 	type Error = InvalidTermLengthError;
 	fn try_from(terms: LoanTerms<Hash>) -> Result<Self, Self::Error> {
@@ -118,11 +374,11 @@ impl<Hash> TryFrom<LoanTerms<Hash>> for BidTerms<Hash> {
 			return Err(InvalidTermLengthError);
 		}
 
-		Ok(Self(terms))
+		Ok(Self(terms.into()))
 	}
 }
 
-impl<Hash> BidTerms<Hash> {
+impl<Hash: PartialEq> BidTerms<Hash> {
 	pub fn match_with(&self, ask_terms: &AskTerms<Hash>) -> bool {
 		ask_terms.match_with(self)
 	}
@@ -154,3 +410,228 @@ impl Default for InterestRate {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn rate(
+		rate_per_period: RatePerPeriod,
+		decimals: Decimals,
+		interest_type: InterestType,
+	) -> InterestRate {
+		InterestRate {
+			rate_per_period,
+			decimals,
+			period: Duration::from_millis(1_000),
+			interest_type,
+		}
+	}
+
+	#[test]
+	fn duration_as_millis_works() {
+		assert_eq!(Duration::new(2, 500_000_000).as_millis(), 2_500);
+		assert_eq!(Duration::from_millis(1_234).as_millis(), 1_234);
+		assert_eq!(Duration::new(0, 999_999).as_millis(), 0);
+	}
+
+	#[test]
+	fn periods_in_counts_started_periods() {
+		let rate = rate(1, 1, InterestType::Simple);
+		assert_eq!(rate.periods_in(&Duration::from_millis(0)), 0);
+		assert_eq!(rate.periods_in(&Duration::from_millis(1)), 1);
+		assert_eq!(rate.periods_in(&Duration::from_millis(1_000)), 1);
+		assert_eq!(rate.periods_in(&Duration::from_millis(1_001)), 2);
+	}
+
+	#[test]
+	fn periods_in_is_zero_when_period_is_zero() {
+		let rate =
+			InterestRate { period: Duration::from_millis(0), ..rate(1, 1, InterestType::Simple) };
+		assert_eq!(rate.periods_in(&Duration::from_millis(5_000)), 0);
+	}
+
+	#[test]
+	fn simple_interest_accrues_linearly() {
+		// 10% per period
+		let rate = rate(1, 1, InterestType::Simple);
+		let principal = ExternalAmount::from(1_000u64);
+
+		assert_eq!(
+			rate.accrued_interest(principal, &Duration::from_millis(0)).unwrap(),
+			0u64.into()
+		);
+		assert_eq!(
+			rate.accrued_interest(principal, &Duration::from_millis(1_000)).unwrap(),
+			100u64.into()
+		);
+		assert_eq!(
+			rate.accrued_interest(principal, &Duration::from_millis(3_000)).unwrap(),
+			300u64.into()
+		);
+	}
+
+	#[test]
+	fn compound_interest_accrues_on_interest() {
+		// 5% per period
+		let rate = rate(5, 2, InterestType::Compound);
+		let principal = ExternalAmount::from(1_000_000u64);
+
+		assert_eq!(
+			rate.accrued_interest(principal, &Duration::from_millis(1_000)).unwrap(),
+			50_000u64.into()
+		);
+		assert_eq!(
+			rate.accrued_interest(principal, &Duration::from_millis(2_000)).unwrap(),
+			102_500u64.into()
+		);
+		// 1.05^12 = 1.795856...
+		assert_eq!(
+			rate.accrued_interest(principal, &Duration::from_millis(12_000)).unwrap(),
+			795_856u64.into()
+		);
+	}
+
+	#[test]
+	fn zero_rate_accrues_nothing() {
+		let rate = rate(0, 1, InterestType::Compound);
+		assert_eq!(
+			rate.accrued_interest(1_000u64.into(), &Duration::from_millis(10_000)).unwrap(),
+			0u64.into()
+		);
+	}
+
+	#[test]
+	fn accrued_interest_should_error_on_overflow() {
+		let rate = rate(1, 1, InterestType::Compound);
+		assert!(rate
+			.accrued_interest(1_000u64.into(), &Duration::from_millis(1_000_000))
+			.is_err());
+
+		let rate = InterestRate { decimals: 100, ..rate };
+		assert!(rate.accrued_interest(1_000u64.into(), &Duration::from_millis(1_000)).is_err());
+	}
+
+	fn ranges(
+		min_amount: u64,
+		max_amount: u64,
+		rate_per_period: RatePerPeriod,
+	) -> LoanTermRanges<sp_core::H256> {
+		LoanTermRanges {
+			min_amount: min_amount.into(),
+			max_amount: max_amount.into(),
+			interest_rate: rate(rate_per_period, 2, InterestType::Simple),
+			min_term_length: Duration::from_millis(10_000),
+			max_term_length: Duration::from_millis(50_000),
+			currency: LoanTerms::default().currency,
+		}
+	}
+
+	#[test]
+	fn compare_scales_by_decimals() {
+		let five_percent = rate(5, 2, InterestType::Simple);
+		let half_percent = rate(5, 3, InterestType::Simple);
+		assert_eq!(five_percent.compare(&rate(50, 3, InterestType::Simple)), Some(Ordering::Equal));
+		assert_eq!(five_percent.compare(&half_percent), Some(Ordering::Greater));
+		assert_eq!(half_percent.compare(&five_percent), Some(Ordering::Less));
+		assert_eq!(five_percent.compare(&rate(5, 2, InterestType::Compound)), None);
+	}
+
+	#[test]
+	fn sort_key_orders_like_compare() {
+		let five_percent = rate(5, 2, InterestType::Simple);
+		let half_percent = rate(5, 3, InterestType::Simple);
+		assert_eq!(five_percent.sort_key(), rate(50, 3, InterestType::Simple).sort_key());
+		assert!(five_percent.sort_key() > half_percent.sort_key());
+		assert!(rate(u64::MAX, 0, InterestType::Simple).sort_key() > five_percent.sort_key());
+		assert!(rate(1, 40, InterestType::Simple).sort_key() < half_percent.sort_key());
+	}
+
+	#[test]
+	fn agreed_terms_takes_largest_common_amount_at_lender_rate() {
+		let ask = AskTerms::try_from(ranges(1_000, 5_000, 3)).unwrap();
+		let bid = BidTerms::try_from(LoanTermRanges {
+			min_term_length: Duration::from_millis(20_000),
+			max_term_length: Duration::from_millis(100_000),
+			..ranges(2_000, 8_000, 5)
+		})
+		.unwrap();
+
+		assert!(ask.match_with(&bid));
+		assert_eq!(
+			ask.agreed_terms(bid),
+			Some(LoanTerms {
+				amount: 5_000u64.into(),
+				interest_rate: rate(3, 2, InterestType::Simple),
+				term_length: Duration::from_millis(50_000),
+				currency: LoanTerms::default().currency,
+			})
+		);
+	}
+
+	#[test]
+	fn match_with_rejects_disjoint_ranges() {
+		let ask = AskTerms::try_from(ranges(1_000, 5_000, 3)).unwrap();
+
+		let bid = BidTerms::try_from(ranges(6_000, 8_000, 5)).unwrap();
+		assert!(!ask.match_with(&bid));
+
+		let bid = BidTerms::try_from(LoanTermRanges {
+			min_term_length: Duration::from_millis(60_000),
+			max_term_length: Duration::from_millis(100_000),
+			..ranges(1_000, 5_000, 5)
+		})
+		.unwrap();
+		assert!(!ask.match_with(&bid));
+	}
+
+	#[test]
+	fn match_with_rejects_rate_above_borrower_limit() {
+		let ask = AskTerms::try_from(ranges(1_000, 5_000, 6)).unwrap();
+		let bid = BidTerms::try_from(ranges(1_000, 5_000, 5)).unwrap();
+		assert!(!ask.match_with(&bid));
+		assert_eq!(ask.agreed_terms(bid), None);
+	}
+
+	#[test]
+	fn exact_terms_agree_with_themselves() {
+		let terms = LoanTerms { amount: 1_000u64.into(), ..LoanTerms::default() };
+		let ask = AskTerms::try_from(terms.clone()).unwrap();
+		let bid = BidTerms::try_from(terms.clone()).unwrap();
+		assert_eq!(ask.agreed_terms(bid), Some(terms));
+	}
+
+	#[test]
+	fn term_ranges_should_be_validated() {
+		assert_matches::assert_matches!(
+			AskTerms::try_from(ranges(5_000, 1_000, 3)),
+			Err(InvalidTermRangesError::EmptyRange)
+		);
+		assert_matches::assert_matches!(
+			BidTerms::try_from(LoanTermRanges {
+				min_term_length: Duration::from_millis(0),
+				..ranges(1_000, 5_000, 3)
+			}),
+			Err(InvalidTermRangesError::TermLength)
+		);
+	}
+
+	#[test]
+	fn amount_due_adds_interest_to_principal() {
+		let terms = LoanTerms {
+			amount: 1_000u64.into(),
+			interest_rate: rate(1, 1, InterestType::Simple),
+			..LoanTerms::default()
+		};
+
+		let due = terms.amount_due(&Duration::from_millis(2_000)).unwrap();
+		assert_eq!(
+			due,
+			AmountDue {
+				principal: 1_000u64.into(),
+				interest: 200u64.into(),
+				total: 1_200u64.into()
+			}
+		);
+	}
+}
@@ -61,11 +61,30 @@ impl EvmInfo {
 	pub const LUNIVERSE: EvmInfo = EvmInfo { chain_id: EvmChainId::LUNIVERSE };
 }
 
+#[derive(
+	Copy,
+	Clone,
+	RuntimeDebug,
+	PartialEq,
+	Eq,
+	PartialOrd,
+	Ord,
+	Encode,
+	Decode,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+pub enum BitcoinNetwork {
+	Mainnet,
+	Testnet,
+}
+
 #[derive(
 	Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Encode, Decode, TypeInfo, MaxEncodedLen,
 )]
 pub enum Blockchain {
 	Evm(EvmInfo),
+	Bitcoin(BitcoinNetwork),
 }
 
 impl Blockchain {
@@ -77,6 +96,8 @@ impl Blockchain {
 	pub const RINKEBY: Blockchain = Blockchain::evm(EvmChainId::RINKEBY);
 	pub const LUNIVERSE_TESTNET: Blockchain = Blockchain::evm(EvmChainId::LUNIVERSE_TESTNET);
 	pub const LUNIVERSE: Blockchain = Blockchain::evm(EvmChainId::LUNIVERSE);
+	pub const BITCOIN: Blockchain = Blockchain::Bitcoin(BitcoinNetwork::Mainnet);
+	pub const BITCOIN_TESTNET: Blockchain = Blockchain::Bitcoin(BitcoinNetwork::Testnet);
 
 	pub fn as_bytes(&self) -> Cow<'_, [u8]> {
 		match *self {
@@ -89,6 +110,8 @@ impl Blockchain {
 				buf.extend(s.as_bytes());
 				Cow::Owned(buf)
 			},
+			Blockchain::Bitcoin(BitcoinNetwork::Mainnet) => Cow::Borrowed(b"bitcoin"),
+			Blockchain::Bitcoin(BitcoinNetwork::Testnet) => Cow::Borrowed(b"bitcoin-testnet"),
 		}
 	}
 
@@ -108,6 +131,27 @@ impl Blockchain {
 	}
 }
 
+/// How deep a transaction must be in its chain before the offchain worker accepts it.
+#[derive(Copy, Clone, RuntimeDebug, PartialEq, Eq, Encode, Decode, TypeInfo, MaxEncodedLen)]
+pub enum ConfirmationPolicy {
+	/// At least this many blocks, which must be at least one, must have been built on top of the
+	/// transaction's block.
+	Blocks(u64),
+	/// The transaction's block must be final according to the `finalized` block tag of the
+	/// chain's JSON-RPC API. Only EVM chains support this.
+	Finalized,
+}
+
+impl ConfirmationPolicy {
+	pub fn is_supported_by(&self, blockchain: &Blockchain) -> bool {
+		match (self, blockchain) {
+			(ConfirmationPolicy::Blocks(blocks), _) => *blocks > 0,
+			(ConfirmationPolicy::Finalized, Blockchain::Evm(_)) => true,
+			(ConfirmationPolicy::Finalized, Blockchain::Bitcoin(_)) => false,
+		}
+	}
+}
+
 #[derive(
 	Copy,
 	Clone,
@@ -125,6 +169,7 @@ impl Blockchain {
 pub enum EvmTransferKind {
 	Erc20,
 	Ethless,
+	Native,
 }
 
 pub type EvmSupportedTransferKinds =
@@ -135,6 +180,8 @@ pub type EvmSupportedTransferKinds =
 )]
 pub enum EvmCurrencyType {
 	SmartContract(ExternalAddress, EvmSupportedTransferKinds),
+	/// The chain's own coin (e.g. ETH on Ethereum), moved by plain value transfers
+	Native,
 }
 
 #[derive(
@@ -142,19 +189,27 @@ pub enum EvmCurrencyType {
 )]
 pub enum Currency {
 	Evm(EvmCurrencyType, EvmInfo),
+	Bitcoin(BitcoinNetwork),
 }
 
 impl Currency {
 	pub fn blockchain(&self) -> Blockchain {
 		match self {
 			Currency::Evm(_, info) => Blockchain::Evm(info.clone()),
+			Currency::Bitcoin(network) => Blockchain::Bitcoin(*network),
 		}
 	}
 	pub fn supports(&self, kind: &TransferKind) -> bool {
 		match (self, kind) {
 			(Currency::Evm(currency, _), TransferKind::Evm(kind)) => match currency {
-				EvmCurrencyType::SmartContract(_, supported) => supported.contains(kind),
+				EvmCurrencyType::SmartContract(_, supported) => {
+					kind != &EvmTransferKind::Native && supported.contains(kind)
+				},
+				EvmCurrencyType::Native => kind == &EvmTransferKind::Native,
 			},
+			(Currency::Bitcoin(_), TransferKind::Bitcoin) => true,
+			(Currency::Evm(..), TransferKind::Bitcoin)
+			| (Currency::Bitcoin(_), TransferKind::Evm(_)) => false,
 		}
 	}
 
@@ -168,6 +223,7 @@ impl Currency {
 )]
 pub enum TransferKind {
 	Evm(EvmTransferKind),
+	Bitcoin,
 }
 
 impl From<EvmTransferKind> for TransferKind {
@@ -181,6 +237,8 @@ impl TryFrom<super::LegacyTransferKind> for TransferKind {
 	fn try_from(legacy: super::LegacyTransferKind) -> Result<Self, Self::Error> {
 		match legacy {
 			LegacyTransferKind::Ethless(_) => Ok(TransferKind::Evm(EvmTransferKind::Ethless)),
+			LegacyTransferKind::Erc20(_) => Ok(TransferKind::Evm(EvmTransferKind::Erc20)),
+			LegacyTransferKind::Native => Ok(TransferKind::Evm(EvmTransferKind::Native)),
 			_ => Err(()),
 		}
 	}
@@ -189,6 +247,7 @@ impl TryFrom<super::LegacyTransferKind> for TransferKind {
 #[derive(
 	Clone, RuntimeDebug, PartialEq, Eq, PartialOrd, Encode, Decode, TypeInfo, MaxEncodedLen, Ord,
 )]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct CurrencyId<Hash>(Hash);
 
 impl<H> CurrencyId<H> {
@@ -202,6 +261,14 @@ impl<H> CurrencyId<H> {
 				let encoded = (address, evm_info.chain_id).encode();
 				CurrencyId(T::Hashing::hash(&encoded))
 			},
+			Currency::Evm(EvmCurrencyType::Native, evm_info) => {
+				let encoded = (b"native", evm_info.chain_id).encode();
+				CurrencyId(T::Hashing::hash(&encoded))
+			},
+			Currency::Bitcoin(network) => {
+				let encoded = (b"bitcoin", network).encode();
+				CurrencyId(T::Hashing::hash(&encoded))
+			},
 		}
 	}
 }
@@ -245,4 +312,34 @@ mod test {
 			b"evm-31337".as_slice()
 		);
 	}
+
+	#[test]
+	fn currency_supports_bitcoin_transfers() {
+		let bitcoin = Currency::Bitcoin(BitcoinNetwork::Mainnet);
+		assert!(bitcoin.supports(&TransferKind::Bitcoin));
+		assert!(!bitcoin.supports(&TransferKind::Evm(EvmTransferKind::Ethless)));
+	}
+
+	#[test]
+	fn currency_supports_native_transfers() {
+		let native = Currency::Evm(EvmCurrencyType::Native, EvmInfo::ETHEREUM);
+		assert!(native.supports(&TransferKind::Evm(EvmTransferKind::Native)));
+		assert!(!native.supports(&TransferKind::Evm(EvmTransferKind::Ethless)));
+		assert!(!native.supports(&TransferKind::Bitcoin));
+
+		let contract = Currency::Evm(
+			EvmCurrencyType::SmartContract(
+				ExternalAddress::default(),
+				EvmSupportedTransferKinds::try_from(vec![EvmTransferKind::Native]).unwrap(),
+			),
+			EvmInfo::ETHEREUM,
+		);
+		assert!(!contract.supports(&TransferKind::Evm(EvmTransferKind::Native)));
+	}
+
+	#[test]
+	fn blockchain_as_bytes_bitcoin() {
+		assert_eq!(Blockchain::BITCOIN.as_bytes(), b"bitcoin".as_slice());
+		assert_eq!(Blockchain::BITCOIN_TESTNET.as_bytes(), b"bitcoin-testnet".as_slice());
+	}
 }
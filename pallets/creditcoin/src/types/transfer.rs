@@ -1,5 +1,5 @@
 use super::*;
-use crate::ocw::errors::SchedulerError;
+use crate::ocw::errors::{OffchainError, SchedulerError};
 use crate::ocw::tasks::OffchainVerification;
 use crate::types::concatenate;
 use crate::Config;
@@ -52,6 +52,7 @@ where
 }
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
 pub struct TransferId<Hash>(Hash);
 
 impl<H> TransferId<H> {
@@ -86,6 +87,12 @@ impl<H> From<H> for TransferId<H> {
 	}
 }
 
+impl<H> AsRef<H> for TransferId<H> {
+	fn as_ref(&self) -> &H {
+		&self.0
+	}
+}
+
 type UnverifiedTransferFor<T> = UnverifiedTransfer<
 	<T as SystemConfig>::AccountId,
 	<T as SystemConfig>::BlockNumber,
@@ -99,7 +106,7 @@ where
 	<UnverifiedTransferFor<T> as OffchainVerification<T>>::Output: Into<Option<T::Moment>>,
 {
 	type Call = crate::pallet::Call<T>;
-	type EvaluationError = VerificationFailureCause;
+	type EvaluationError = OffchainError;
 	type SchedulerError = SchedulerError;
 
 	fn to_id(&self) -> T::Hash {
@@ -127,13 +134,18 @@ where
 				let id = TransferId::from(*id);
 				Ok(Self::Call::fail_task { deadline, task_id: id.into(), cause })
 			},
-			Err(InvalidTask(e)) => Err(TaskError::Evaluation(e)),
+			Err(e @ (InvalidTask(_) | RpcError(_))) => Err(TaskError::Evaluation(e)),
 			Err(NoRpcUrl(e)) => Err(TaskError::Scheduler(e.into())),
-			Err(RpcError(e)) => Err(TaskError::Scheduler(e.into())),
 			Err(IncorrectChainId) => Err(TaskError::Scheduler(SchedulerError::IncorrectChainId)),
 		}
 	}
 
+	fn retries_exhausted(&self, deadline: T::BlockNumber, id: &T::Hash) -> Option<Self::Call> {
+		let id = TransferId::from(*id);
+		let cause = VerificationFailureCause::RetriesExhausted;
+		Some(Self::Call::fail_task { deadline, task_id: id.into(), cause })
+	}
+
 	fn is_persisted(id: &T::Hash) -> bool {
 		let id = TransferId::from(*id);
 		crate::pallet::Transfers::<T>::contains_key(id)
@@ -47,9 +47,11 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
 	}
 	// Storage: Creditcoin DealOrders (r:511 w:510)
-	// Storage: Creditcoin BidOrders (r:0 w:255)
+	// Storage: Creditcoin BidOrders (r:255 w:255)
+	// Storage: Creditcoin OpenBidOrders (r:0 w:255)
 	// Storage: Creditcoin Offers (r:0 w:255)
-	// Storage: Creditcoin AskOrders (r:0 w:25)
+	// Storage: Creditcoin AskOrders (r:25 w:25)
+	// Storage: Creditcoin OpenAskOrders (r:0 w:25)
 	fn on_initialize(a: u32, b: u32, o: u32, d: u32, f: u32, ) -> Weight {
 		Weight::from_ref_time(1_210_357_000 as u64)
 			// Standard Error: 2_179_000
@@ -61,10 +63,12 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			// Standard Error: 2_179_000
 			.saturating_add(Weight::from_ref_time(15_846_000 as u64).saturating_mul(f as u64))
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(b as u64)))
 			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(d as u64)))
 			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(f as u64)))
-			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(a as u64)))
-			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(b as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(a as u64)))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(b as u64)))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(o as u64)))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(d as u64)))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(f as u64)))
@@ -89,21 +93,25 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	// Storage: Creditcoin Addresses (r:1 w:0)
 	// Storage: Creditcoin Currencies (r:1 w:0)
 	// Storage: Creditcoin UsedGuids (r:1 w:1)
+	// Storage: Creditcoin OrderMatching (r:1 w:0)
+	// Storage: Creditcoin MatchQueue (r:1 w:1)
 	fn add_ask_order() -> Weight {
 		// Minimum execution time: 24_000 nanoseconds.
 		Weight::from_ref_time(25_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Creditcoin BidOrders (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:1 w:0)
 	// Storage: Creditcoin Currencies (r:1 w:0)
 	// Storage: Creditcoin UsedGuids (r:1 w:1)
+	// Storage: Creditcoin OrderMatching (r:1 w:0)
+	// Storage: Creditcoin MatchQueue (r:1 w:1)
 	fn add_bid_order() -> Weight {
 		// Minimum execution time: 23_000 nanoseconds.
 		Weight::from_ref_time(24_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Creditcoin AskOrders (r:1 w:0)
 	// Storage: Creditcoin BidOrders (r:1 w:0)
@@ -126,40 +134,86 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(5 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
-	// Storage: Creditcoin Authorities (r:1 w:1)
-	fn add_authority() -> Weight {
-		// Minimum execution time: 5_000 nanoseconds.
-		Weight::from_ref_time(6_000_000 as u64)
+	// Storage: Creditcoin AskOrders (r:1 w:1)
+	// Storage: Creditcoin OpenAskOrders (r:0 w:1)
+	fn cancel_ask_order() -> Weight {
+		// Minimum execution time: 17_000 nanoseconds.
+		Weight::from_ref_time(18_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Creditcoin BidOrders (r:1 w:1)
+	// Storage: Creditcoin OpenBidOrders (r:0 w:1)
+	fn cancel_bid_order() -> Weight {
+		// Minimum execution time: 17_000 nanoseconds.
+		Weight::from_ref_time(18_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Creditcoin Offers (r:1 w:1)
+	fn cancel_offer() -> Weight {
+		// Minimum execution time: 16_000 nanoseconds.
+		Weight::from_ref_time(17_000_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Creditcoin Authorities (r:1 w:1)
+	// Storage: Creditcoin AuthorityBond (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Creditcoin AuthorityBonds (r:0 w:1)
+	fn add_authority() -> Weight {
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
 	// Storage: Creditcoin Authorities (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:0)
+	// Storage: Creditcoin AuthorityBond (r:1 w:0)
+	// Storage: Creditcoin QuorumThreshold (r:1 w:0)
+	// Storage: Creditcoin TaskOutcomes (r:1 w:0)
+	// Storage: Creditcoin TaskOutputVotes (r:1 w:1)
 	// Storage: Creditcoin Transfers (r:1 w:1)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFees (r:1 w:1)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn persist_transfer() -> Weight {
-		// Minimum execution time: 21_000 nanoseconds.
-		Weight::from_ref_time(22_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		// Minimum execution time: 22_000 nanoseconds.
+		Weight::from_ref_time(23_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
 	// Storage: Creditcoin Authorities (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:0)
+	// Storage: Creditcoin AuthorityBond (r:1 w:0)
 	// Storage: Creditcoin Transfers (r:1 w:0)
+	// Storage: Creditcoin QuorumThreshold (r:1 w:0)
+	// Storage: Creditcoin TaskOutcomes (r:1 w:0)
+	// Storage: Creditcoin TaskOutputVotes (r:1 w:1)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFees (r:1 w:1)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn fail_transfer() -> Weight {
 		// Minimum execution time: 16_000 nanoseconds.
 		Weight::from_ref_time(17_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: Creditcoin Transfers (r:1 w:1)
+	// Storage: Creditcoin CreditRecords (r:1 w:1)
+	// Storage: Creditcoin BorrowedVolume (r:1 w:1)
 	fn fund_deal_order() -> Weight {
-		// Minimum execution time: 30_000 nanoseconds.
-		Weight::from_ref_time(31_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		// Minimum execution time: 36_000 nanoseconds.
+		Weight::from_ref_time(37_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:1)
 	fn lock_deal_order() -> Weight {
@@ -173,61 +227,131 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	// Storage: Creditcoin Transfers (r:1 w:0)
 	// Storage: Creditcoin Currencies (r:1 w:0)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFee (r:1 w:0)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn register_funding_transfer() -> Weight {
 		// Minimum execution time: 30_000 nanoseconds.
 		Weight::from_ref_time(31_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:0)
 	// Storage: Creditcoin Addresses (r:2 w:0)
 	// Storage: Creditcoin Transfers (r:1 w:0)
 	// Storage: Creditcoin Currencies (r:1 w:0)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFee (r:1 w:0)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn register_repayment_transfer() -> Weight {
 		// Minimum execution time: 30_000 nanoseconds.
 		Weight::from_ref_time(31_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(5 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:2 w:0)
 	// Storage: Creditcoin Transfers (r:1 w:0)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFee (r:1 w:0)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn register_funding_transfer_legacy() -> Weight {
 		// Minimum execution time: 30_000 nanoseconds.
 		Weight::from_ref_time(31_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Creditcoin NextMaturityBucket (r:1 w:1)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Creditcoin DealOrderMaturities (r:2 w:1)
+	// Storage: Creditcoin DealOrders (r:1 w:0)
+	// Storage: Creditcoin DefaultedDealOrders (r:0 w:1)
+	// Storage: Creditcoin CreditRecords (r:1 w:1)
+	fn on_idle(c: u32, ) -> Weight {
+		Weight::from_ref_time(9_000_000 as u64)
+			// Standard Error: 34_000
+			.saturating_add(Weight::from_ref_time(24_381_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().reads((3 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((3 as u64).saturating_mul(c as u64)))
+	}
+	// Storage: Creditcoin MatchQueue (r:1 w:1)
+	// Storage: Creditcoin AskOrders (r:1 w:0)
+	// Storage: Creditcoin Addresses (r:2 w:0)
+	// Storage: Creditcoin OpenBidOrders (r:1 w:1)
+	// Storage: Creditcoin BidOrders (r:1 w:0)
+	// Storage: Creditcoin Offers (r:1 w:1)
+	fn match_orders(m: u32, ) -> Weight {
+		Weight::from_ref_time(5_000_000 as u64)
+			// Standard Error: 44_000
+			.saturating_add(Weight::from_ref_time(41_263_000 as u64).saturating_mul(m as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((6 as u64).saturating_mul(m as u64)))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().writes((2 as u64).saturating_mul(m as u64)))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:2 w:0)
 	// Storage: Creditcoin Transfers (r:1 w:0)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFee (r:1 w:0)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn register_repayment_transfer_legacy() -> Weight {
 		// Minimum execution time: 30_000 nanoseconds.
 		Weight::from_ref_time(31_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Creditcoin Transfers (r:2 w:1)
+	// Storage: Creditcoin DealOrders (r:1 w:1)
+	// Storage: Creditcoin Addresses (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Creditcoin DefaultedDealOrders (r:0 w:1)
+	fn repay_installment() -> Weight {
+		// Minimum execution time: 34_000 nanoseconds.
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
 	// Storage: Creditcoin Transfers (r:1 w:1)
+	// Storage: Creditcoin CreditRecords (r:1 w:1)
+	// Storage: Creditcoin DefaultedDealOrders (r:0 w:1)
 	fn close_deal_order() -> Weight {
-		// Minimum execution time: 31_000 nanoseconds.
-		Weight::from_ref_time(32_000_000 as u64)
+		// Minimum execution time: 34_000 nanoseconds.
+		Weight::from_ref_time(35_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(4 as u64))
+	}
+	// Storage: Creditcoin DealOrders (r:1 w:1)
+	// Storage: Creditcoin Addresses (r:2 w:0)
+	// Storage: Creditcoin DealOrderAssignments (r:1 w:1)
+	fn assign_deal_order() -> Weight {
+		// Minimum execution time: 61_000 nanoseconds.
+		Weight::from_ref_time(62_000_000 as u64)
 			.saturating_add(T::DbWeight::get().reads(4 as u64))
 			.saturating_add(T::DbWeight::get().writes(2 as u64))
 	}
 	// Storage: Creditcoin DealOrders (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Creditcoin CreditRecords (r:1 w:1)
+	// Storage: Creditcoin DefaultedDealOrders (r:0 w:1)
 	fn exempt() -> Weight {
-		// Minimum execution time: 23_000 nanoseconds.
-		Weight::from_ref_time(23_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+		// Minimum execution time: 26_000 nanoseconds.
+		Weight::from_ref_time(26_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Creditcoin Currencies (r:1 w:0)
 	// Storage: Creditcoin Addresses (r:2 w:0)
@@ -246,37 +370,62 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 	// Storage: Creditcoin CollectedCoins (r:1 w:0)
 	// Storage: Creditcoin PendingTasks (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:1 w:0)
+	// Storage: Creditcoin TaskFee (r:1 w:0)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn request_collect_coins() -> Weight {
 		// Minimum execution time: 21_000 nanoseconds.
 		Weight::from_ref_time(22_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(4 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(6 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
 	}
 	// Storage: Creditcoin Authorities (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:0)
+	// Storage: Creditcoin AuthorityBond (r:1 w:0)
 	// Storage: Creditcoin CollectedCoins (r:1 w:0)
+	// Storage: Creditcoin QuorumThreshold (r:1 w:0)
+	// Storage: Creditcoin TaskOutcomes (r:1 w:0)
+	// Storage: Creditcoin TaskOutputVotes (r:1 w:1)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFees (r:1 w:1)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn fail_collect_coins() -> Weight {
 		// Minimum execution time: 15_000 nanoseconds.
 		Weight::from_ref_time(15_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(2 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Creditcoin Authorities (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:0)
+	// Storage: Creditcoin AuthorityBond (r:1 w:0)
+	// Storage: Creditcoin QuorumThreshold (r:1 w:0)
+	// Storage: Creditcoin TaskOutcomes (r:1 w:0)
+	// Storage: Creditcoin TaskOutputVotes (r:1 w:1)
 	// Storage: Creditcoin CollectedCoins (r:1 w:1)
 	// Storage: Creditcoin Addresses (r:1 w:0)
 	// Storage: Creditcoin PendingTasks (r:0 w:1)
+	// Storage: Creditcoin TaskFees (r:1 w:1)
+	// Storage: Creditcoin TaskStatuses (r:0 w:1)
+	// Storage: Creditcoin TaskStatusUpdates (r:0 w:2)
+	// Storage: Creditcoin TaskStatusUpdateBlocks (r:1 w:1)
 	fn persist_collect_coins() -> Weight {
-		// Minimum execution time: 39_000 nanoseconds.
-		Weight::from_ref_time(40_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(3 as u64))
-			.saturating_add(T::DbWeight::get().writes(2 as u64))
+		// Minimum execution time: 40_000 nanoseconds.
+		Weight::from_ref_time(41_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
 	}
-	// Storage: Creditcoin Authorities (r:1 w:1)
+	// Storage: Creditcoin Authorities (r:2 w:1)
+	// Storage: Creditcoin QuorumThreshold (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
 	fn remove_authority() -> Weight {
-		// Minimum execution time: 8_000 nanoseconds.
-		Weight::from_ref_time(8_000_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(1 as u64))
-			.saturating_add(T::DbWeight::get().writes(1 as u64))
+		// Minimum execution time: 20_000 nanoseconds.
+		Weight::from_ref_time(21_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
 	}
 	// Storage: Creditcoin Currencies (r:1 w:1)
 	fn register_currency() -> Weight {
@@ -291,4 +440,72 @@ impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
 		Weight::from_ref_time(5_000_000 as u64)
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Creditcoin OrderMatching (r:0 w:1)
+	fn set_order_matching() -> Weight {
+		// Minimum execution time: 4_000 nanoseconds.
+		Weight::from_ref_time(5_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Creditcoin DealOrders (r:1 w:1)
+	// Storage: Creditcoin Addresses (r:1 w:0)
+	// Storage: Creditcoin DealOrderAmendments (r:1 w:1)
+	// Storage: System Number (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Creditcoin DealOrderMaturities (r:0 w:2)
+	// Storage: Creditcoin NextMaturityBucket (r:1 w:1)
+	// Storage: Creditcoin DefaultedDealOrders (r:1 w:0)
+	fn renegotiate_deal_order() -> Weight {
+		// Minimum execution time: 66_000 nanoseconds.
+		Weight::from_ref_time(67_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	// Storage: Creditcoin ConfirmationPolicies (r:0 w:1)
+	fn set_confirmation_policy() -> Weight {
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_ref_time(7_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Creditcoin Authorities (r:4 w:0)
+	// Storage: Creditcoin QuorumThreshold (r:0 w:1)
+	fn set_quorum_threshold() -> Weight {
+		// Minimum execution time: 8_000 nanoseconds.
+		Weight::from_ref_time(8_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Creditcoin AuthorityBond (r:0 w:1)
+	fn set_authority_bond() -> Weight {
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_ref_time(6_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Creditcoin Authorities (r:2 w:1)
+	// Storage: Creditcoin TaskOutputVotes (r:1 w:0)
+	// Storage: Creditcoin TaskOutcomes (r:1 w:0)
+	// Storage: Creditcoin QuorumThreshold (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn slash_authority() -> Weight {
+		// Minimum execution time: 28_000 nanoseconds.
+		Weight::from_ref_time(29_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Creditcoin TaskFee (r:0 w:1)
+	fn set_task_fee() -> Weight {
+		// Minimum execution time: 6_000 nanoseconds.
+		Weight::from_ref_time(6_000_000 as u64)
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Creditcoin Authorities (r:1 w:0)
+	// Storage: Creditcoin AuthorityBonds (r:1 w:1)
+	// Storage: Creditcoin AuthorityBond (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn bond_authority() -> Weight {
+		// Minimum execution time: 21_000 nanoseconds.
+		Weight::from_ref_time(22_000_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(4 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
 }
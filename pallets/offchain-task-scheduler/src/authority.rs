@@ -1,11 +1,12 @@
 use super::pallet;
-use super::Config;
+use super::{Config, Registered};
 
 pub trait AuthorityController {
 	type AccountId;
 	fn insert_authority(authority: &Self::AccountId);
 	fn remove_authority(authority: &Self::AccountId);
 	fn is_authority(authority: &Self::AccountId) -> bool;
+	fn authority_count() -> u32;
 }
 
 impl<Runtime: Config> AuthorityController for Runtime {
@@ -20,6 +21,26 @@ impl<Runtime: Config> AuthorityController for Runtime {
 	fn is_authority(authority: &Self::AccountId) -> bool {
 		pallet::Authorities::<Runtime>::contains_key(authority)
 	}
+	fn authority_count() -> u32 {
+		pallet::Authorities::<Runtime>::iter_keys().count() as u32
+	}
+}
+
+impl<Runtime: Config, Task> AuthorityController for Registered<Runtime, Task> {
+	type AccountId = Runtime::AccountId;
+
+	fn insert_authority(authority: &Self::AccountId) {
+		Runtime::insert_authority(authority);
+	}
+	fn remove_authority(authority: &Self::AccountId) {
+		Runtime::remove_authority(authority);
+	}
+	fn is_authority(authority: &Self::AccountId) -> bool {
+		Runtime::is_authority(authority)
+	}
+	fn authority_count() -> u32 {
+		Runtime::authority_count()
+	}
 }
 
 #[cfg(test)]
@@ -35,8 +56,10 @@ mod tests {
 			let account: AccountId = AccountId::new([0; 32]);
 
 			assert!(!Runtime::is_authority(&account));
+			assert_eq!(Runtime::authority_count(), 0);
 
 			Runtime::insert_authority(&account);
+			assert_eq!(Runtime::authority_count(), 1);
 
 			let value = crate::Pallet::<Runtime>::authorities(&account);
 			assert_eq!(value, Some(()));
@@ -45,7 +68,8 @@ mod tests {
 
 			Runtime::remove_authority(&account);
 			let value = crate::Pallet::<Runtime>::authorities(&account);
-			assert_eq!(value, None)
+			assert_eq!(value, None);
+			assert_eq!(Runtime::authority_count(), 0);
 		});
 	}
 }
@@ -1,7 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
-use frame_support::traits::Get;
+use core::marker::PhantomData;
+use frame_support::traits::{Get, StorageVersion};
 use frame_system::offchain::AppCrypto;
 use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::Config as SystemConfig;
@@ -20,6 +21,7 @@ use tracing as log;
 
 pub mod authority;
 pub mod benchmarking;
+pub mod migrations;
 pub mod mock;
 pub mod ocw;
 pub mod tasks;
@@ -29,6 +31,17 @@ pub mod weights;
 //gluwa's offchain task scheduler
 pub const KEY_TYPE: KeyTypeId = KeyTypeId(*b"gots");
 
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+#[doc(hidden)]
+pub mod __private {
+	pub use alloc::boxed::Box;
+	pub use frame_support::RuntimeDebug;
+	pub use frame_system::Config as SystemConfig;
+	pub use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+	pub use scale_info::TypeInfo;
+}
+
 pub mod crypto {
 	use super::AppCrypto;
 	use crate::KEY_TYPE;
@@ -60,6 +73,7 @@ pub mod crypto {
 pub mod pallet {
 	use super::{
 		log,
+		ocw::{queue, retry, shard::Shard},
 		tasks::{self, ForwardTask},
 		AppCrypto, Saturating, SystemConfig,
 	};
@@ -71,6 +85,7 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use scale_info::TypeInfo;
 	use sp_runtime::codec::FullCodec;
+	use sp_runtime::traits::IdentifyAccount;
 
 	#[pallet::config]
 	pub trait Config:
@@ -83,6 +98,24 @@ pub mod pallet {
 			+ ForwardTask<Self, Call = Self::TaskCall>
 			+ Debug;
 		type UnverifiedTaskTimeout: Get<<Self as SystemConfig>::BlockNumber>;
+		/// The maximum number of tasks an offchain worker evaluates in a single run.
+		type MaxTasksPerRun: Get<u32>;
+		/// The maximum time, in milliseconds, an offchain worker spends evaluating tasks in a
+		/// single run.
+		type MaxRunDuration: Get<u64>;
+		/// How many authorities evaluate each pending task, unless `QuorumThreshold` is larger.
+		type AuthoritiesPerTask: Get<u32>;
+		/// How many authorities have to agree on a task's output for it to be persisted. Each
+		/// pending task is evaluated by at least this many authorities.
+		type QuorumThreshold: Get<u32>;
+		/// How many blocks before its deadline a pending task is evaluated by every authority.
+		type ShardingFallback: Get<<Self as SystemConfig>::BlockNumber>;
+		/// How many times the evaluation of a task is attempted before the task is given up.
+		type MaxTaskAttempts: Get<u32>;
+		/// How many blocks to wait before attempting a task whose evaluation failed. The delay
+		/// doubles after every failed attempt, and the task is given up once the next attempt
+		/// would not happen before its deadline.
+		type RetryBackoff: Get<<Self as SystemConfig>::BlockNumber>;
 		type WeightInfo: WeightInfo;
 		type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
 		type TaskCall: Dispatchable<RuntimeOrigin = Self::RuntimeOrigin> + Clone;
@@ -93,10 +126,24 @@ pub mod pallet {
 	}
 
 	#[pallet::event]
-	pub enum Event<T: Config> {}
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A task has been scheduled and will be processed until its deadline.
+		/// [task_id, deadline]
+		TaskScheduled(T::Hash, T::BlockNumber),
+
+		/// A task has been completed before its deadline and is no longer processed.
+		/// [task_id, deadline]
+		TaskCompleted(T::Hash, T::BlockNumber),
+
+		/// A task reached its deadline without being completed.
+		/// [task_id, deadline]
+		TaskExpired(T::Hash, T::BlockNumber),
+	}
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(super::STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::storage]
@@ -128,15 +175,9 @@ pub mod pallet {
 			log::debug!("Cleaning up expired entries");
 
 			let mut unverified_task_count = 0u32;
-			let mut cursor: Option<Vec<u8>> = None;
-			loop {
-				let result =
-					PendingTasks::<T>::clear_prefix(block_number, u32::MAX, cursor.as_deref());
-				unverified_task_count.saturating_accrue(result.backend);
-				cursor = result.maybe_cursor;
-				if cursor.is_none() {
-					break;
-				}
+			for (id, _) in PendingTasks::<T>::drain_prefix(block_number) {
+				unverified_task_count.saturating_inc();
+				Self::deposit_event(Event::<T>::TaskExpired(id, block_number));
 			}
 
 			<T as Config>::WeightInfo::on_initialize(unverified_task_count)
@@ -151,7 +192,31 @@ pub mod pallet {
 				},
 			};
 
-			for (deadline, id, task) in PendingTasks::<T>::iter() {
+			retry::prune::<T>();
+
+			let shard = Shard::<T>::new(&signer.clone().into_account());
+			let mut budget = queue::Budget::new::<T>();
+			let mut last = None;
+			for (deadline, id) in queue::pending_tasks::<T>() {
+				if budget.is_exhausted() {
+					log::debug!(target: "task", "@{block_number:?} Out of budget after {last:?}");
+					if let Some(last) = &last {
+						queue::save_cursor::<T>(last);
+					}
+					return;
+				}
+
+				if !shard.is_assigned(&id, deadline, block_number)
+					|| !retry::is_due::<T>(&id, block_number)
+				{
+					continue;
+				}
+
+				let task = match PendingTasks::<T>::get(deadline, id) {
+					Some(task) => task,
+					None => continue,
+				};
+
 				let storage_key = tasks::storage_key(&id);
 				let mut lock = tasks::task_lock::<T>(&storage_key);
 
@@ -160,31 +225,48 @@ pub mod pallet {
 					Err(_) => continue,
 				};
 
+				budget.spend();
+				last = Some((deadline, id));
+
 				log::trace!(target: "task", "@{block_number:?} Task {:8?}", id);
 
 				use tasks::error::TaskError::*;
-				match task.forward_task(deadline) {
-					Ok(call) => {
-						match Self::submit_txn_with_synced_nonce(signer.clone(), |_| call.clone()) {
-							Ok(_) => guard.forget(),
-							Err(e) => {
-								log::error!("Failed to send a dispatchable transaction: {:?}", e)
-							},
-						}
-					},
+				let call = match task.forward_task(deadline) {
+					Ok(call) => call,
 					Err(FinishedTask) => {
 						log::debug!("Already handled Task ({:?}, {:?}) {task:?}", deadline, id);
+						retry::clear::<T>(&id);
 						guard.forget();
 						continue;
 					},
 					Err(Evaluation(cause)) => {
 						log::warn!("Failed to verify pending task {:?} : {:?}", task, cause);
+						if retry::record_failure::<T>(&id, deadline, block_number) {
+							continue;
+						}
+
+						log::warn!("Giving up on task {:?}, out of attempts", id);
+						match task.retries_exhausted(deadline) {
+							Some(call) => call,
+							None => continue,
+						}
 					},
 					Err(Scheduler(error)) => {
-						log::error!("Task verification encountered a processing error {:?}", error)
+						log::error!("Task verification encountered a processing error {:?}", error);
+						continue;
 					},
+				};
+
+				match Self::submit_txn_with_synced_nonce(signer.clone(), |_| call.clone()) {
+					Ok(_) => {
+						retry::clear::<T>(&id);
+						guard.forget();
+					},
+					Err(e) => log::error!("Failed to send a dispatchable transaction: {:?}", e),
 				}
 			}
+
+			queue::clear_cursor();
 		}
 	}
 
@@ -226,5 +308,107 @@ impl<Runtime: Config>
 	}
 	fn insert(deadline: &BlockNumberFor<Runtime>, id: &HashFor<Runtime>, task: TaskFor<Runtime>) {
 		crate::pallet::PendingTasks::<Runtime>::insert(deadline, id, task);
+		Pallet::<Runtime>::deposit_event(Event::<Runtime>::TaskScheduled(*id, *deadline));
+	}
+	fn complete(deadline: &BlockNumberFor<Runtime>, id: &HashFor<Runtime>) {
+		if crate::pallet::PendingTasks::<Runtime>::take(deadline, id).is_some() {
+			Pallet::<Runtime>::deposit_event(Event::<Runtime>::TaskCompleted(*id, *deadline));
+		}
+	}
+}
+
+/// Schedules the tasks of a single pallet when the runtime composes the tasks of several pallets
+/// with [`task_registry!`]. Use it as the pallet's scheduler instead of the runtime itself.
+pub struct Registered<Runtime, Task>(PhantomData<(Runtime, Task)>);
+
+impl<Runtime: Config, Task: Into<TaskFor<Runtime>>>
+	tasks::TaskScheduler<BlockNumberFor<Runtime>, HashFor<Runtime>, Task>
+	for Registered<Runtime, Task>
+{
+	fn deadline() -> BlockNumberFor<Runtime> {
+		<Runtime as tasks::TaskScheduler<_, _, TaskFor<Runtime>>>::deadline()
+	}
+	fn is_scheduled(deadline: &BlockNumberFor<Runtime>, id: &HashFor<Runtime>) -> bool {
+		<Runtime as tasks::TaskScheduler<_, _, TaskFor<Runtime>>>::is_scheduled(deadline, id)
+	}
+	fn insert(deadline: &BlockNumberFor<Runtime>, id: &HashFor<Runtime>, task: Task) {
+		<Runtime as tasks::TaskScheduler<_, _, TaskFor<Runtime>>>::insert(
+			deadline,
+			id,
+			task.into(),
+		);
+	}
+	fn complete(deadline: &BlockNumberFor<Runtime>, id: &HashFor<Runtime>) {
+		<Runtime as tasks::TaskScheduler<_, _, TaskFor<Runtime>>>::complete(deadline, id);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::{
+		runtime::{Runtime, RuntimeEvent, System},
+		task::MockTask,
+	};
+	use crate::tasks::{TaskScheduler as TaskSchedulerT, TaskV2};
+	use crate::{Event, GenesisConfig, Pallet};
+	use runtime_utils::{roll_to, ExtBuilder, Trivial};
+
+	fn scheduler_events() -> Vec<Event<Runtime>> {
+		System::events()
+			.into_iter()
+			.filter_map(|record| match record.event {
+				RuntimeEvent::TaskScheduler(event) => Some(event),
+				_ => None,
+			})
+			.collect()
+	}
+
+	#[test]
+	fn completed_task_emits_scheduled_and_completed() {
+		ExtBuilder::<GenesisConfig<Runtime>>::default().build().execute_with(|| {
+			roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+			let deadline = Runtime::deadline();
+			let task = MockTask::Remark(0);
+			let id = TaskV2::<Runtime>::to_id(&task);
+			Runtime::insert(&deadline, &id, task);
+			assert!(Runtime::is_scheduled(&deadline, &id));
+
+			Runtime::complete(&deadline, &id);
+			assert!(!Runtime::is_scheduled(&deadline, &id));
+
+			// completing a task that is no longer scheduled does nothing
+			Runtime::complete(&deadline, &id);
+
+			roll_to::<Trivial, Runtime, Pallet<Runtime>>(deadline);
+
+			assert_eq!(
+				scheduler_events(),
+				vec![Event::TaskScheduled(id, deadline), Event::TaskCompleted(id, deadline)]
+			);
+		});
+	}
+
+	#[test]
+	fn pending_task_expires_at_deadline() {
+		ExtBuilder::<GenesisConfig<Runtime>>::default().build().execute_with(|| {
+			roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+			let deadline = Runtime::deadline();
+			let task = MockTask::Remark(0);
+			let id = TaskV2::<Runtime>::to_id(&task);
+			Runtime::insert(&deadline, &id, task);
+
+			roll_to::<Trivial, Runtime, Pallet<Runtime>>(deadline - 1);
+			assert!(Runtime::is_scheduled(&deadline, &id));
+			assert!(!scheduler_events().contains(&Event::TaskExpired(id, deadline)));
+
+			roll_to::<Trivial, Runtime, Pallet<Runtime>>(deadline);
+			assert!(!Runtime::is_scheduled(&deadline, &id));
+			assert_eq!(
+				scheduler_events(),
+				vec![Event::TaskScheduled(id, deadline), Event::TaskExpired(id, deadline)]
+			);
+		});
 	}
 }
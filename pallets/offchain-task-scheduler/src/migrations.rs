@@ -0,0 +1,100 @@
+use crate::{pallet::PendingTasks, Config, Pallet};
+use core::marker::PhantomData;
+use frame_support::traits::{Get, OnRuntimeUpgrade, StorageVersion};
+use frame_support::weights::Weight;
+use parity_scale_codec::Decode;
+use sp_runtime::traits::Saturating;
+#[cfg(feature = "try-runtime")]
+use {frame_support::ensure, parity_scale_codec::Encode, sp_std::vec::Vec};
+
+/// Wraps the pending tasks stored while every task was an `OldTask`, for runtimes that start
+/// composing their tasks with [`task_registry!`](crate::task_registry).
+pub struct MigrateToTaskRegistry<Runtime, OldTask>(PhantomData<(Runtime, OldTask)>);
+
+impl<Runtime, OldTask> OnRuntimeUpgrade for MigrateToTaskRegistry<Runtime, OldTask>
+where
+	Runtime: Config,
+	OldTask: Decode + Into<Runtime::Task>,
+{
+	fn on_runtime_upgrade() -> Weight {
+		if StorageVersion::get::<Pallet<Runtime>>() >= 1 {
+			return Runtime::DbWeight::get().reads(1);
+		}
+
+		let mut count = 0u64;
+		PendingTasks::<Runtime>::translate::<OldTask, _>(|_, _, task| {
+			count.saturating_inc();
+			Some(task.into())
+		});
+		StorageVersion::new(1).put::<Pallet<Runtime>>();
+
+		Runtime::DbWeight::get().reads_writes(count.saturating_add(1), count.saturating_add(1))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, &'static str> {
+		let count = PendingTasks::<Runtime>::iter_keys().count() as u64;
+		Ok(count.encode())
+	}
+
+	/// Every pending task is still there and decodes as a `Runtime::Task`.
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), &'static str> {
+		let count = u64::decode(&mut &state[..]).map_err(|_| "invalid pending task count")?;
+		ensure!(
+			PendingTasks::<Runtime>::iter_values().count() as u64 == count,
+			"pending tasks were lost in the migration"
+		);
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::mock::{runtime::Runtime, task::MockTask};
+	use crate::tasks::{TaskScheduler, TaskV2};
+	use crate::GenesisConfig;
+	use runtime_utils::ExtBuilder;
+
+	#[test]
+	fn pending_tasks_are_migrated_once() {
+		ExtBuilder::<GenesisConfig<Runtime>>::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Runtime>>();
+			let deadline = Runtime::deadline();
+			let task = MockTask::Remark(0);
+			let id = TaskV2::<Runtime>::to_id(&task);
+			Runtime::insert(&deadline, &id, task);
+
+			MigrateToTaskRegistry::<Runtime, MockTask<u32>>::on_runtime_upgrade();
+
+			assert!(Runtime::is_scheduled(&deadline, &id));
+			assert_eq!(StorageVersion::get::<Pallet<Runtime>>(), 1);
+			assert_eq!(
+				MigrateToTaskRegistry::<Runtime, MockTask<u32>>::on_runtime_upgrade(),
+				<Runtime as frame_system::Config>::DbWeight::get().reads(1)
+			);
+		});
+	}
+
+	#[cfg(feature = "try-runtime")]
+	#[test]
+	fn pending_task_count_is_unchanged_by_the_migration() {
+		ExtBuilder::<GenesisConfig<Runtime>>::default().build().execute_with(|| {
+			StorageVersion::new(0).put::<Pallet<Runtime>>();
+			let deadline = Runtime::deadline();
+			for nonce in 0..3 {
+				let task = MockTask::Remark(nonce);
+				let id = TaskV2::<Runtime>::to_id(&task);
+				Runtime::insert(&deadline, &id, task);
+			}
+
+			let state = MigrateToTaskRegistry::<Runtime, MockTask<u32>>::pre_upgrade().unwrap();
+			MigrateToTaskRegistry::<Runtime, MockTask<u32>>::on_runtime_upgrade();
+			MigrateToTaskRegistry::<Runtime, MockTask<u32>>::post_upgrade(state).unwrap();
+
+			let fewer = 2u64.encode();
+			assert!(MigrateToTaskRegistry::<Runtime, MockTask<u32>>::post_upgrade(fewer).is_err());
+		});
+	}
+}
@@ -14,6 +14,7 @@ use sp_runtime::{
 parameter_types! {
 	pub const BlockHashCount: u64 = 250;
 	pub const SS58Prefix: u8 = 42;
+	pub static QuorumThreshold: u32 = 1;
 }
 
 type Block = MockBlock<Runtime>;
@@ -112,6 +113,13 @@ where
 impl crate::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UnverifiedTaskTimeout = ConstU64<5>;
+	type MaxTasksPerRun = ConstU32<2>;
+	type MaxRunDuration = ConstU64<30_000>;
+	type AuthoritiesPerTask = ConstU32<1>;
+	type QuorumThreshold = QuorumThreshold;
+	type ShardingFallback = ConstU64<2>;
+	type MaxTaskAttempts = ConstU32<4>;
+	type RetryBackoff = ConstU64<1>;
 	type AuthorityId = crate::crypto::AuthorityId;
 	type TaskCall = RuntimeCall;
 	type WeightInfo = crate::weights::WeightInfo<Self>;
@@ -35,6 +35,9 @@ where
 	) -> Result<Self::Call, TaskError<Self::EvaluationError, Self::SchedulerError>> {
 		TaskV2::<T>::forward_task(self, deadline).map(|c| c.into())
 	}
+	fn retries_exhausted(&self, deadline: T::BlockNumber) -> Option<Self::Call> {
+		TaskV2::<T>::retries_exhausted(self, deadline, &TaskV2::<T>::to_id(self)).map(|c| c.into())
+	}
 }
 
 impl<Runtime: Config, Nonce: Encode> TaskV2<Runtime> for MockTask<Nonce> {
@@ -62,4 +65,16 @@ impl<Runtime: Config, Nonce: Encode> TaskV2<Runtime> for MockTask<Nonce> {
 			MockTask::Scheduler => Err(TaskError::Scheduler(())),
 		}
 	}
+	fn retries_exhausted(
+		&self,
+		_deadline: Runtime::BlockNumber,
+		_id: &Runtime::Hash,
+	) -> Option<SystemCall<Runtime>> {
+		match self {
+			MockTask::Evaluation => Some(frame_system::pallet::Call::remark_with_event {
+				remark: b"exhausted".to_vec(),
+			}),
+			_ => None,
+		}
+	}
 }
@@ -1,4 +1,7 @@
 pub(crate) mod nonce;
+pub(crate) mod queue;
+pub(crate) mod retry;
+pub(crate) mod shard;
 
 use super::Error;
 use super::{log, Authorities, Config, Pallet};
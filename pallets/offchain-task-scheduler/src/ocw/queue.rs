@@ -0,0 +1,57 @@
+use crate::{pallet::PendingTasks, Config};
+use alloc::vec::Vec;
+use frame_support::traits::Get;
+use frame_system::Config as SystemConfig;
+use sp_runtime::offchain::storage::StorageValueRef;
+use sp_runtime::offchain::{Duration, Timestamp};
+use sp_runtime::traits::Saturating;
+
+pub(crate) const CURSOR: &[u8] = b"task-scheduler/queue/cursor";
+
+pub(crate) type TaskKey<T> = (<T as SystemConfig>::BlockNumber, <T as SystemConfig>::Hash);
+
+/// Bounds how many tasks a single offchain worker run evaluates and for how long.
+pub(crate) struct Budget {
+	tasks: u32,
+	until: Timestamp,
+}
+
+impl Budget {
+	pub(crate) fn new<T: Config>() -> Self {
+		let duration = Duration::from_millis(T::MaxRunDuration::get());
+		Self { tasks: T::MaxTasksPerRun::get(), until: sp_io::offchain::timestamp().add(duration) }
+	}
+
+	pub(crate) fn is_exhausted(&self) -> bool {
+		self.tasks == 0 || sp_io::offchain::timestamp() >= self.until
+	}
+
+	pub(crate) fn spend(&mut self) {
+		self.tasks.saturating_dec();
+	}
+}
+
+/// The pending tasks ordered by nearest deadline, starting after the last task evaluated by a
+/// previous run that ran out of budget.
+pub(crate) fn pending_tasks<T: Config>() -> Vec<TaskKey<T>> {
+	let mut keys: Vec<TaskKey<T>> = PendingTasks::<T>::iter_keys().collect();
+	keys.sort();
+
+	if let Ok(Some(cursor)) = StorageValueRef::persistent(CURSOR).get::<TaskKey<T>>() {
+		let start = keys.partition_point(|key| *key <= cursor);
+		keys.rotate_left(start);
+	}
+
+	keys
+}
+
+/// Makes the next run resume after `last`, the last task evaluated by a run that ran out of
+/// budget.
+pub(crate) fn save_cursor<T: Config>(last: &TaskKey<T>) {
+	StorageValueRef::persistent(CURSOR).set(last);
+}
+
+/// Makes the next run start from the nearest deadline.
+pub(crate) fn clear_cursor() {
+	StorageValueRef::persistent(CURSOR).clear();
+}
@@ -0,0 +1,98 @@
+use super::queue::TaskKey;
+use crate::{pallet::PendingTasks, Config};
+use alloc::vec::Vec;
+use frame_support::traits::Get;
+use parity_scale_codec::{Decode, Encode};
+use sp_runtime::offchain::storage::StorageValueRef;
+use sp_runtime::traits::Saturating;
+
+/// The tasks that have a retry record, so that the records of tasks that are no longer pending
+/// can be found and cleared.
+pub(crate) const RETRIED_TASKS: &[u8] = b"task-scheduler/task/retried";
+
+/// The failed attempts at evaluating a task and the block it can be attempted again at.
+#[derive(Encode, Decode, Default, Debug, PartialEq, Eq)]
+pub(crate) struct Retry<BlockNumber> {
+	pub(crate) attempts: u32,
+	pub(crate) next_attempt: BlockNumber,
+}
+
+#[inline]
+pub(crate) fn storage_key<Id: Encode>(id: &Id) -> Vec<u8> {
+	const TASK_RETRY: &[u8] = b"task-scheduler/task/retry/";
+	id.using_encoded(|encoded_id| TASK_RETRY.iter().chain(encoded_id).copied().collect())
+}
+
+/// Whether the task `id` is not backing off from a failed attempt at `block_number`.
+pub(crate) fn is_due<T: Config>(id: &T::Hash, block_number: T::BlockNumber) -> bool {
+	match StorageValueRef::persistent(&storage_key(id)).get::<Retry<T::BlockNumber>>() {
+		Ok(Some(retry)) => retry.next_attempt <= block_number,
+		_ => true,
+	}
+}
+
+/// Records a failed attempt at evaluating the task `id`, due at `deadline`, and delays the next
+/// one by `RetryBackoff` blocks, doubled for every previous failure. Returns `false` once the
+/// task has been attempted `MaxTaskAttempts` times or the next attempt would not happen before
+/// the deadline, in which case the task is not attempted again.
+pub(crate) fn record_failure<T: Config>(
+	id: &T::Hash,
+	deadline: T::BlockNumber,
+	block_number: T::BlockNumber,
+) -> bool {
+	let key = storage_key(id);
+	let storage = StorageValueRef::persistent(&key);
+	let mut retry = storage.get::<Retry<T::BlockNumber>>().ok().flatten().unwrap_or_default();
+
+	retry.attempts.saturating_inc();
+	let factor = T::BlockNumber::from(2u32.saturating_pow(retry.attempts - 1));
+	retry.next_attempt = block_number.saturating_add(T::RetryBackoff::get().saturating_mul(factor));
+
+	let retrying = retry.attempts < T::MaxTaskAttempts::get() && retry.next_attempt < deadline;
+	if !retrying {
+		retry.next_attempt = deadline;
+	}
+
+	let retried = StorageValueRef::persistent(RETRIED_TASKS);
+	let mut tasks = retried.get::<Vec<TaskKey<T>>>().ok().flatten().unwrap_or_default();
+	if !tasks.contains(&(deadline, *id)) {
+		tasks.push((deadline, *id));
+		retried.set(&tasks);
+	}
+	storage.set(&retry);
+	retrying
+}
+
+/// Forgets the failed attempts at evaluating the task `id`.
+pub(crate) fn clear<T: Config>(id: &T::Hash) {
+	StorageValueRef::persistent(&storage_key(id)).clear();
+}
+
+/// Clears the retry records of the tasks that are no longer pending, because they were completed
+/// or expired.
+pub(crate) fn prune<T: Config>() {
+	let retried = StorageValueRef::persistent(RETRIED_TASKS);
+	let tasks = match retried.get::<Vec<TaskKey<T>>>() {
+		Ok(Some(tasks)) => tasks,
+		_ => return,
+	};
+
+	let (pending, finished): (Vec<_>, Vec<_>) = tasks
+		.into_iter()
+		.partition(|(deadline, id)| PendingTasks::<T>::contains_key(deadline, id));
+	if finished.is_empty() {
+		return;
+	}
+
+	for (_, id) in &finished {
+		// the same task may have been scheduled again under another deadline
+		if !pending.iter().any(|(_, pending_id)| pending_id == id) {
+			clear::<T>(id);
+		}
+	}
+	if pending.is_empty() {
+		retried.clear();
+	} else {
+		retried.set(&pending);
+	}
+}
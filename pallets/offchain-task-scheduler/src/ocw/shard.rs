@@ -0,0 +1,62 @@
+use crate::{Authorities, Config};
+use alloc::vec::Vec;
+use frame_support::traits::Get;
+use sp_runtime::traits::Saturating;
+
+/// Splits the pending tasks between the authorities, so that each task is only evaluated by
+/// `AuthoritiesPerTask` of them, or `QuorumThreshold` if that is larger, until its deadline is
+/// `ShardingFallback` blocks away.
+pub(crate) struct Shard<T: Config> {
+	authorities: usize,
+	position: Option<usize>,
+	size: usize,
+	_marker: core::marker::PhantomData<T>,
+}
+
+impl<T: Config> Shard<T> {
+	/// The shard of `authority` within the current authority set.
+	pub(crate) fn new(authority: &T::AccountId) -> Self {
+		let mut authorities: Vec<T::AccountId> = Authorities::<T>::iter_keys().collect();
+		authorities.sort();
+
+		Self {
+			authorities: authorities.len(),
+			position: authorities.binary_search(authority).ok(),
+			size: T::AuthoritiesPerTask::get().max(T::QuorumThreshold::get()).max(1) as usize,
+			_marker: core::marker::PhantomData,
+		}
+	}
+
+	/// Whether the task `id`, due at `deadline`, should be evaluated at `block_number`. A task is
+	/// assigned to consecutive authorities of the sorted authority set, starting at an offset
+	/// derived from its id.
+	pub(crate) fn is_assigned(
+		&self,
+		id: &T::Hash,
+		deadline: T::BlockNumber,
+		block_number: T::BlockNumber,
+	) -> bool {
+		if deadline.saturating_sub(T::ShardingFallback::get()) <= block_number {
+			return true;
+		}
+
+		let position = match self.position {
+			Some(position) => position,
+			None => return true,
+		};
+
+		if self.size >= self.authorities {
+			return true;
+		}
+
+		let start = (offset(id.as_ref()) % self.authorities as u64) as usize;
+		(position + self.authorities - start) % self.authorities < self.size
+	}
+}
+
+fn offset(id: &[u8]) -> u64 {
+	let mut bytes = [0u8; 8];
+	let len = id.len().min(bytes.len());
+	bytes[..len].copy_from_slice(&id[..len]);
+	u64::from_le_bytes(bytes)
+}
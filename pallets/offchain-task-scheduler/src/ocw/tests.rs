@@ -5,11 +5,12 @@ use crate::{
 	mock::{
 		generate_authority,
 		runtime::{
-			AccountId, Extrinsic, Runtime, RuntimeCall, RuntimeOrigin, System, TaskScheduler,
+			AccountId, Extrinsic, QuorumThreshold, Runtime, RuntimeCall, RuntimeOrigin, System,
+			TaskScheduler,
 		},
 		task::MockTask,
 	},
-	ocw::StorageValueRef,
+	ocw::{queue, retry, shard::Shard, StorageValueRef},
 	tasks::{storage_key, task_lock, TaskScheduler as TaskSchedulerT, TaskV2},
 	Pallet,
 };
@@ -136,6 +137,117 @@ fn evaluation_error_is_retried() {
 	});
 }
 
+#[test]
+fn evaluation_is_retried_with_backoff_until_the_deadline() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	generate_authority(&mut ext_builder);
+	ext_builder.with_offchain();
+	let pool = ext_builder.with_pool();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		let task = MockTask::Evaluation;
+		let id = TaskV2::<Runtime>::to_id(&task);
+		Runtime::insert(&deadline, &id, task);
+
+		let key = retry::storage_key(&id);
+		let retry =
+			|| StorageValueRef::persistent(&key).get::<retry::Retry<u64>>().expect("decoded");
+
+		// RetryBackoff is 1, MaxTaskAttempts is 4 and UnverifiedTaskTimeout is 5 in the mock
+		// runtime.
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(2);
+		assert_eq!(retry(), Some(retry::Retry { attempts: 1, next_attempt: 3 }));
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(4);
+		assert_eq!(retry(), Some(retry::Retry { attempts: 2, next_attempt: 5 }));
+		assert!(pool.read().transactions.is_empty());
+
+		// the next attempt, at block 9, would be past the deadline.
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(5);
+		let tx = pool.write().transactions.pop().expect("Retries exhausted");
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::System(frame_system::pallet::Call::remark_with_event {
+				remark: b"exhausted".to_vec()
+			})
+		);
+		assert_eq!(retry(), None);
+	});
+}
+
+#[test]
+fn evaluation_is_retried_with_backoff_until_out_of_attempts() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	generate_authority(&mut ext_builder);
+	ext_builder.with_offchain();
+	let pool = ext_builder.with_pool();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline() + 20;
+		let task = MockTask::Evaluation;
+		let id = TaskV2::<Runtime>::to_id(&task);
+		Runtime::insert(&deadline, &id, task);
+
+		let key = retry::storage_key(&id);
+		let retry =
+			|| StorageValueRef::persistent(&key).get::<retry::Retry<u64>>().expect("decoded");
+
+		// RetryBackoff is 1 and MaxTaskAttempts is 4 in the mock runtime.
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(5);
+		assert_eq!(retry(), Some(retry::Retry { attempts: 3, next_attempt: 9 }));
+		assert!(pool.read().transactions.is_empty());
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(9);
+		assert!(9 < deadline);
+		let tx = pool.write().transactions.pop().expect("Retries exhausted");
+		let tx = Extrinsic::decode(&mut &*tx).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::System(frame_system::pallet::Call::remark_with_event {
+				remark: b"exhausted".to_vec()
+			})
+		);
+	});
+}
+
+#[test]
+fn retry_records_are_cleared_once_tasks_are_no_longer_pending() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	generate_authority(&mut ext_builder);
+	ext_builder.with_offchain();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		let task = MockTask::Evaluation;
+		let id = TaskV2::<Runtime>::to_id(&task);
+		Runtime::insert(&deadline, &id, task);
+
+		let key = retry::storage_key(&id);
+		let retry =
+			|| StorageValueRef::persistent(&key).get::<retry::Retry<u64>>().expect("decoded");
+		let retried = || {
+			StorageValueRef::persistent(retry::RETRIED_TASKS)
+				.get::<Vec<queue::TaskKey<Runtime>>>()
+				.expect("decoded")
+		};
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(2);
+		assert!(retry().is_some());
+		assert_eq!(retried(), Some(vec![(deadline, id)]));
+
+		Runtime::complete(&deadline, &id);
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(3);
+		assert_eq!(retry(), None);
+		assert_eq!(retried(), None);
+	});
+}
+
 #[test]
 #[tracing_test::traced_test]
 fn forget_task_guard_when_task_has_been_persisted() {
@@ -238,6 +350,185 @@ fn effective_guard_lifetime_until_task_expiration() {
 	});
 }
 
+fn submitted_remarks(transactions: &mut Vec<Vec<u8>>) -> Vec<u32> {
+	let mut remarks: Vec<u32> = transactions
+		.drain(..)
+		.map(|tx| match Extrinsic::decode(&mut &*tx).unwrap().call {
+			RuntimeCall::System(frame_system::pallet::Call::remark_with_event { remark }) => {
+				u32::decode(&mut &*remark).unwrap()
+			},
+			call => panic!("Unexpected call {call:?}"),
+		})
+		.collect();
+	remarks.sort();
+	remarks
+}
+
+#[test]
+fn offchain_worker_carries_over_tasks_beyond_its_budget() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	generate_authority(&mut ext_builder);
+	ext_builder.with_offchain();
+	let pool = ext_builder.with_pool();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		for nonce in 0..3 {
+			let task = MockTask::Remark(nonce);
+			Runtime::insert(&deadline, &TaskV2::<Runtime>::to_id(&task), task);
+		}
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(2);
+
+		// MaxTasksPerRun is 2 in the mock runtime.
+		let first_run = submitted_remarks(&mut pool.write().transactions);
+		assert_eq!(first_run.len(), 2);
+		assert!(StorageValueRef::persistent(queue::CURSOR)
+			.get::<queue::TaskKey<Runtime>>()
+			.expect("decoded")
+			.is_some());
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(3);
+
+		let second_run = submitted_remarks(&mut pool.write().transactions);
+		assert_eq!(second_run.len(), 1);
+		assert!(!first_run.contains(&second_run[0]));
+		assert!(StorageValueRef::persistent(queue::CURSOR)
+			.get::<queue::TaskKey<Runtime>>()
+			.expect("decoded")
+			.is_none());
+	});
+}
+
+#[test]
+fn offchain_worker_resumes_after_cursor_when_earlier_tasks_are_unlocked() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	generate_authority(&mut ext_builder);
+	ext_builder.with_offchain();
+	let pool = ext_builder.with_pool();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		for nonce in 0..3 {
+			let task = MockTask::Remark(nonce);
+			Runtime::insert(&deadline, &TaskV2::<Runtime>::to_id(&task), task);
+		}
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(2);
+
+		let first_run = submitted_remarks(&mut pool.write().transactions);
+		assert_eq!(first_run.len(), 2);
+
+		// the first run's tasks become available again, e.g. because their evaluation failed.
+		for nonce in &first_run {
+			let id = TaskV2::<Runtime>::to_id(&MockTask::Remark(*nonce));
+			StorageValueRef::persistent(storage_key(&id).as_ref()).clear();
+		}
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(3);
+
+		let skipped = (0..3).find(|nonce| !first_run.contains(nonce)).unwrap();
+		let second_run = submitted_remarks(&mut pool.write().transactions);
+		assert_eq!(second_run.len(), 2);
+		assert!(second_run.contains(&skipped));
+	});
+}
+
+#[test]
+fn offchain_worker_evaluates_nearest_deadline_first() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	generate_authority(&mut ext_builder);
+	ext_builder.with_offchain();
+	let pool = ext_builder.with_pool();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		for (nonce, offset) in [(0, 2), (1, 0), (2, 1)] {
+			let task = MockTask::Remark(nonce);
+			Runtime::insert(&(deadline + offset), &TaskV2::<Runtime>::to_id(&task), task);
+		}
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(2);
+
+		assert_eq!(submitted_remarks(&mut pool.write().transactions), vec![1, 2]);
+	});
+}
+
+#[test]
+fn each_task_is_assigned_to_authorities_per_task() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default();
+	let authorities: Vec<AccountId> = (1..=3).map(|n| AccountId::new([n; 32])).collect();
+	ext_builder.genesis_config.authorities = authorities.clone();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		let shards: Vec<_> = authorities.iter().map(Shard::<Runtime>::new).collect();
+		for nonce in 0..10 {
+			let id = TaskV2::<Runtime>::to_id(&MockTask::Remark(nonce));
+
+			// AuthoritiesPerTask is 1 in the mock runtime.
+			let assigned = shards.iter().filter(|s| s.is_assigned(&id, deadline, 1)).count();
+			assert_eq!(assigned, 1);
+
+			// ShardingFallback is 2 in the mock runtime.
+			assert!(shards.iter().all(|s| s.is_assigned(&id, deadline, deadline - 2)));
+		}
+	});
+}
+
+#[test]
+fn each_task_is_assigned_to_enough_authorities_for_a_quorum() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default();
+	let authorities: Vec<AccountId> = (1..=3).map(|n| AccountId::new([n; 32])).collect();
+	ext_builder.genesis_config.authorities = authorities.clone();
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+		QuorumThreshold::set(2);
+
+		let deadline = Runtime::deadline();
+		let shards: Vec<_> = authorities.iter().map(Shard::<Runtime>::new).collect();
+		for nonce in 0..10 {
+			let id = TaskV2::<Runtime>::to_id(&MockTask::Remark(nonce));
+
+			let assigned = shards.iter().filter(|s| s.is_assigned(&id, deadline, 1)).count();
+			assert_eq!(assigned, 2);
+		}
+	});
+}
+
+#[test]
+fn unassigned_tasks_are_evaluated_near_their_deadline() {
+	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
+	let acct_pubkey = generate_authority(&mut ext_builder);
+	ext_builder.genesis_config.authorities.push(AccountId::new([1; 32]));
+	ext_builder.genesis_config.authorities.push(AccountId::new([2; 32]));
+	ext_builder.with_offchain();
+	let pool = ext_builder.with_pool();
+	let auth = AccountId::from(acct_pubkey.into_account().0);
+	ext_builder.build().execute_with(|| {
+		roll_to::<Trivial, Runtime, Pallet<Runtime>>(1);
+
+		let deadline = Runtime::deadline();
+		let shard = Shard::<Runtime>::new(&auth);
+		let task = (0..)
+			.map(MockTask::Remark)
+			.find(|task| !shard.is_assigned(&TaskV2::<Runtime>::to_id(task), deadline, 1))
+			.expect("a task assigned to other authorities");
+		let id = TaskV2::<Runtime>::to_id(&task);
+		Runtime::insert(&deadline, &id, task);
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(deadline - 3);
+		assert!(pool.read().transactions.is_empty());
+
+		roll_to::<WithWorkerHook<Pallet<Runtime>>, Runtime, Pallet<Runtime>>(deadline - 2);
+		assert_eq!(pool.read().transactions.len(), 1);
+	});
+}
+
 #[test]
 fn offchain_signed_tx_works() {
 	let mut ext_builder = ExtBuilder::<GenesisConfig<Runtime>>::default().with_keystore();
@@ -44,6 +44,11 @@ pub trait ForwardTask<Runtime: SystemConfig> {
 		&self,
 		deadline: Runtime::BlockNumber,
 	) -> Result<Self::Call, TaskError<Self::EvaluationError, Self::SchedulerError>>;
+	/// A call to give up on the task once its evaluation can no longer be retried before its
+	/// deadline. Without one, the task is left to expire at its deadline.
+	fn retries_exhausted(&self, _deadline: Runtime::BlockNumber) -> Option<Self::Call> {
+		None
+	}
 }
 
 pub trait TaskV2<Runtime: SystemConfig> {
@@ -73,10 +78,21 @@ pub trait TaskV2<Runtime: SystemConfig> {
 		}
 		self.persistence_call(deadline, &id)
 	}
+	/// A call to give up on the task once its evaluation can no longer be retried before its
+	/// deadline.
+	fn retries_exhausted(
+		&self,
+		_deadline: Runtime::BlockNumber,
+		_id: &Runtime::Hash,
+	) -> Option<Self::Call> {
+		None
+	}
 }
 
 pub trait TaskScheduler<BlockNumber, Hash, Task> {
 	fn deadline() -> BlockNumber;
 	fn is_scheduled(deadline: &BlockNumber, id: &Hash) -> bool;
 	fn insert(deadline: &BlockNumber, id: &Hash, task: Task);
+	/// Removes a task that no longer needs processing before its deadline.
+	fn complete(deadline: &BlockNumber, id: &Hash);
 }
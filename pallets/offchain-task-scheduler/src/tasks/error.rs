@@ -1,7 +1,28 @@
+use alloc::boxed::Box;
+use core::fmt::Debug;
+
 #[derive(Debug)]
 pub enum TaskError<E, S> {
+	/// the task could not be evaluated yet, it is retried with a backoff until it runs out of
+	/// attempts.
 	Evaluation(E),
+	/// the node could not process the task, it is retried on the next run.
 	Scheduler(S),
 	/// the task id has been found in onchain storage, this is most likely a dupe.
 	FinishedTask,
 }
+
+/// A task error whose evaluation and scheduler errors are only known to be printable.
+pub type BoxedTaskError = TaskError<Box<dyn Debug>, Box<dyn Debug>>;
+
+impl<E: Debug + 'static, S: Debug + 'static> TaskError<E, S> {
+	/// Boxes the evaluation and scheduler errors, so that tasks with different error types can
+	/// be forwarded by the same registry.
+	pub fn boxed(self) -> BoxedTaskError {
+		match self {
+			TaskError::Evaluation(e) => TaskError::Evaluation(Box::new(e)),
+			TaskError::Scheduler(s) => TaskError::Scheduler(Box::new(s)),
+			TaskError::FinishedTask => TaskError::FinishedTask,
+		}
+	}
+}
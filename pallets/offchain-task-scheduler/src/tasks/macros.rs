@@ -13,3 +13,170 @@ macro_rules! impl_enum_from_variant {
         )+
     };
 }
+
+/// Composes the tasks of several pallets into a single type to be used as the scheduler's
+/// `Config::Task`. Each pallet keeps its own task type and schedules it through
+/// [`Registered`](crate::Registered), sharing the scheduler, the nonce and the authorities with
+/// the other pallets. Errors of the registered tasks are reported as `Box<dyn Debug>`.
+///
+/// ```ignore
+/// pallet_offchain_task_scheduler::task_registry! {
+/// 	pub enum RuntimeTask for Runtime {
+/// 		Creditcoin(pallet_creditcoin::Task<AccountId, BlockNumber, Hash, Moment>),
+/// 	}
+/// }
+/// ```
+#[macro_export]
+macro_rules! task_registry {
+	(
+		$(#[$attr: meta])*
+		$vis: vis enum $registry: ident for $runtime: ty {
+			$first: ident($first_task: ty)
+			$(, $variant: ident($task: ty))* $(,)?
+		}
+	) => {
+		$(#[$attr])*
+		#[derive(
+			Clone,
+			$crate::__private::RuntimeDebug,
+			$crate::__private::Encode,
+			$crate::__private::Decode,
+			$crate::__private::MaxEncodedLen,
+			$crate::__private::TypeInfo,
+		)]
+		$vis enum $registry {
+			$first($first_task),
+			$($variant($task),)*
+		}
+
+		impl From<$first_task> for $registry {
+			fn from(task: $first_task) -> Self {
+				$registry::$first(task)
+			}
+		}
+
+		$(
+			impl From<$task> for $registry {
+				fn from(task: $task) -> Self {
+					$registry::$variant(task)
+				}
+			}
+		)*
+
+		impl $crate::tasks::ForwardTask<$runtime> for $registry {
+			type Call = <$runtime as $crate::Config>::TaskCall;
+			type EvaluationError = $crate::__private::Box<dyn core::fmt::Debug>;
+			type SchedulerError = $crate::__private::Box<dyn core::fmt::Debug>;
+
+			fn forward_task(
+				&self,
+				deadline: <$runtime as $crate::__private::SystemConfig>::BlockNumber,
+			) -> Result<Self::Call, $crate::tasks::error::BoxedTaskError> {
+				use $crate::tasks::ForwardTask;
+				match self {
+					$registry::$first(task) => {
+						ForwardTask::<$runtime>::forward_task(task, deadline).map_err(|e| e.boxed())
+					},
+					$($registry::$variant(task) => {
+						ForwardTask::<$runtime>::forward_task(task, deadline).map_err(|e| e.boxed())
+					},)*
+				}
+			}
+
+			fn retries_exhausted(
+				&self,
+				deadline: <$runtime as $crate::__private::SystemConfig>::BlockNumber,
+			) -> Option<Self::Call> {
+				use $crate::tasks::ForwardTask;
+				match self {
+					$registry::$first(task) => {
+						ForwardTask::<$runtime>::retries_exhausted(task, deadline)
+					},
+					$($registry::$variant(task) => {
+						ForwardTask::<$runtime>::retries_exhausted(task, deadline)
+					},)*
+				}
+			}
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		impl $crate::benchmarking::TaskDefault<$runtime> for $registry {
+			fn generate_from_seed(seed: u32) -> Self {
+				$registry::$first($crate::benchmarking::TaskDefault::<$runtime>::generate_from_seed(
+					seed,
+				))
+			}
+		}
+	};
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::mock::{
+		runtime::{Runtime, RuntimeCall},
+		task::MockTask,
+	};
+	use crate::tasks::{error::TaskError, ForwardTask};
+	use assert_matches::assert_matches;
+	use sp_runtime::codec::{Decode, Encode};
+
+	crate::task_registry! {
+		enum TestTask for Runtime {
+			Small(MockTask<u32>),
+			Large(MockTask<u64>),
+		}
+	}
+
+	#[test]
+	fn registered_tasks_are_forwarded() {
+		let small = TestTask::from(MockTask::<u32>::Remark(1));
+		let large = TestTask::from(MockTask::<u64>::Remark(2));
+
+		assert_eq!(
+			ForwardTask::<Runtime>::forward_task(&small, 1).unwrap(),
+			RuntimeCall::System(frame_system::Call::remark_with_event { remark: 1u32.encode() })
+		);
+		assert_eq!(
+			ForwardTask::<Runtime>::forward_task(&large, 1).unwrap(),
+			RuntimeCall::System(frame_system::Call::remark_with_event { remark: 2u64.encode() })
+		);
+	}
+
+	#[test]
+	fn registered_task_errors_are_boxed() {
+		let task = TestTask::from(MockTask::<u64>::Evaluation);
+		assert_matches!(
+			ForwardTask::<Runtime>::forward_task(&task, 1),
+			Err(TaskError::Evaluation(e)) => assert_eq!(format!("{e:?}"), "()")
+		);
+
+		let task = TestTask::from(MockTask::<u32>::Scheduler);
+		assert_matches!(
+			ForwardTask::<Runtime>::forward_task(&task, 1),
+			Err(TaskError::Scheduler(_))
+		);
+	}
+
+	#[test]
+	fn registered_tasks_forward_retries_exhausted() {
+		let task = TestTask::from(MockTask::<u64>::Evaluation);
+		assert_eq!(
+			ForwardTask::<Runtime>::retries_exhausted(&task, 1),
+			Some(RuntimeCall::System(frame_system::Call::remark_with_event {
+				remark: b"exhausted".to_vec()
+			}))
+		);
+
+		let task = TestTask::from(MockTask::<u32>::Remark(1));
+		assert_eq!(ForwardTask::<Runtime>::retries_exhausted(&task, 1), None);
+	}
+
+	#[test]
+	fn registered_tasks_are_encoded_with_their_variant() {
+		let task = TestTask::from(MockTask::<u64>::Remark(2));
+		let encoded = task.encode();
+
+		assert_eq!(encoded, (1u8, MockTask::<u64>::Remark(2)).encode());
+		assert_matches!(TestTask::decode(&mut &*encoded), Ok(TestTask::Large(MockTask::Remark(2))));
+	}
+}
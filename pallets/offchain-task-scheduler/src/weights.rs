@@ -32,11 +32,12 @@ use sp_std::marker::PhantomData;
 /// Weight functions for `pallet_offchain_task_scheduler`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> super::WeightInfo for WeightInfo<T> {
-	// Storage: TaskScheduler PendingTasks (r:0 w:5)
+	// Storage: TaskScheduler PendingTasks (r:5 w:5)
 	fn on_initialize(t: u32, ) -> Weight {
 		Weight::from_ref_time(2_348_000 as u64)
 			// Standard Error: 2_000
 			.saturating_add(Weight::from_ref_time(1_039_000 as u64).saturating_mul(t as u64))
+			.saturating_add(T::DbWeight::get().reads((1 as u64).saturating_mul(t as u64)))
 			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(t as u64)))
 	}
 }
@@ -7,7 +7,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 pub use frame_support::traits::EqualPrivilegeOnly;
 use frame_support::{
-	traits::{ConstU32, ConstU8},
+	traits::{ConstU32, ConstU64, ConstU8, Get},
 	weights::{WeightToFeeCoefficient, WeightToFeeCoefficients, WeightToFeePolynomial},
 };
 use pallet_creditcoin::weights::WeightInfo as creditcoin_weights;
@@ -144,13 +144,39 @@ parameter_types! {
 
 }
 
+type CreditcoinTask = pallet_creditcoin::Task<AccountId, BlockNumber, Hash, Moment>;
+
+pallet_offchain_task_scheduler::task_registry! {
+	/// The off-chain tasks of every pallet, which share the task scheduler.
+	pub enum RuntimeTask for Runtime {
+		Creditcoin(CreditcoinTask),
+	}
+}
+
+/// The quorum threshold of the creditcoin pallet, which every task is sharded to enough
+/// authorities to reach.
+pub struct CreditcoinQuorumThreshold;
+
+impl Get<u32> for CreditcoinQuorumThreshold {
+	fn get() -> u32 {
+		Creditcoin::quorum_threshold()
+	}
+}
+
 impl pallet_offchain_task_scheduler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type UnverifiedTaskTimeout = ConstU32<60>;
+	type MaxTasksPerRun = ConstU32<100>;
+	type MaxRunDuration = ConstU64<30_000>;
+	type AuthoritiesPerTask = ConstU32<1>;
+	type QuorumThreshold = CreditcoinQuorumThreshold;
+	type ShardingFallback = ConstU32<10>;
+	type MaxTaskAttempts = ConstU32<5>;
+	type RetryBackoff = ConstU32<2>;
 	type AuthorityId = AuthorityId;
 	type TaskCall = RuntimeCall;
 	type WeightInfo = pallet_offchain_task_scheduler::weights::WeightInfo<Runtime>;
-	type Task = pallet_creditcoin::Task<AccountId, BlockNumber, Hash, Moment>;
+	type Task = RuntimeTask;
 }
 
 impl pallet_scheduler::Config for Runtime {
@@ -322,8 +348,11 @@ impl pallet_creditcoin::Config for Runtime {
 	type SignerSignature = Signature;
 	type HashIntoNonce = Hash;
 	type UnverifiedTaskTimeout = ConstU32<60>;
+	type RewardEraLength = ConstU32<1440>;
+	type RewardEraRetention = ConstU32<90>;
+	type TaskStatusRetention = ConstU32<1440>;
 	type WeightInfo = pallet_creditcoin::weights::WeightInfo<Runtime>;
-	type TaskScheduler = Self;
+	type TaskScheduler = pallet_offchain_task_scheduler::Registered<Runtime, CreditcoinTask>;
 }
 
 impl pallet_difficulty::Config for Runtime {
@@ -429,8 +458,13 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 
+/// Migrations that are not owned by a single pallet.
+type Migrations =
+	pallet_offchain_task_scheduler::migrations::MigrateToTaskRegistry<Runtime, CreditcoinTask>;
+
 #[cfg(feature = "runtime-benchmarks")]
 #[macro_use]
 extern crate frame_benchmarking;
@@ -598,6 +632,41 @@ impl_runtime_apis! {
 			pallet_offchain_task_scheduler::ocw::nonce_key(acc)
 		}
 	}
+
+	impl creditcoin_runtime_api::LoanApi<Block, AccountId, BlockNumber, Hash, Moment> for Runtime {
+		fn amount_due(
+			deal_order_id: pallet_creditcoin::DealOrderId<BlockNumber, Hash>,
+			at: Moment,
+		) -> Option<pallet_creditcoin::AmountDue> {
+			Creditcoin::amount_due(&deal_order_id, at)
+		}
+
+		fn credit_history(account_id: AccountId) -> pallet_creditcoin::CreditHistory<Hash> {
+			Creditcoin::credit_history(&account_id)
+		}
+	}
+
+	impl creditcoin_runtime_api::RewardApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn current_reward_era() -> BlockNumber {
+			Creditcoin::reward_era(System::block_number())
+		}
+
+		fn authority_rewards(era: BlockNumber, authority: AccountId) -> Balance {
+			Creditcoin::era_rewards(era, authority)
+		}
+
+		fn total_rewards(era: BlockNumber) -> Balance {
+			Creditcoin::era_reward_totals(era)
+		}
+	}
+
+	impl creditcoin_runtime_api::TaskStatusApi<Block, BlockNumber, Hash> for Runtime {
+		fn task_status(
+			id: pallet_creditcoin::TaskId<Hash>,
+		) -> Option<pallet_creditcoin::TaskStatus<BlockNumber>> {
+			Creditcoin::task_statuses(id)
+		}
+	}
 }
 
 impl frame_system::offchain::SigningTypes for Runtime {